@@ -1,4 +1,16 @@
-use miden_objects::crypto::dsa::rpo_falcon512;
+use core::iter;
+use miden_objects::{
+    accounts::{AccountStorage, SlotItem, StorageSlotType},
+    crypto::dsa::rpo_falcon512,
+    utils::{
+        collections::{BTreeMap, Vec},
+        format, vec,
+    },
+    AccountError, Felt, Word, EMPTY_WORD, ZERO,
+};
+
+// AUTH SCHEME
+// ================================================================================================
 
 /// Defines authentication schemes available to standard and faucet accounts.
 pub enum AuthScheme {
@@ -7,4 +19,98 @@ pub enum AuthScheme {
     /// the standard in that instead of using SHAKE256 hash function in the hash-to-point algorithm we
     /// use RPO256. This makes the signature more efficient to verify in Miden VM.
     RpoFalcon512 { pub_key: rpo_falcon512::PublicKey },
+
+    /// A multi-key authentication scheme which relies on RPO Falcon512 signatures. A transaction is
+    /// authenticated if it is signed by at least `threshold` of the provided public keys.
+    RpoFalcon512Multisig {
+        threshold: u32,
+        pub_keys: Vec<rpo_falcon512::PublicKey>,
+    },
+}
+
+impl AuthScheme {
+    /// The maximum number of public keys supported by the multisig scheme. The keys and the
+    /// threshold are stored as a multi-word value in a single storage slot, whose arity must fit
+    /// into a u8.
+    pub const MAX_MULTISIG_KEYS: usize = 254;
+
+    /// Returns the name of the procedure in `miden::auth::basic` which authenticates transactions
+    /// using this scheme.
+    pub(crate) fn procedure_name(&self) -> &'static str {
+        match self {
+            AuthScheme::RpoFalcon512 { .. } => "auth_tx_rpo_falcon512",
+            AuthScheme::RpoFalcon512Multisig { .. } => "auth_tx_rpo_falcon512_multisig",
+        }
+    }
+
+    /// Returns the authentication data of this scheme in the form expected in account storage.
+    ///
+    /// For the single-key scheme, the data consists of a single word containing the public key.
+    /// For the multisig scheme, the data consists of the word [threshold, 0, 0, 0] followed by the
+    /// public keys, and is stored in the slot as a multi-word value.
+    ///
+    /// # Errors
+    /// Returns an error if the threshold of the multisig scheme is zero or greater than the
+    /// number of public keys, or if more than [AuthScheme::MAX_MULTISIG_KEYS] keys are provided.
+    pub(crate) fn storage_words(&self) -> Result<Vec<Word>, AccountError> {
+        match self {
+            AuthScheme::RpoFalcon512 { pub_key } => Ok(vec![(*pub_key).into()]),
+            AuthScheme::RpoFalcon512Multisig {
+                threshold,
+                pub_keys,
+            } => {
+                if pub_keys.len() > Self::MAX_MULTISIG_KEYS {
+                    return Err(AccountError::InvalidAuthScheme(format!(
+                        "Multisig supports at most {} public keys, but {} were provided",
+                        Self::MAX_MULTISIG_KEYS,
+                        pub_keys.len()
+                    )));
+                }
+                if *threshold == 0 || *threshold as usize > pub_keys.len() {
+                    return Err(AccountError::InvalidAuthScheme(format!(
+                        "Multisig threshold must be between 1 and {}, but was {threshold}",
+                        pub_keys.len()
+                    )));
+                }
+
+                let mut words = Vec::with_capacity(pub_keys.len() + 1);
+                words.push([Felt::from(*threshold), ZERO, ZERO, ZERO]);
+                words.extend(pub_keys.iter().map(|&pub_key| Word::from(pub_key)));
+                Ok(words)
+            }
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns account storage containing the authentication data of the specified scheme at slot 0,
+/// followed by the provided items.
+///
+/// For single-key schemes, slot 0 is a single-word value containing the public key. For multisig
+/// schemes, slot 0 is a multi-word value containing the threshold and the public keys.
+pub(crate) fn create_auth_storage(
+    auth_scheme: &AuthScheme,
+    items: Vec<SlotItem>,
+) -> Result<AccountStorage, AccountError> {
+    let auth_words = auth_scheme.storage_words()?;
+
+    // the value of a multi-word slot is set to the commitment to its words by set_item_words()
+    let (value_arity, auth_value) = match auth_scheme {
+        AuthScheme::RpoFalcon512 { .. } => (0, auth_words[0]),
+        AuthScheme::RpoFalcon512Multisig { .. } => (auth_words.len() as u8, EMPTY_WORD),
+    };
+
+    let auth_slot = (0, (StorageSlotType::Value { value_arity }, auth_value));
+    let mut account_storage = AccountStorage::new(
+        iter::once(auth_slot).chain(items).collect(),
+        BTreeMap::new(),
+        BTreeMap::new(),
+    )?;
+    if value_arity > 0 {
+        account_storage.set_item_words(0, auth_words)?;
+    }
+
+    Ok(account_storage)
 }
@@ -1,12 +1,17 @@
 use super::Library;
-use crate::{assembler::assembler, auth::AuthScheme};
+use crate::{
+    assembler::assembler,
+    auth::{create_auth_storage, AuthScheme},
+    memory::FAUCET_STORAGE_DATA_SLOT,
+};
 use assembly::LibraryPath;
 use miden_objects::{
     accounts::{
-        Account, AccountCode, AccountId, AccountStorage, AccountType, AccountVault, StorageSlotType,
+        Account, AccountCode, AccountId, AccountType, AccountVault, StorageMap, StorageSlotType,
     },
+    assembly::ModuleAst,
     assets::TokenSymbol,
-    utils::{string::ToString, vec},
+    utils::{format, string::ToString, vec},
     AccountError, Felt, StarkField, Word, ZERO,
 };
 
@@ -23,8 +28,9 @@ const MAX_DECIMALS: u8 = 12;
 /// `distribute` requires authentication. The authentication procedure is defined by the specified
 /// authentication scheme. `burn` does not require authentication and can be called by anyone.
 ///
-/// Public key information for the scheme is stored in the account storage at slot 0. The token
-/// metadata is stored in the account storage at slot 1.
+/// Public key information for the scheme is stored in the account storage at slot 0. For multisig
+/// schemes, slot 0 holds a multi-word value consisting of the threshold followed by the public
+/// keys. The token metadata is stored in the account storage at slot 1.
 pub fn create_basic_fungible_faucet(
     init_seed: [u8; 32],
     symbol: TokenSymbol,
@@ -32,21 +38,10 @@ pub fn create_basic_fungible_faucet(
     max_supply: Felt,
     auth_scheme: AuthScheme,
 ) -> Result<(Account, Word), AccountError> {
-    // Atm we only have RpoFalcon512 as authentication scheme and this is also the default in the
-    // faucet contract, so we can just use the public key as storage slot 0.
-    // TODO: consider using a trait when we have more auth schemes.
-    let auth_data: Word = match auth_scheme {
-        AuthScheme::RpoFalcon512 { pub_key } => pub_key.into(),
-    };
-
-    let miden = super::MidenLib::default();
-    let path = "miden::faucets::basic_fungible";
-    let faucet_code_ast = miden
-        .get_module_ast(&LibraryPath::new(path).unwrap())
-        .expect("Getting module AST failed");
+    let faucet_code_ast = faucet_code_ast("basic_fungible", &auth_scheme)?;
 
     let account_assembler = assembler();
-    let account_code = AccountCode::new(faucet_code_ast.clone(), &account_assembler)?;
+    let account_code = AccountCode::new(faucet_code_ast, &account_assembler)?;
 
     // First check that the metadata is valid.
     if decimals > MAX_DECIMALS {
@@ -65,10 +60,10 @@ pub fn create_basic_fungible_faucet(
     // We store the authentication data and the token metadata in the account storage:
     // - slot 0: authentication data
     // - slot 1: token metadata as [max_supply, decimals, token_symbol, 0]
-    let account_storage = AccountStorage::new(vec![
-        (0, (StorageSlotType::Value { value_arity: 0 }, auth_data)),
-        (1, (StorageSlotType::Value { value_arity: 0 }, metadata)),
-    ])?;
+    let account_storage = create_auth_storage(
+        &auth_scheme,
+        vec![(1, (StorageSlotType::Value { value_arity: 0 }, metadata))],
+    )?;
     let account_vault = AccountVault::new(&[])?;
 
     let account_seed = AccountId::get_account_seed(
@@ -84,3 +79,121 @@ pub fn create_basic_fungible_faucet(
         account_seed,
     ))
 }
+
+/// Creates a new faucet account with basic non-fungible faucet interface, specified authentication
+/// scheme, and provided collection metadata (token symbol, max supply).
+///
+/// The basic non-fungible faucet interface exposes two procedures:
+/// - `distribute`, which mints a non-fungible asset with the provided data hash and creates a note
+///   for the provided recipient.
+/// - `burn`, which burns the provided asset.
+///
+/// `distribute` requires authentication. The authentication procedure is defined by the specified
+/// authentication scheme. `burn` does not require authentication and can be called by anyone.
+///
+/// If `max_supply` is provided, the number of outstanding assets issued by the faucet can never
+/// exceed it. Otherwise, the supply of the collection is not limited.
+///
+/// Public key information for the scheme is stored in the account storage at slot 0. The
+/// collection metadata is stored in the account storage at slot 1, and the number of outstanding
+/// assets at slot 2.
+pub fn create_basic_non_fungible_faucet(
+    init_seed: [u8; 32],
+    symbol: TokenSymbol,
+    max_supply: Option<u64>,
+    auth_scheme: AuthScheme,
+) -> Result<(Account, Word), AccountError> {
+    let faucet_code_ast = faucet_code_ast("basic_non_fungible", &auth_scheme)?;
+
+    let account_assembler = assembler();
+    let account_code = AccountCode::new(faucet_code_ast, &account_assembler)?;
+
+    // First check that the metadata is valid. A max supply of 0 is used to denote an unlimited
+    // supply, and thus cannot be provided explicitly.
+    let max_supply = match max_supply {
+        Some(0) => {
+            return Err(AccountError::NonFungibleFaucetInvalidMetadata(
+                "Max supply must be greater than 0".to_string(),
+            ))
+        }
+        Some(max_supply) if max_supply > MAX_MAX_SUPPLY => {
+            return Err(AccountError::NonFungibleFaucetInvalidMetadata(
+                "Max supply must be < 2^63".to_string(),
+            ))
+        }
+        Some(max_supply) => Felt::new(max_supply),
+        None => ZERO,
+    };
+
+    // Note: data is stored as [a0, a1, a2, a3] but loaded onto the stack as [a3, a2, a1, a0, ...]
+    let metadata = [max_supply, symbol.into(), ZERO, ZERO];
+
+    // We store the authentication data and the collection metadata in the account storage:
+    // - slot 0: authentication data
+    // - slot 1: collection metadata as [max_supply, token_symbol, 0, 0]
+    // - slot 2: number of outstanding assets as [num_issued, 0, 0, 0]
+    // - slot 254: the map of issued assets, which must be empty for a new faucet
+    let account_storage = create_auth_storage(
+        &auth_scheme,
+        vec![
+            (1, (StorageSlotType::Value { value_arity: 0 }, metadata)),
+            (2, (StorageSlotType::Value { value_arity: 0 }, [ZERO; 4])),
+            (
+                FAUCET_STORAGE_DATA_SLOT,
+                (StorageSlotType::Map { value_arity: 0 }, *StorageMap::new().root()),
+            ),
+        ],
+    )?;
+    let account_vault = AccountVault::new(&[])?;
+
+    let account_seed = AccountId::get_account_seed(
+        init_seed,
+        AccountType::NonFungibleFaucet,
+        false,
+        account_code.root(),
+        account_storage.root(),
+    )?;
+    let account_id = AccountId::new(account_seed, account_code.root(), account_storage.root())?;
+    Ok((
+        Account::new(account_id, account_vault, account_storage, account_code, ZERO),
+        account_seed,
+    ))
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the code of the faucet defined in the `miden::faucets::<module>` module, authenticated
+/// using the specified authentication scheme.
+///
+/// The basic faucet modules export the single-key authentication procedure, and thus they can be
+/// used as the account code directly. For other schemes, the faucet interface is re-exported
+/// alongside the respective authentication procedure.
+fn faucet_code_ast(module: &str, auth_scheme: &AuthScheme) -> Result<ModuleAst, AccountError> {
+    match auth_scheme {
+        AuthScheme::RpoFalcon512 { .. } => {
+            let miden = super::MidenLib::default();
+            let path = format!("miden::faucets::{module}");
+            let faucet_code_ast = miden
+                .get_module_ast(&LibraryPath::new(&path).unwrap())
+                .expect("Getting module AST failed");
+            Ok(faucet_code_ast.clone())
+        }
+        AuthScheme::RpoFalcon512Multisig { .. } => {
+            let auth_procedure = auth_scheme.procedure_name();
+            let account_code_string = format!(
+                "
+    use.miden::faucets::{module}->faucet
+    use.miden::auth::basic
+
+    export.faucet::distribute
+    export.faucet::burn
+    export.basic::{auth_procedure}
+
+    "
+            );
+            ModuleAst::parse(&account_code_string)
+                .map_err(|e| AccountError::AccountCodeAssemblerError(e.into()))
+        }
+    }
+}
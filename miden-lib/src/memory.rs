@@ -197,7 +197,8 @@ pub const CONSUMED_NOTE_SCRIPT_ROOT_OFFSET: MemoryOffset = 2;
 pub const CONSUMED_NOTE_INPUTS_HASH_OFFSET: MemoryOffset = 3;
 pub const CONSUMED_NOTE_VAULT_ROOT_OFFSET: MemoryOffset = 4;
 pub const CONSUMED_NOTE_METADATA_OFFSET: MemoryOffset = 5;
-pub const CONSUMED_NOTE_ASSETS_OFFSET: MemoryOffset = 6;
+pub const CONSUMED_NOTE_ARGS_OFFSET: MemoryOffset = 6;
+pub const CONSUMED_NOTE_ASSETS_OFFSET: MemoryOffset = 7;
 
 /// The maximum number of consumed notes that can be processed in a single transaction.
 pub const MAX_NUM_CONSUMED_NOTES: u32 = 1023;
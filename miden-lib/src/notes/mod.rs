@@ -4,12 +4,15 @@ use crate::memory::{
     CREATED_NOTE_METADATA_OFFSET, CREATED_NOTE_RECIPIENT_OFFSET, CREATED_NOTE_VAULT_HASH_OFFSET,
 };
 use miden_objects::{
-    accounts::AccountId,
+    accounts::{AccountId, AccountType},
     assembly::ProgramAst,
-    assets::Asset,
-    notes::{Note, NoteMetadata, NoteScript, NoteStub, NoteVault},
-    utils::{collections::Vec, vec},
-    Digest, Felt, NoteError, StarkField, Word, WORD_SIZE, ZERO,
+    assets::{Asset, FungibleAsset},
+    notes::{
+        Note, NoteExecution, NoteInputs, NoteMetadata, NoteScript, NoteStub, NoteTag, NoteType,
+        NoteVault,
+    },
+    utils::{collections::Vec, format, string::ToString, vec},
+    Digest, Felt, Hasher, NoteError, StarkField, Word, ONE, WORD_SIZE, ZERO,
 };
 
 pub enum Script {
@@ -20,23 +23,60 @@ pub enum Script {
         target: AccountId,
         recall_height: u32,
     },
+    SWAP {
+        requested_asset: Asset,
+        payback_tag: NoteTag,
+    },
+    PSWAP {
+        requested_faucet_id: AccountId,
+        offered_ratio: u32,
+        requested_ratio: u32,
+    },
+    HTLC {
+        target: AccountId,
+        hashlock: Digest,
+        timeout: u32,
+    },
 }
 
-/// Users can create notes with a standard script. Atm we provide two standard scripts:
+/// Users can create notes with a standard script. Atm we provide five standard scripts:
 /// 1. P2ID - pay to id.
 /// 2. P2IDR - pay to id with recall after a certain block height.
+/// 3. SWAP - offers the note assets to whoever pays the requested asset back to the sender. The
+///    payback is a P2ID note addressed to the sender, which is created by the consuming account
+///    in the same transaction. Its serial number is derived from the serial number of the SWAP
+///    note (see [swap_payback_serial_num()]), and it is an off-chain note tagged with the provided
+///    payback tag.
+/// 4. PSWAP - offers a single fungible asset at a fixed price ratio, such that the consuming
+///    account can take any multiple of `offered_ratio` units of the offered asset in exchange for
+///    the same multiple of `requested_ratio` units of the requested asset. The payment is sent to
+///    the sender via a P2ID note, and a new PSWAP note containing the remainder of the offered
+///    asset is created if the note is filled partially. The created notes carry the tag of the
+///    PSWAP note. The payment note is an off-chain note, while the remainder note is a public note
+///    (see [fill_partial_swap_note()]).
+/// 5. HTLC - hashed timelock contract. The target can consume the note by revealing the preimage
+///    of the hashlock via the advice map (see [htlc_hashlock()]), while the sender can reclaim the
+///    note at or after the timeout block height.
+///
+/// If no tag is provided, the note is tagged with the default tag of its script (see
+/// [default_note_tag()]).
 pub fn create_note(
     script: Script,
     assets: Vec<Asset>,
     sender: AccountId,
-    tag: Option<Felt>,
+    note_type: NoteType,
+    tag: Option<NoteTag>,
     serial_num: Word,
 ) -> Result<Note, NoteError> {
     let note_assembler = assembler();
+    let tag = tag.unwrap_or_else(|| default_note_tag(&script, &assets));
 
     // Include the binary version of the scripts into the source file at compile time
     let p2id_bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/P2ID.masb"));
     let p2idr_bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/P2IDR.masb"));
+    let swap_bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/SWAP.masb"));
+    let pswap_bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/PSWAP.masb"));
+    let htlc_bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/HTLC.masb"));
 
     let (note_script_ast, inputs): (ProgramAst, Vec<Felt>) = match script {
         Script::P2ID { target } => (
@@ -50,11 +90,257 @@ pub fn create_note(
             ProgramAst::from_bytes(p2idr_bytes).map_err(NoteError::NoteDeserializationError)?,
             vec![target.into(), recall_height.into(), ZERO, ZERO],
         ),
+        Script::SWAP {
+            requested_asset,
+            payback_tag,
+        } => {
+            let payback_note = create_note(
+                Script::P2ID { target: sender },
+                vec![requested_asset],
+                sender,
+                NoteType::OffChain,
+                Some(payback_tag),
+                swap_payback_serial_num(serial_num),
+            )?;
+
+            let mut inputs = Vec::from(Word::from(payback_note.recipient()));
+            inputs.extend_from_slice(&Word::from(requested_asset));
+            inputs.push(payback_tag.into());
+
+            (
+                ProgramAst::from_bytes(swap_bytes).map_err(NoteError::NoteDeserializationError)?,
+                inputs,
+            )
+        }
+        Script::PSWAP {
+            requested_faucet_id,
+            offered_ratio,
+            requested_ratio,
+        } => {
+            validate_partial_swap(&assets, requested_faucet_id, offered_ratio, requested_ratio)?;
+
+            let p2id_ast =
+                ProgramAst::from_bytes(p2id_bytes).map_err(NoteError::NoteDeserializationError)?;
+            let (payback_script, _) = NoteScript::new(p2id_ast, &note_assembler)?;
+            let payback_inputs = NoteInputs::new(&[sender.into(), ZERO, ZERO, ZERO])?;
+
+            let pswap_ast =
+                ProgramAst::from_bytes(pswap_bytes).map_err(NoteError::NoteDeserializationError)?;
+            let (swap_script, _) = NoteScript::new(pswap_ast.clone(), &note_assembler)?;
+
+            // the ratios are packed into a single element as requested_ratio * 2^32 + offered_ratio
+            let ratios = ((requested_ratio as u64) << 32) | offered_ratio as u64;
+            let nonce = Hasher::hash_elements(&serial_num).as_elements()[0];
+
+            let mut inputs = Vec::with_capacity(16);
+            inputs.extend_from_slice(payback_script.hash().as_elements());
+            inputs.extend_from_slice(payback_inputs.hash().as_elements());
+            inputs.extend_from_slice(swap_script.hash().as_elements());
+            inputs.extend_from_slice(&[
+                requested_faucet_id.into(),
+                Felt::new(ratios),
+                tag.into(),
+                nonce,
+            ]);
+
+            (pswap_ast, inputs)
+        }
+        Script::HTLC {
+            target,
+            hashlock,
+            timeout,
+        } => {
+            let mut inputs = vec![target.into(), timeout.into(), ZERO, ZERO];
+            inputs.extend_from_slice(hashlock.as_elements());
+
+            (
+                ProgramAst::from_bytes(htlc_bytes).map_err(NoteError::NoteDeserializationError)?,
+                inputs,
+            )
+        }
     };
 
     let (note_script, _) = NoteScript::new(note_script_ast, &note_assembler)?;
 
-    Note::new(note_script.clone(), &inputs, &assets, serial_num, sender, tag.unwrap_or(ZERO))
+    Note::new(note_script.clone(), &inputs, &assets, serial_num, sender, note_type, tag)
+}
+
+/// Returns the tag of a note with the specified script and assets which is used when no tag is
+/// provided to [create_note()].
+///
+/// - P2ID, P2IDR and HTLC notes are tagged with the ID of the target account, such that the target
+///   can find the notes addressed to it.
+/// - SWAP and PSWAP notes are tagged with the pair of the offered and the requested assets, such
+///   that potential counterparties can find the notes offering the asset they are interested in.
+pub fn default_note_tag(script: &Script, assets: &[Asset]) -> NoteTag {
+    match script {
+        Script::P2ID { target } | Script::P2IDR { target, .. } | Script::HTLC { target, .. } => {
+            NoteTag::from_account_id(*target, NoteExecution::Local)
+        }
+        Script::SWAP {
+            requested_asset, ..
+        } => match assets.first() {
+            Some(offered_asset) => NoteTag::for_swap(*offered_asset, *requested_asset),
+            None => NoteTag::default(),
+        },
+        Script::PSWAP {
+            requested_faucet_id,
+            ..
+        } => match (assets.first(), FungibleAsset::new(*requested_faucet_id, 0)) {
+            (Some(offered_asset), Ok(requested_asset)) => {
+                NoteTag::for_swap(*offered_asset, requested_asset.into())
+            }
+            _ => NoteTag::default(),
+        },
+    }
+}
+
+/// Returns the serial number of the payback note which must be created when consuming a SWAP note
+/// with the provided serial number.
+///
+/// The payback serial number is derived from the serial number of the SWAP note by incrementing
+/// its first element, such that the sender can compute it without any additional data.
+pub fn swap_payback_serial_num(serial_num: Word) -> Word {
+    [serial_num[0] + ONE, serial_num[1], serial_num[2], serial_num[3]]
+}
+
+/// Returns the hashlock of an HTLC note which can be unlocked by revealing the provided preimage.
+///
+/// When consuming the note, the target account must provide the preimage via the advice map
+/// under the hashlock key.
+pub fn htlc_hashlock(preimage: Word) -> Digest {
+    Hasher::hash_elements(&preimage)
+}
+
+/// Notes created by the consuming account when filling a PSWAP note.
+pub struct PartialSwapFill {
+    /// The P2ID note paying the requested asset to the sender of the PSWAP note.
+    pub payback_note: Note,
+    /// The PSWAP note containing the remainder of the offered asset, if the fill was partial.
+    pub remainder_note: Option<Note>,
+}
+
+/// Returns the notes which are created when the specified PSWAP note is consumed by the
+/// `consumer` account taking `fill_amount` units of the offered asset.
+///
+/// `sender` must be the account which created the original PSWAP note. The payback is always
+/// addressed to this account, including when filling a remainder note, whose metadata records the
+/// account which filled the previous note instead.
+///
+/// The serial numbers of the created notes are derived from the inputs hash of the PSWAP note and
+/// the remaining amount of the offered asset, and thus the sender can reconstruct the created
+/// notes without any additional data.
+///
+/// # Errors
+/// Returns an error if:
+/// - The note is not a valid PSWAP note, i.e., its script is not the PSWAP script, it does not
+///   have the expected number of inputs, or its assets and ratios are invalid.
+/// - The payback of the note is not addressed to `sender`.
+/// - The fill amount is zero, exceeds the offered amount, or is not a multiple of
+///   `offered_ratio`.
+/// - The fill amount or the payback amount do not fit into a u32.
+pub fn fill_partial_swap_note(
+    note: &Note,
+    sender: AccountId,
+    fill_amount: u64,
+    consumer: AccountId,
+) -> Result<PartialSwapFill, NoteError> {
+    let pswap_bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/PSWAP.masb"));
+    let pswap_ast =
+        ProgramAst::from_bytes(pswap_bytes).map_err(NoteError::NoteDeserializationError)?;
+    let (pswap_script, _) = NoteScript::new(pswap_ast, &assembler())?;
+
+    let inputs = note.inputs().inputs();
+    if note.script().hash() != pswap_script.hash() || inputs.len() != PSWAP_NOTE_NUM_INPUTS {
+        return Err(NoteError::InvalidPartialSwap(format!(
+            "Note must be a PSWAP note with {PSWAP_NOTE_NUM_INPUTS} inputs"
+        )));
+    }
+
+    // the payback note is addressed to the account whose ID hashes to the payback inputs hash
+    let payback_inputs = NoteInputs::new(&[sender.into(), ZERO, ZERO, ZERO])?;
+    if payback_inputs.hash().as_elements() != &inputs[4..8] {
+        return Err(NoteError::InvalidPartialSwap(format!(
+            "Payback of the note is not addressed to account {sender}"
+        )));
+    }
+
+    let requested_faucet_id = AccountId::try_from(inputs[12])
+        .map_err(|err| NoteError::InvalidPartialSwap(format!("{err:?}")))?;
+    let ratios = inputs[13].as_int();
+    let (requested_ratio, offered_ratio) = ((ratios >> 32) as u32, ratios as u32);
+    let tag = NoteTag::try_from(inputs[14])?;
+
+    let assets = note.vault().iter().copied().collect::<Vec<_>>();
+    validate_partial_swap(&assets, requested_faucet_id, offered_ratio, requested_ratio)?;
+    let offered_asset = match assets[0] {
+        Asset::Fungible(asset) => asset,
+        Asset::NonFungible(_) => unreachable!("offered asset was validated to be fungible"),
+    };
+
+    if fill_amount == 0 || fill_amount > offered_asset.amount() {
+        return Err(NoteError::InvalidPartialSwap(format!(
+            "Fill amount must be between 1 and {}, but was {fill_amount}",
+            offered_asset.amount()
+        )));
+    }
+    if fill_amount > u32::MAX as u64 || fill_amount % offered_ratio as u64 != 0 {
+        return Err(NoteError::InvalidPartialSwap(format!(
+            "Fill amount {fill_amount} must be a u32 multiple of {offered_ratio}"
+        )));
+    }
+    let payback_amount = fill_amount / offered_ratio as u64 * requested_ratio as u64;
+    if payback_amount > u32::MAX as u64 {
+        return Err(NoteError::InvalidPartialSwap(format!(
+            "Payback amount {payback_amount} does not fit into a u32"
+        )));
+    }
+
+    let remaining_amount = offered_asset.amount() - fill_amount;
+    let serial_num = |domain: u64| -> Word {
+        let remaining = [Felt::new(remaining_amount), Felt::new(domain), ZERO, ZERO];
+        Hasher::merge(&[note.inputs().hash(), remaining.into()]).into()
+    };
+
+    let payback_asset = FungibleAsset::new(requested_faucet_id, payback_amount)
+        .map_err(|err| NoteError::InvalidPartialSwap(format!("{err:?}")))?;
+    let payback_note = create_note(
+        Script::P2ID { target: sender },
+        vec![payback_asset.into()],
+        consumer,
+        NoteType::OffChain,
+        Some(tag),
+        serial_num(PSWAP_PAYBACK_NOTE_DOMAIN),
+    )?;
+
+    let remainder_note = if remaining_amount > 0 {
+        let remainder_asset = FungibleAsset::new(offered_asset.faucet_id(), remaining_amount)
+            .map_err(|err| NoteError::InvalidPartialSwap(format!("{err:?}")))?;
+        Some(Note::new(
+            note.script().clone(),
+            inputs,
+            &[remainder_asset.into()],
+            serial_num(PSWAP_REMAINDER_NOTE_DOMAIN),
+            consumer,
+            NoteType::Public,
+            tag,
+        )?)
+    } else {
+        None
+    };
+
+    Ok(PartialSwapFill {
+        payback_note,
+        remainder_note,
+    })
+}
+
+/// Returns the key under which the fill amount must be provided via the advice map when
+/// consuming the specified PSWAP note.
+///
+/// The key is computed as hash(INPUTS_HASH, [0; 4]).
+pub fn partial_swap_fill_key(note: &Note) -> Word {
+    Hasher::merge(&[note.inputs().hash(), Digest::default()]).into()
 }
 
 pub fn notes_try_from_elements(elements: &[Word]) -> Result<NoteStub, NoteError> {
@@ -88,3 +374,40 @@ pub fn notes_try_from_elements(elements: &[Word]) -> Result<NoteStub, NoteError>
 
     Ok(stub)
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Number of inputs of a PSWAP note.
+const PSWAP_NOTE_NUM_INPUTS: usize = 16;
+
+/// Domain separators used to derive the serial numbers of the notes created by the PSWAP script.
+const PSWAP_PAYBACK_NOTE_DOMAIN: u64 = 0;
+const PSWAP_REMAINDER_NOTE_DOMAIN: u64 = 1;
+
+/// Validates the parameters of a PSWAP note offering the provided assets.
+fn validate_partial_swap(
+    assets: &[Asset],
+    requested_faucet_id: AccountId,
+    offered_ratio: u32,
+    requested_ratio: u32,
+) -> Result<(), NoteError> {
+    if !matches!(assets, [Asset::Fungible(_)]) {
+        return Err(NoteError::InvalidPartialSwap(
+            "PSWAP note must offer exactly one fungible asset".to_string(),
+        ));
+    }
+    if !matches!(requested_faucet_id.account_type(), AccountType::FungibleFaucet) {
+        return Err(NoteError::InvalidPartialSwap(
+            "Requested asset must be issued by a fungible faucet".to_string(),
+        ));
+    }
+    // requested_ratio must be smaller than u32::MAX for the packed ratios to be a valid element
+    if offered_ratio == 0 || requested_ratio == 0 || requested_ratio == u32::MAX {
+        return Err(NoteError::InvalidPartialSwap(format!(
+            "Invalid ratios {offered_ratio}:{requested_ratio}"
+        )));
+    }
+
+    Ok(())
+}
@@ -1,5 +1,8 @@
-use super::Library;
-use std::path::PathBuf;
+use super::{transaction::TransactionKernelError, Library};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
 use vm_core::{crypto::hash::Rpo256 as Hasher, Felt, StackInputs, Word, ONE, ZERO};
 use vm_processor::{
     AdviceProvider, ContextId, DefaultHost, MemAdviceProvider, Process, ProcessState,
@@ -37,9 +40,52 @@ fn test_compile() {
     assert!(exists);
 }
 
+#[test]
+fn test_kernel_error_codes() {
+    let mut masm_files = Vec::new();
+    collect_masm_files(&build_module_path("miden/sat", ""), &mut masm_files);
+
+    let mut num_errors = 0;
+    for file in masm_files {
+        let source = fs::read_to_string(&file).unwrap();
+        for line in source.lines() {
+            let Some(constant) = line.trim().strip_prefix("const.ERR_") else {
+                continue;
+            };
+            let (name, code) = constant.split_once('=').unwrap();
+
+            // ERR_PROLOGUE_BLOCK_HASH_MISMATCH is expected to map to PrologueBlockHashMismatch
+            let variant_name: String = name
+                .split('_')
+                .map(|word| word[..1].to_string() + &word[1..].to_lowercase())
+                .collect();
+
+            let code = code.parse::<u32>().unwrap();
+            let error = TransactionKernelError::from_code(code)
+                .unwrap_or_else(|| panic!("no kernel error with code {code} (ERR_{name})"));
+            assert_eq!(format!("{error:?}"), variant_name);
+            assert_eq!(error.code(), code);
+            num_errors += 1;
+        }
+    }
+
+    assert_eq!(num_errors, TransactionKernelError::ALL.len());
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
 fn build_module_path(dir: &str, file: &str) -> PathBuf {
     [env!("CARGO_MANIFEST_DIR"), "asm", dir, file].iter().collect()
 }
+
+fn collect_masm_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_masm_files(&path, files);
+        } else if path.extension().map_or(false, |ext| ext == "masm") {
+            files.push(path);
+        }
+    }
+}
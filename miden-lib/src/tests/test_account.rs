@@ -1,5 +1,8 @@
 use super::{ContextId, Felt, MemAdviceProvider, ProcessState, StackInputs, Word, ONE, ZERO};
-use crate::memory::{ACCT_CODE_ROOT_PTR, ACCT_NEW_CODE_ROOT_PTR};
+use crate::{
+    memory::{ACCT_CODE_ROOT_PTR, ACCT_NEW_CODE_ROOT_PTR},
+    transaction::TransactionKernelError,
+};
 use miden_objects::accounts::{
     AccountId, AccountType, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_INSUFFICIENT_ONES,
     ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN, ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
@@ -7,8 +10,12 @@ use miden_objects::accounts::{
 };
 use mock::{
     constants::{
-        storage_item_0, storage_item_1, CHILD_ROOT_PARENT_LEAF_INDEX, CHILD_SMT_DEPTH,
-        CHILD_STORAGE_INDEX_0, CHILD_STORAGE_VALUE_0,
+        storage_array_3, storage_item_0, storage_item_1, storage_map_2,
+        CHILD_ROOT_PARENT_LEAF_INDEX, CHILD_SMT_DEPTH, CHILD_STORAGE_INDEX_0,
+        CHILD_STORAGE_VALUE_0, STORAGE_ARRAY_INDEX_0, STORAGE_ARRAY_INDEX_1, STORAGE_ARRAY_VALUE_0,
+        STORAGE_ARRAY_VALUE_1, STORAGE_INDEX_0, STORAGE_INDEX_2, STORAGE_INDEX_3, STORAGE_INDEX_4,
+        STORAGE_MAP_KEY_0, STORAGE_MAP_KEY_1, STORAGE_MAP_VALUE_0, STORAGE_MAP_VALUE_1,
+        STORAGE_WORDS_4,
     },
     mock::{
         account::MockAccountType, notes::AssetPreservationStatus, transaction::mock_executed_tx,
@@ -19,6 +26,13 @@ use mock::{
     run_tx, run_within_tx_kernel,
 };
 
+// CONSTANTS
+// ================================================================================================
+
+/// Word written right after the memory region of a multi-word value to check that the memory
+/// outside of the region is not modified.
+const SENTINEL_WORD: Word = [Felt::new(7), Felt::new(7), Felt::new(7), Felt::new(7)];
+
 // TESTS
 // ================================================================================================
 
@@ -186,6 +200,26 @@ fn test_validate_id_fails_on_insuficcient_ones() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_incr_nonce_fails_on_non_u32_value() {
+    let code = "
+        use.miden::sat::internal::account
+
+        begin
+            push.4294967296
+            exec.account::incr_nonce
+        end
+        ";
+
+    let result =
+        run_within_tx_kernel("", code, StackInputs::default(), MemAdviceProvider::default(), None);
+
+    assert_eq!(
+        TransactionKernelError::from_execution_error(&result.err().unwrap()),
+        Some(TransactionKernelError::AccountNonceIncrementNotU32)
+    );
+}
+
 #[test]
 fn test_get_item() {
     for storage_item in [storage_item_0(), storage_item_1()] {
@@ -361,6 +395,621 @@ fn test_set_item() {
     .unwrap();
 }
 
+#[test]
+fn test_get_map_item() {
+    for (key, value) in [
+        (STORAGE_MAP_KEY_0, STORAGE_MAP_VALUE_0),
+        (STORAGE_MAP_KEY_1, STORAGE_MAP_VALUE_1),
+        ([ONE, ZERO, ONE, ZERO], [ZERO; 4]),
+    ] {
+        let (account, block_header, chain, notes, auxiliary_data) =
+            mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+        let code = format!(
+            "
+        use.miden::sat::account
+        use.miden::sat::internal::prologue
+
+        begin
+            # prepare the transaction
+            exec.prologue::prepare_transaction
+
+            # push the map key and the account storage slot index
+            push.{key} push.{STORAGE_INDEX_2}
+
+            # get the map item
+            exec.account::get_map_item
+
+            # assert the item value is correct
+            push.{value} assert_eqw
+        end
+        ",
+            key = prepare_word(&key),
+            value = prepare_word(&value),
+        );
+
+        let transaction = prepare_transaction(
+            account,
+            None,
+            block_header,
+            chain,
+            notes,
+            None,
+            auxiliary_data,
+            &code,
+            "",
+            None,
+        );
+
+        let _process = run_tx(
+            transaction.tx_program().clone(),
+            StackInputs::from(transaction.stack_inputs()),
+            MemAdviceProvider::from(transaction.advice_provider_inputs()),
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn test_get_map_item_fails_on_non_map_slot() {
+    let (account, block_header, chain, notes, auxiliary_data) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    let code = format!(
+        "
+        use.miden::sat::account
+        use.miden::sat::internal::prologue
+
+        begin
+            exec.prologue::prepare_transaction
+            push.{key} push.{STORAGE_INDEX_0}
+            exec.account::get_map_item
+        end
+        ",
+        key = prepare_word(&STORAGE_MAP_KEY_0),
+    );
+
+    let transaction = prepare_transaction(
+        account,
+        None,
+        block_header,
+        chain,
+        notes,
+        None,
+        auxiliary_data,
+        &code,
+        "",
+        None,
+    );
+
+    let process = run_tx(
+        transaction.tx_program().clone(),
+        StackInputs::from(transaction.stack_inputs()),
+        MemAdviceProvider::from(transaction.advice_provider_inputs()),
+    );
+
+    assert_eq!(
+        TransactionKernelError::from_execution_error(&process.err().unwrap()),
+        Some(TransactionKernelError::AccountStorageSlotNotMap)
+    );
+}
+
+#[test]
+fn test_get_map_item_fails_on_non_u32_index() {
+    let (account, block_header, chain, notes, auxiliary_data) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    let code = format!(
+        "
+        use.miden::sat::account
+        use.miden::sat::internal::prologue
+
+        begin
+            exec.prologue::prepare_transaction
+            push.{key} push.4294967296
+            exec.account::get_map_item
+        end
+        ",
+        key = prepare_word(&STORAGE_MAP_KEY_0),
+    );
+
+    let transaction = prepare_transaction(
+        account,
+        None,
+        block_header,
+        chain,
+        notes,
+        None,
+        auxiliary_data,
+        &code,
+        "",
+        None,
+    );
+
+    let process = run_tx(
+        transaction.tx_program().clone(),
+        StackInputs::from(transaction.stack_inputs()),
+        MemAdviceProvider::from(transaction.advice_provider_inputs()),
+    );
+
+    assert_eq!(
+        TransactionKernelError::from_execution_error(&process.err().unwrap()),
+        Some(TransactionKernelError::LayoutStorageSlotIndexNotU32)
+    );
+}
+
+#[test]
+fn test_set_map_item() {
+    let (account, block_header, chain, notes, auxiliary_data) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    // compute the expected map root after the update
+    const NEW_VALUE: Word = [Felt::new(9), Felt::new(8), Felt::new(7), Felt::new(6)];
+    let init_map = storage_map_2();
+    let mut new_map = init_map.clone();
+    new_map.insert(STORAGE_MAP_KEY_0.into(), NEW_VALUE);
+
+    // compute the expected storage root after the update
+    let mut new_storage = account.storage().clone();
+    new_storage.set_map_item(STORAGE_INDEX_2, STORAGE_MAP_KEY_0, NEW_VALUE).unwrap();
+
+    let code = format!(
+        "
+        use.miden::sat::account
+        use.miden::sat::internal::layout
+        use.miden::sat::internal::prologue
+
+        begin
+            # prepare the transaction
+            exec.prologue::prepare_transaction
+
+            # push the new value, the map key and the account storage slot index
+            push.{new_value} push.{key} push.{STORAGE_INDEX_2}
+
+            # set the map item
+            exec.account::set_map_item
+
+            # assert the old map root and the old value are correct
+            push.{init_map_root} assert_eqw
+            push.{old_value} assert_eqw
+
+            # assert the new map root is stored in the account storage
+            push.{STORAGE_INDEX_2} exec.account::get_item
+            push.{new_map_root} assert_eqw
+
+            # assert the new value can be read back from the map
+            push.{key} push.{STORAGE_INDEX_2} exec.account::get_map_item
+            push.{new_value} assert_eqw
+
+            # assert the storage root is correct
+            exec.layout::get_acct_storage_root
+            push.{new_storage_root} assert_eqw
+        end
+        ",
+        new_value = prepare_word(&NEW_VALUE),
+        key = prepare_word(&STORAGE_MAP_KEY_0),
+        old_value = prepare_word(&STORAGE_MAP_VALUE_0),
+        init_map_root = prepare_word(&init_map.root()),
+        new_map_root = prepare_word(&new_map.root()),
+        new_storage_root = prepare_word(&new_storage.root()),
+    );
+
+    let transaction = prepare_transaction(
+        account,
+        None,
+        block_header,
+        chain,
+        notes,
+        None,
+        auxiliary_data,
+        &code,
+        "",
+        None,
+    );
+
+    let _process = run_tx(
+        transaction.tx_program().clone(),
+        StackInputs::from(transaction.stack_inputs()),
+        MemAdviceProvider::from(transaction.advice_provider_inputs()),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_get_array_item() {
+    for (item_index, value) in [
+        (STORAGE_ARRAY_INDEX_0, STORAGE_ARRAY_VALUE_0),
+        (STORAGE_ARRAY_INDEX_1, STORAGE_ARRAY_VALUE_1),
+        (STORAGE_ARRAY_INDEX_0 + 1, [ZERO; 4]),
+    ] {
+        let (account, block_header, chain, notes, auxiliary_data) =
+            mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+        let code = format!(
+            "
+        use.miden::sat::account
+        use.miden::sat::internal::prologue
+
+        begin
+            # prepare the transaction
+            exec.prologue::prepare_transaction
+
+            # push the item index and the account storage slot index
+            push.{item_index} push.{STORAGE_INDEX_3}
+
+            # get the array item
+            exec.account::get_array_item
+
+            # assert the item value is correct
+            push.{value} assert_eqw
+        end
+        ",
+            value = prepare_word(&value),
+        );
+
+        let transaction = prepare_transaction(
+            account,
+            None,
+            block_header,
+            chain,
+            notes,
+            None,
+            auxiliary_data,
+            &code,
+            "",
+            None,
+        );
+
+        let _process = run_tx(
+            transaction.tx_program().clone(),
+            StackInputs::from(transaction.stack_inputs()),
+            MemAdviceProvider::from(transaction.advice_provider_inputs()),
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn test_get_array_item_fails_on_invalid_access() {
+    for (slot_index, item_index) in [
+        // slot is a map slot
+        (STORAGE_INDEX_2, STORAGE_ARRAY_INDEX_0),
+        // item index is outside of the array capacity
+        (STORAGE_INDEX_3, 1_u64 << storage_array_3().depth()),
+    ] {
+        let (account, block_header, chain, notes, auxiliary_data) =
+            mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+        let code = format!(
+            "
+        use.miden::sat::account
+        use.miden::sat::internal::prologue
+
+        begin
+            exec.prologue::prepare_transaction
+            push.{item_index} push.{slot_index}
+            exec.account::get_array_item
+        end
+        "
+        );
+
+        let transaction = prepare_transaction(
+            account,
+            None,
+            block_header,
+            chain,
+            notes,
+            None,
+            auxiliary_data,
+            &code,
+            "",
+            None,
+        );
+
+        let process = run_tx(
+            transaction.tx_program().clone(),
+            StackInputs::from(transaction.stack_inputs()),
+            MemAdviceProvider::from(transaction.advice_provider_inputs()),
+        );
+
+        assert!(process.is_err());
+    }
+}
+
+#[test]
+fn test_set_array_item() {
+    let (account, block_header, chain, notes, auxiliary_data) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    // compute the expected array root after the update
+    const NEW_VALUE: Word = [Felt::new(9), Felt::new(8), Felt::new(7), Felt::new(6)];
+    let init_array = storage_array_3();
+    let mut new_array = init_array.clone();
+    new_array.set(STORAGE_ARRAY_INDEX_0, NEW_VALUE).unwrap();
+
+    // compute the expected storage root after the update
+    let mut new_storage = account.storage().clone();
+    new_storage
+        .set_array_item(STORAGE_INDEX_3, STORAGE_ARRAY_INDEX_0, NEW_VALUE)
+        .unwrap();
+
+    let code = format!(
+        "
+        use.miden::sat::account
+        use.miden::sat::internal::layout
+        use.miden::sat::internal::prologue
+
+        begin
+            # prepare the transaction
+            exec.prologue::prepare_transaction
+
+            # push the new value, the item index and the account storage slot index
+            push.{new_value} push.{STORAGE_ARRAY_INDEX_0} push.{STORAGE_INDEX_3}
+
+            # set the array item
+            exec.account::set_array_item
+
+            # assert the old array root and the old value are correct
+            push.{init_array_root} assert_eqw
+            push.{old_value} assert_eqw
+
+            # assert the new array root is stored in the account storage
+            push.{STORAGE_INDEX_3} exec.account::get_item
+            push.{new_array_root} assert_eqw
+
+            # assert the new value can be read back from the array
+            push.{STORAGE_ARRAY_INDEX_0} push.{STORAGE_INDEX_3} exec.account::get_array_item
+            push.{new_value} assert_eqw
+
+            # assert the storage root is correct
+            exec.layout::get_acct_storage_root
+            push.{new_storage_root} assert_eqw
+        end
+        ",
+        new_value = prepare_word(&NEW_VALUE),
+        old_value = prepare_word(&STORAGE_ARRAY_VALUE_0),
+        init_array_root = prepare_word(&init_array.root()),
+        new_array_root = prepare_word(&new_array.root()),
+        new_storage_root = prepare_word(&new_storage.root()),
+    );
+
+    let transaction = prepare_transaction(
+        account,
+        None,
+        block_header,
+        chain,
+        notes,
+        None,
+        auxiliary_data,
+        &code,
+        "",
+        None,
+    );
+
+    let _process = run_tx(
+        transaction.tx_program().clone(),
+        StackInputs::from(transaction.stack_inputs()),
+        MemAdviceProvider::from(transaction.advice_provider_inputs()),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_get_item_words() {
+    const DEST_POINTER: u32 = 100000000;
+
+    let (account, block_header, chain, notes, auxiliary_data) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    // assert exactly the words of the value are written into memory; the word following the last
+    // word is left unchanged even though the number of words is odd
+    let sentinel_ptr = DEST_POINTER + STORAGE_WORDS_4.len() as u32;
+    let word_assertions = STORAGE_WORDS_4
+        .iter()
+        .chain([SENTINEL_WORD].iter())
+        .enumerate()
+        .map(|(i, word)| {
+            format!(
+                "padw push.{ptr} mem_loadw push.{word} assert_eqw",
+                ptr = DEST_POINTER + i as u32,
+                word = prepare_word(word)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let code = format!(
+        "
+        use.miden::sat::account
+        use.miden::sat::internal::prologue
+
+        begin
+            # prepare the transaction
+            exec.prologue::prepare_transaction
+
+            # write a sentinel word right after the memory region of the value
+            push.{sentinel_word} push.{sentinel_ptr} mem_storew dropw
+
+            # push the destination pointer and the account storage slot index
+            push.{DEST_POINTER} push.{STORAGE_INDEX_4}
+
+            # get the words of the value
+            exec.account::get_item_words
+
+            # assert the number of words and the pointer are correct
+            push.{num_words} assert_eq
+            push.{DEST_POINTER} assert_eq
+
+            # assert the words are correct
+            {word_assertions}
+        end
+        ",
+        num_words = STORAGE_WORDS_4.len(),
+        sentinel_word = prepare_word(&SENTINEL_WORD),
+    );
+
+    let transaction = prepare_transaction(
+        account,
+        None,
+        block_header,
+        chain,
+        notes,
+        None,
+        auxiliary_data,
+        &code,
+        "",
+        None,
+    );
+
+    let _process = run_tx(
+        transaction.tx_program().clone(),
+        StackInputs::from(transaction.stack_inputs()),
+        MemAdviceProvider::from(transaction.advice_provider_inputs()),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_get_item_words_fails_on_single_word_slot() {
+    let (account, block_header, chain, notes, auxiliary_data) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    let code = format!(
+        "
+        use.miden::sat::account
+        use.miden::sat::internal::prologue
+
+        begin
+            exec.prologue::prepare_transaction
+            push.100000000 push.{STORAGE_INDEX_0}
+            exec.account::get_item_words
+        end
+        "
+    );
+
+    let transaction = prepare_transaction(
+        account,
+        None,
+        block_header,
+        chain,
+        notes,
+        None,
+        auxiliary_data,
+        &code,
+        "",
+        None,
+    );
+
+    let process = run_tx(
+        transaction.tx_program().clone(),
+        StackInputs::from(transaction.stack_inputs()),
+        MemAdviceProvider::from(transaction.advice_provider_inputs()),
+    );
+
+    assert!(process.is_err());
+}
+
+#[test]
+fn test_set_item_words() {
+    const SRC_POINTER: u32 = 100000000;
+    const DEST_POINTER: u32 = 200000000;
+
+    let (account, block_header, chain, notes, auxiliary_data) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    // compute the expected commitment and storage root after the update
+    let new_words = vec![
+        [Felt::new(9), Felt::new(8), Felt::new(7), Felt::new(6)],
+        [Felt::new(5), Felt::new(4), Felt::new(3), Felt::new(2)],
+        [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)],
+    ];
+    let mut new_storage = account.storage().clone();
+    let old_commitment = new_storage.set_item_words(STORAGE_INDEX_4, new_words.clone()).unwrap();
+    let new_commitment = new_storage.get_item(STORAGE_INDEX_4);
+
+    // write the new words into memory, and assert they can be read back after the update
+    let mut store_words = Vec::new();
+    let mut word_assertions = Vec::new();
+    for (i, word) in new_words.iter().enumerate() {
+        let word = prepare_word(word);
+        let src_ptr = SRC_POINTER + i as u32;
+        let dest_ptr = DEST_POINTER + i as u32;
+        store_words.push(format!("push.{word} push.{src_ptr} mem_storew dropw"));
+        word_assertions.push(format!("padw push.{dest_ptr} mem_loadw push.{word} assert_eqw"));
+    }
+
+    // the word following the last word in memory must not be modified as the number of words is odd
+    let sentinel_ptr = SRC_POINTER + new_words.len() as u32;
+    store_words.push(format!(
+        "push.{word} push.{sentinel_ptr} mem_storew dropw",
+        word = prepare_word(&SENTINEL_WORD)
+    ));
+    word_assertions.push(format!(
+        "padw push.{sentinel_ptr} mem_loadw push.{word} assert_eqw",
+        word = prepare_word(&SENTINEL_WORD)
+    ));
+
+    let code = format!(
+        "
+        use.miden::sat::account
+        use.miden::sat::internal::prologue
+
+        begin
+            # prepare the transaction
+            exec.prologue::prepare_transaction
+
+            # write the new words into memory
+            {store_words}
+
+            # push the source pointer and the account storage slot index
+            push.{SRC_POINTER} push.{STORAGE_INDEX_4}
+
+            # set the words of the value
+            exec.account::set_item_words
+
+            # assert the new storage root and the old commitment are correct
+            push.{new_storage_root} assert_eqw
+            push.{old_commitment} assert_eqw
+
+            # assert the new commitment is stored in the account storage
+            push.{STORAGE_INDEX_4} exec.account::get_item
+            push.{new_commitment} assert_eqw
+
+            # assert the new words can be read back via the advice map
+            push.{DEST_POINTER} push.{STORAGE_INDEX_4} exec.account::get_item_words
+            push.{num_words} assert_eq drop
+            {word_assertions}
+        end
+        ",
+        store_words = store_words.join("\n"),
+        new_storage_root = prepare_word(&new_storage.root()),
+        old_commitment = prepare_word(&old_commitment),
+        new_commitment = prepare_word(&new_commitment),
+        num_words = new_words.len(),
+        word_assertions = word_assertions.join("\n"),
+    );
+
+    let transaction = prepare_transaction(
+        account,
+        None,
+        block_header,
+        chain,
+        notes,
+        None,
+        auxiliary_data,
+        &code,
+        "",
+        None,
+    );
+
+    let _process = run_tx(
+        transaction.tx_program().clone(),
+        StackInputs::from(transaction.stack_inputs()),
+        MemAdviceProvider::from(transaction.advice_provider_inputs()),
+    )
+    .unwrap();
+}
+
 #[test]
 fn test_is_faucet_procedure() {
     let test_cases = vec![
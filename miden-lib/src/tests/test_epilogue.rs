@@ -6,6 +6,7 @@ use crate::{
     outputs::{
         CREATED_NOTES_COMMITMENT_WORD_IDX, FINAL_ACCOUNT_HASH_WORD_IDX, TX_SCRIPT_ROOT_WORD_IDX,
     },
+    transaction::TransactionKernelError,
 };
 use mock::{
     mock::{notes::AssetPreservationStatus, transaction::mock_executed_tx},
@@ -221,5 +222,8 @@ fn test_epilogue_increment_nonce_violation() {
         Some(assembly_file),
     );
 
-    assert!(process.is_err());
+    assert_eq!(
+        TransactionKernelError::from_execution_error(&process.err().unwrap()),
+        Some(TransactionKernelError::EpilogueNonceNotIncremented)
+    );
 }
@@ -1,11 +1,15 @@
 use super::{
     AdviceProvider, ContextId, DefaultHost, Felt, MemAdviceProvider, Process, ProcessState, ZERO,
 };
-use crate::memory::CURRENT_CONSUMED_NOTE_PTR;
+use crate::{memory::CURRENT_CONSUMED_NOTE_PTR, transaction::TransactionKernelError};
 use miden_objects::{notes::Note, transaction::PreparedTransaction};
 use mock::{
     consumed_note_data_ptr,
-    mock::{account::MockAccountType, notes::AssetPreservationStatus, transaction::mock_inputs},
+    mock::{
+        account::MockAccountType,
+        notes::AssetPreservationStatus,
+        transaction::{mock_inputs, mock_inputs_with_existing},
+    },
     prepare_transaction,
     procedures::prepare_word,
     run_tx,
@@ -52,7 +56,10 @@ fn test_get_sender_no_sender() {
         transaction.stack_inputs(),
         MemAdviceProvider::from(transaction.advice_provider_inputs()),
     );
-    assert!(process.is_err());
+    assert_eq!(
+        TransactionKernelError::from_execution_error(&process.err().unwrap()),
+        Some(TransactionKernelError::NoteGetSenderNoNoteProcessed)
+    );
 }
 
 #[test]
@@ -293,20 +300,6 @@ fn test_get_inputs() {
 
     const DEST_POINTER_NOTE_0: u32 = 100000000;
 
-    fn construct_input_assertions(note: &Note) -> String {
-        let mut code = String::new();
-        for input_word in note.inputs().inputs().chunks(WORD_SIZE) {
-            code += &format!(
-                "
-                # assert the asset is correct
-                dup padw movup.4 mem_loadw push.{input_word} assert_eqw push.1 add
-                ",
-                input_word = prepare_word(input_word.try_into().unwrap())
-            );
-        }
-        code
-    }
-
     // calling get_assets should return assets at the specified address
     let code = format!(
         "
@@ -321,10 +314,11 @@ fn test_get_inputs() {
             # set the destination pointer for note 0 assets
             push.{DEST_POINTER_NOTE_0}
 
-            # get the assets
+            # get the inputs
             exec.note::get_inputs
 
-            # assert the correct pointer is returned
+            # assert the correct number of inputs and pointer are returned
+            eq.{NUM_INPUTS_NOTE_0} assert
             dup eq.{DEST_POINTER_NOTE_0} assert
 
             # apply note 1 input assertions
@@ -345,6 +339,7 @@ fn test_get_inputs() {
             call.process_note_0
         end
         ",
+        NUM_INPUTS_NOTE_0 = notes[0].note().inputs().num_inputs(),
         NOTE_1_INPUT_ASSERTIONS = construct_input_assertions(&notes[0].note()),
     );
 
@@ -369,6 +364,101 @@ fn test_get_inputs() {
     .unwrap();
 }
 
+#[test]
+fn test_get_inputs_many() {
+    let (_, _, _, notes, _) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    // create a note with 20 inputs, which are read from the advice provider in 3 chunks
+    let note = notes[0].note();
+    let note_inputs = (1..=20).map(Felt::new).collect::<Vec<_>>();
+    let note = Note::new(
+        note.script().clone(),
+        &note_inputs,
+        &note.vault().iter().copied().collect::<Vec<_>>(),
+        note.serial_num(),
+        note.metadata().sender(),
+        note.metadata().note_type(),
+        note.metadata().tag(),
+    )
+    .unwrap();
+
+    let (account, block_header, chain, notes, auxiliary_data) = mock_inputs_with_existing(
+        MockAccountType::StandardExisting,
+        AssetPreservationStatus::Preserved,
+        None,
+        Some(vec![note.clone()]),
+    );
+
+    const DEST_POINTER_NOTE_0: u32 = 100000000;
+
+    let code = format!(
+        "
+        use.miden::sat::internal::prologue
+        use.miden::sat::internal::note->note_internal
+        use.miden::sat::note
+
+        proc.process_note_0
+            # drop the note inputs
+            dropw
+
+            # assert the inputs hash is correct
+            exec.note::get_inputs_hash push.{INPUTS_HASH} assert_eqw
+
+            # set the destination pointer for note 0 inputs
+            push.{DEST_POINTER_NOTE_0}
+
+            # get the inputs
+            exec.note::get_inputs
+
+            # assert the correct number of inputs and pointer are returned
+            eq.{NUM_INPUTS_NOTE_0} assert
+            dup eq.{DEST_POINTER_NOTE_0} assert
+
+            # apply note 0 input assertions
+            {NOTE_0_INPUT_ASSERTIONS}
+
+            # clean the pointer
+            drop
+        end
+
+        begin
+            # prepare tx
+            exec.prologue::prepare_transaction
+
+            # prepare note 0
+            exec.note_internal::prepare_note
+
+            # process note 0
+            call.process_note_0
+        end
+        ",
+        INPUTS_HASH = prepare_word(&note.inputs().hash().into()),
+        NUM_INPUTS_NOTE_0 = note_inputs.len(),
+        NOTE_0_INPUT_ASSERTIONS = construct_input_assertions(&note),
+    );
+
+    let inputs = prepare_transaction(
+        account,
+        None,
+        block_header,
+        chain,
+        notes,
+        None,
+        auxiliary_data,
+        &code,
+        "",
+        None,
+    );
+
+    let _process = run_tx(
+        inputs.tx_program().clone(),
+        inputs.stack_inputs(),
+        MemAdviceProvider::from(inputs.advice_provider_inputs()),
+    )
+    .unwrap();
+}
+
 #[test]
 fn test_note_setup() {
     let (account, block_header, chain, notes, auxiliary_data) =
@@ -429,3 +519,17 @@ fn note_setup_memory_assertions<A: AdviceProvider>(process: &Process<DefaultHost
         Felt::try_from(consumed_note_data_ptr(0)).unwrap()
     );
 }
+
+fn construct_input_assertions(note: &Note) -> String {
+    let mut code = String::new();
+    for input_word in note.inputs().to_padded_inputs().chunks(WORD_SIZE) {
+        code += &format!(
+            "
+            # assert the input word is correct
+            dup padw movup.4 mem_loadw push.{input_word} assert_eqw push.1 add
+            ",
+            input_word = prepare_word(input_word.try_into().unwrap())
+        );
+    }
+    code
+}
@@ -327,14 +327,22 @@ fn consumed_notes_memory_assertions<A: AdviceProvider>(
             Word::from(note.note().metadata())
         );
 
-        // The assets should be stored at (CONSUMED_NOTES_OFFSET + (note_index + 1) * 1024 + 6..)
+        // The note args should be stored at (CONSUMED_NOTES_OFFSET + (note_index + 1) * 1024 + 6)
+        assert_eq!(
+            process
+                .get_mem_value(ContextId::root(), consumed_note_data_ptr(note_idx) + 6)
+                .unwrap(),
+            inputs.consumed_notes().note_args()[note_idx as usize]
+        );
+
+        // The assets should be stored at (CONSUMED_NOTES_OFFSET + (note_index + 1) * 1024 + 7..)
         for (asset, asset_idx) in note.note().vault().iter().cloned().zip(0u32..) {
             let word: Word = asset.into();
             assert_eq!(
                 process
                     .get_mem_value(
                         ContextId::root(),
-                        consumed_note_data_ptr(note_idx) + 6 + asset_idx
+                        consumed_note_data_ptr(note_idx) + 7 + asset_idx
                     )
                     .unwrap(),
                 word
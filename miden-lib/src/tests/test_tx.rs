@@ -1,9 +1,15 @@
 use super::{ContextId, Felt, MemAdviceProvider, ProcessState, StackInputs, Word, ONE, ZERO};
-use crate::memory::{
-    CREATED_NOTE_ASSETS_OFFSET, CREATED_NOTE_METADATA_OFFSET, CREATED_NOTE_RECIPIENT_OFFSET,
-    CREATED_NOTE_SECTION_OFFSET, NUM_CREATED_NOTES_PTR,
+use crate::{
+    memory::{
+        CREATED_NOTE_ASSETS_OFFSET, CREATED_NOTE_METADATA_OFFSET, CREATED_NOTE_RECIPIENT_OFFSET,
+        CREATED_NOTE_SECTION_OFFSET, NUM_CREATED_NOTES_PTR,
+    },
+    transaction::TransactionKernelError,
+};
+use miden_objects::{
+    notes::{Note, NoteTag, NoteType},
+    transaction::utils::generate_created_notes_commitment,
 };
-use miden_objects::{notes::Note, transaction::utils::generate_created_notes_commitment};
 use mock::{
     constants::ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
     mock::{account::MockAccountType, notes::AssetPreservationStatus, transaction::mock_inputs},
@@ -19,6 +25,7 @@ fn test_create_note() {
     let account_id = account.id();
 
     let recipient = [ZERO, ONE, Felt::new(2), Felt::new(3)];
+    let note_type = NoteType::OffChain;
     let tag = Felt::new(4);
     let asset = [Felt::new(10), ZERO, ZERO, Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN)];
 
@@ -31,6 +38,7 @@ fn test_create_note() {
         exec.prologue::prepare_transaction
 
         push.{recipient}
+        push.{note_type}
         push.{tag}
         push.{asset}
 
@@ -38,6 +46,7 @@ fn test_create_note() {
     end
     ",
         recipient = prepare_word(&recipient),
+        note_type = note_type,
         tag = tag,
         asset = prepare_word(&asset)
     );
@@ -87,7 +96,7 @@ fn test_create_note() {
                 CREATED_NOTE_SECTION_OFFSET + CREATED_NOTE_METADATA_OFFSET
             )
             .unwrap(),
-        [ONE, tag, Felt::from(account_id), ZERO]
+        [ONE, tag, Felt::from(account_id), note_type.into()]
     );
 
     // assert the asset is stored at the correct memory location.
@@ -122,6 +131,7 @@ fn test_create_note_too_many_notes() {
         exec.layout::set_num_created_notes
 
         push.{recipient}
+        push.{note_type}
         push.{tag}
         push.{asset}
 
@@ -129,6 +139,7 @@ fn test_create_note_too_many_notes() {
     end
     ",
         recipient = prepare_word(&recipient),
+        note_type = NoteType::OffChain,
         tag = tag,
         asset = prepare_word(&asset)
     );
@@ -140,6 +151,39 @@ fn test_create_note_too_many_notes() {
     assert!(process.is_err());
 }
 
+#[test]
+fn test_create_note_invalid_note_type() {
+    let recipient = [ZERO, ONE, Felt::new(2), Felt::new(3)];
+    let tag = Felt::new(4);
+    let asset = [Felt::new(10), ZERO, ZERO, Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN)];
+
+    let code = format!(
+        "
+    use.miden::sat::tx
+
+    begin
+        push.{recipient}
+        push.4
+        push.{tag}
+        push.{asset}
+
+        exec.tx::create_note
+    end
+    ",
+        recipient = prepare_word(&recipient),
+        tag = tag,
+        asset = prepare_word(&asset)
+    );
+
+    let process =
+        run_within_tx_kernel("", &code, StackInputs::default(), MemAdviceProvider::default(), None);
+
+    assert_eq!(
+        TransactionKernelError::from_execution_error(&process.err().unwrap()),
+        Some(TransactionKernelError::TxInvalidNoteType)
+    );
+}
+
 #[test]
 fn test_get_output_notes_hash() {
     let (account, block_header, chain, notes, auxiliary_data) =
@@ -153,26 +197,28 @@ fn test_get_output_notes_hash() {
 
     // create output note 1
     let output_serial_no_1 = [Felt::new(8); 4];
-    let output_tag_1 = Felt::new(8888);
+    let output_tag_1 = NoteTag::from(8888);
     let output_note_1 = Note::new(
         input_note_1.script().clone(),
         &[],
         &[input_asset_1],
         output_serial_no_1,
         account.id(),
+        NoteType::Public,
         output_tag_1,
     )
     .unwrap();
 
     // create output note 2
     let output_serial_no_2 = [Felt::new(11); 4];
-    let output_tag_2 = Felt::new(1111);
+    let output_tag_2 = NoteTag::from(1111);
     let output_note_2 = Note::new(
         input_note_2.script().clone(),
         &[],
         &[input_asset_2],
         output_serial_no_2,
         account.id(),
+        NoteType::OffChain,
         output_tag_2,
     )
     .unwrap();
@@ -191,6 +237,7 @@ fn test_get_output_notes_hash() {
 
         # create output note 1
         push.{recipient_1}
+        push.{note_type_1}
         push.{tag_1}
         push.{asset_1}
         exec.tx::create_note
@@ -198,6 +245,7 @@ fn test_get_output_notes_hash() {
 
         # create output note 2
         push.{recipient_2}
+        push.{note_type_2}
         push.{tag_2}
         push.{asset_2}
         exec.tx::create_note
@@ -209,11 +257,13 @@ fn test_get_output_notes_hash() {
     end
     ",
         recipient_1 = prepare_word(&*output_note_1.recipient()),
+        note_type_1 = output_note_1.metadata().note_type(),
         tag_1 = output_note_1.metadata().tag(),
         asset_1 = prepare_word(&Word::from(
             **output_note_1.vault().iter().take(1).collect::<Vec<_>>().first().unwrap()
         )),
         recipient_2 = prepare_word(&*output_note_2.recipient()),
+        note_type_2 = output_note_2.metadata().note_type(),
         tag_2 = output_note_2.metadata().tag(),
         asset_2 = prepare_word(&Word::from(
             **output_note_2.vault().iter().take(1).collect::<Vec<_>>().first().unwrap()
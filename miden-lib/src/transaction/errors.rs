@@ -0,0 +1,390 @@
+use core::fmt;
+use vm_processor::ExecutionError;
+
+// TRANSACTION KERNEL ERROR
+// ================================================================================================
+
+/// Errors raised by the assertions of the transaction kernel.
+///
+/// Each variant corresponds to a distinct error code which the kernel attaches to the assertion
+/// raising it. The error codes are defined as `ERR_*` constants in the kernel modules, and are
+/// grouped by module as follows:
+/// - 131072..131328: prologue.
+/// - 131328..131584: epilogue.
+/// - 131584..131840: asset vault.
+/// - 131840..132096: faucet.
+/// - 132096..132352: transaction.
+/// - 132352..132608: account.
+/// - 132608..132864: asset.
+/// - 132864..133120: note.
+/// - 133120..133376: kernel procedures exposed to accounts and notes.
+/// - 133376..133632: memory layout.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum TransactionKernelError {
+    // PROLOGUE
+    PrologueBlockHashMismatch = 131072,
+    PrologueStorageSlotTypesCommitmentMismatch = 131073,
+    PrologueStorageSlotArityInvalid = 131074,
+    PrologueStorageSlotTypeInvalid = 131075,
+    PrologueNewAccountNonceNotZero = 131076,
+    PrologueNewAccountVaultNotEmpty = 131077,
+    PrologueSlotTypesSlotInvalidType = 131078,
+    PrologueFungibleFaucetReservedSlotNotEmpty = 131079,
+    PrologueFungibleFaucetReservedSlotInvalidType = 131080,
+    PrologueNonFungibleFaucetReservedSlotNotEmpty = 131081,
+    PrologueNonFungibleFaucetReservedSlotInvalidType = 131082,
+    PrologueAccountHashMismatch = 131083,
+    PrologueAccountIdMismatch = 131084,
+    PrologueNoteMmrLeafMismatch = 131085,
+    PrologueNoteTooManyAssets = 131086,
+    PrologueNoteVaultHashMismatch = 131087,
+    PrologueTooManyConsumedNotes = 131088,
+    PrologueNullifierCommitmentMismatch = 131089,
+    PrologueStorageSlotArityNotU32 = 131090,
+    PrologueStorageSlotTypeNotU32 = 131091,
+    PrologueSlotTypesSlotInvalidArity = 131092,
+    PrologueNumConsumedNotesNotU32 = 131093,
+
+    // EPILOGUE
+    EpilogueNonceNotIncremented = 131328,
+    EpilogueAssetsNotPreserved = 131329,
+
+    // ASSET VAULT
+    VaultGetBalanceNotFungibleFaucet = 131584,
+    VaultHasNonFungibleAssetNotNonFungible = 131585,
+    VaultFungibleMaxAmountExceeded = 131586,
+    VaultAddFungibleAssetMismatch = 131587,
+    VaultNonFungibleAssetAlreadyExists = 131588,
+    VaultFungibleAssetAmountLessThanAmountToWithdraw = 131589,
+    VaultRemoveFungibleAssetMismatch = 131590,
+    VaultNonFungibleAssetToRemoveNotFound = 131591,
+
+    // FAUCET
+    FaucetNewTotalSupplyWouldExceedMaxAssetAmount = 131840,
+    FaucetBurnCannotExceedExistingTotalSupply = 131841,
+    FaucetNonFungibleAssetAlreadyIssued = 131842,
+    FaucetBurnNonFungibleAssetNotNonFungibleFaucet = 131843,
+    FaucetNonFungibleAssetToBurnNotFound = 131844,
+
+    // TRANSACTION
+    TxInvalidNoteType = 132096,
+    TxTooManyCreatedNotes = 132097,
+
+    // ACCOUNT
+    AccountIdInsufficientOnes = 132352,
+    AccountCodeIsNotUpdatable = 132353,
+    AccountStorageSlotNotMap = 132354,
+    AccountStorageSlotNotArray = 132355,
+    AccountSeedIdMismatch = 132356,
+    AccountSeedInsufficientPow = 132357,
+    AccountNonceIncrementNotU32 = 132358,
+
+    // ASSET
+    AssetFungibleElementOneNotZero = 132608,
+    AssetFungibleElementTwoNotZero = 132609,
+    AssetFungibleOriginNotFungibleFaucet = 132610,
+    AssetFungibleMaxAmountExceeded = 132611,
+    AssetNonFungibleOriginNotNonFungibleFaucet = 132612,
+    AssetNonFungibleMostSignificantBitNotZero = 132613,
+    AssetFungibleOriginMismatch = 132614,
+    AssetNonFungibleOriginMismatch = 132615,
+
+    // NOTE
+    NoteGetSenderNoNoteProcessed = 132864,
+    NoteGetVaultInfoNoNoteProcessed = 132865,
+    NoteGetInputsHashNoNoteProcessed = 132866,
+    NoteGetArgsNoNoteProcessed = 132867,
+
+    // KERNEL
+    KernelSetItemFaucetReservedSlot = 133120,
+    KernelStorageSlotIndexOutOfBounds = 133121,
+    KernelSetMapItemFaucetReservedSlot = 133122,
+    KernelSetArrayItemFaucetReservedSlot = 133123,
+    KernelTotalIssuanceNotFungibleFaucet = 133124,
+
+    // LAYOUT
+    LayoutStorageSlotIndexNotU32 = 133376,
+}
+
+impl TransactionKernelError {
+    /// All errors which can be raised by the transaction kernel.
+    pub const ALL: [Self; 64] = {
+        use TransactionKernelError::*;
+        [
+            PrologueBlockHashMismatch,
+            PrologueStorageSlotTypesCommitmentMismatch,
+            PrologueStorageSlotArityInvalid,
+            PrologueStorageSlotTypeInvalid,
+            PrologueNewAccountNonceNotZero,
+            PrologueNewAccountVaultNotEmpty,
+            PrologueSlotTypesSlotInvalidType,
+            PrologueFungibleFaucetReservedSlotNotEmpty,
+            PrologueFungibleFaucetReservedSlotInvalidType,
+            PrologueNonFungibleFaucetReservedSlotNotEmpty,
+            PrologueNonFungibleFaucetReservedSlotInvalidType,
+            PrologueAccountHashMismatch,
+            PrologueAccountIdMismatch,
+            PrologueNoteMmrLeafMismatch,
+            PrologueNoteTooManyAssets,
+            PrologueNoteVaultHashMismatch,
+            PrologueTooManyConsumedNotes,
+            PrologueNullifierCommitmentMismatch,
+            PrologueStorageSlotArityNotU32,
+            PrologueStorageSlotTypeNotU32,
+            PrologueSlotTypesSlotInvalidArity,
+            PrologueNumConsumedNotesNotU32,
+            EpilogueNonceNotIncremented,
+            EpilogueAssetsNotPreserved,
+            VaultGetBalanceNotFungibleFaucet,
+            VaultHasNonFungibleAssetNotNonFungible,
+            VaultFungibleMaxAmountExceeded,
+            VaultAddFungibleAssetMismatch,
+            VaultNonFungibleAssetAlreadyExists,
+            VaultFungibleAssetAmountLessThanAmountToWithdraw,
+            VaultRemoveFungibleAssetMismatch,
+            VaultNonFungibleAssetToRemoveNotFound,
+            FaucetNewTotalSupplyWouldExceedMaxAssetAmount,
+            FaucetBurnCannotExceedExistingTotalSupply,
+            FaucetNonFungibleAssetAlreadyIssued,
+            FaucetBurnNonFungibleAssetNotNonFungibleFaucet,
+            FaucetNonFungibleAssetToBurnNotFound,
+            TxInvalidNoteType,
+            TxTooManyCreatedNotes,
+            AccountIdInsufficientOnes,
+            AccountCodeIsNotUpdatable,
+            AccountStorageSlotNotMap,
+            AccountStorageSlotNotArray,
+            AccountSeedIdMismatch,
+            AccountSeedInsufficientPow,
+            AccountNonceIncrementNotU32,
+            AssetFungibleElementOneNotZero,
+            AssetFungibleElementTwoNotZero,
+            AssetFungibleOriginNotFungibleFaucet,
+            AssetFungibleMaxAmountExceeded,
+            AssetNonFungibleOriginNotNonFungibleFaucet,
+            AssetNonFungibleMostSignificantBitNotZero,
+            AssetFungibleOriginMismatch,
+            AssetNonFungibleOriginMismatch,
+            NoteGetSenderNoNoteProcessed,
+            NoteGetVaultInfoNoNoteProcessed,
+            NoteGetInputsHashNoNoteProcessed,
+            NoteGetArgsNoNoteProcessed,
+            KernelSetItemFaucetReservedSlot,
+            KernelStorageSlotIndexOutOfBounds,
+            KernelSetMapItemFaucetReservedSlot,
+            KernelSetArrayItemFaucetReservedSlot,
+            KernelTotalIssuanceNotFungibleFaucet,
+            LayoutStorageSlotIndexNotU32,
+        ]
+    };
+
+    /// Returns the [TransactionKernelError] with the specified error code, or None if the code
+    /// does not correspond to an error raised by the transaction kernel.
+    pub fn from_code(code: u32) -> Option<Self> {
+        Self::ALL.into_iter().find(|error| error.code() == code)
+    }
+
+    /// Decodes the kernel error from the provided [ExecutionError].
+    ///
+    /// Returns None if the execution did not fail due to an assertion of the transaction kernel.
+    pub fn from_execution_error(error: &ExecutionError) -> Option<Self> {
+        match error {
+            ExecutionError::FailedAssertion { err_code, .. } => Self::from_code(*err_code),
+            _ => None,
+        }
+    }
+
+    /// Returns the error code of this error.
+    pub fn code(&self) -> u32 {
+        *self as u32
+    }
+
+    /// Returns the name of the kernel procedure which raises this error.
+    pub fn procedure(&self) -> &'static str {
+        use TransactionKernelError::*;
+
+        match self {
+            PrologueBlockHashMismatch => "prologue::process_block_data",
+            PrologueStorageSlotTypesCommitmentMismatch => "prologue::ingest_acct_storage_types",
+            PrologueStorageSlotArityInvalid
+            | PrologueStorageSlotTypeInvalid
+            | PrologueStorageSlotArityNotU32
+            | PrologueStorageSlotTypeNotU32 => "prologue::validate_storage_slot_types",
+            PrologueNewAccountNonceNotZero
+            | PrologueNewAccountVaultNotEmpty
+            | PrologueSlotTypesSlotInvalidType
+            | PrologueSlotTypesSlotInvalidArity
+            | PrologueFungibleFaucetReservedSlotNotEmpty
+            | PrologueFungibleFaucetReservedSlotInvalidType
+            | PrologueNonFungibleFaucetReservedSlotNotEmpty
+            | PrologueNonFungibleFaucetReservedSlotInvalidType => "prologue::validate_new_account",
+            PrologueAccountHashMismatch | PrologueAccountIdMismatch => {
+                "prologue::process_acct_data"
+            }
+            PrologueNoteMmrLeafMismatch => "prologue::authenticate_note",
+            PrologueNoteTooManyAssets | PrologueNoteVaultHashMismatch => {
+                "prologue::process_consumed_note"
+            }
+            PrologueTooManyConsumedNotes
+            | PrologueNullifierCommitmentMismatch
+            | PrologueNumConsumedNotesNotU32 => "prologue::process_consumed_notes_data",
+            EpilogueNonceNotIncremented | EpilogueAssetsNotPreserved => {
+                "epilogue::finalize_transaction"
+            }
+            VaultGetBalanceNotFungibleFaucet => "asset_vault::get_balance",
+            VaultHasNonFungibleAssetNotNonFungible => "asset_vault::has_non_fungible_asset",
+            VaultFungibleMaxAmountExceeded | VaultAddFungibleAssetMismatch => {
+                "asset_vault::add_fungible_asset"
+            }
+            VaultNonFungibleAssetAlreadyExists => "asset_vault::add_non_fungible_asset",
+            VaultFungibleAssetAmountLessThanAmountToWithdraw | VaultRemoveFungibleAssetMismatch => {
+                "asset_vault::remove_fungible_asset"
+            }
+            VaultNonFungibleAssetToRemoveNotFound => "asset_vault::remove_non_fungible_asset",
+            FaucetNewTotalSupplyWouldExceedMaxAssetAmount => "faucet::mint_fungible_asset",
+            FaucetBurnCannotExceedExistingTotalSupply => "faucet::burn_fungible_asset",
+            FaucetNonFungibleAssetAlreadyIssued => "faucet::mint_non_fungible_asset",
+            FaucetBurnNonFungibleAssetNotNonFungibleFaucet
+            | FaucetNonFungibleAssetToBurnNotFound => "faucet::burn_non_fungible_asset",
+            TxInvalidNoteType | TxTooManyCreatedNotes => "tx::create_note",
+            AccountIdInsufficientOnes => "account::validate_id",
+            AccountCodeIsNotUpdatable => "account::set_code",
+            AccountStorageSlotNotMap => "account::assert_map_slot",
+            AccountStorageSlotNotArray => "account::get_array_slot_depth",
+            AccountSeedIdMismatch | AccountSeedInsufficientPow => "account::validate_seed",
+            AccountNonceIncrementNotU32 => "account::incr_nonce",
+            AssetFungibleElementOneNotZero
+            | AssetFungibleElementTwoNotZero
+            | AssetFungibleOriginNotFungibleFaucet
+            | AssetFungibleMaxAmountExceeded => "asset::validate_fungible_asset",
+            AssetNonFungibleOriginNotNonFungibleFaucet
+            | AssetNonFungibleMostSignificantBitNotZero => "asset::validate_non_fungible_asset",
+            AssetFungibleOriginMismatch => "asset::validate_fungible_asset_origin",
+            AssetNonFungibleOriginMismatch => "asset::validate_non_fungible_asset_origin",
+            NoteGetSenderNoNoteProcessed => "note::get_sender",
+            NoteGetVaultInfoNoNoteProcessed => "note::get_vault_info",
+            NoteGetInputsHashNoNoteProcessed => "note::get_inputs_hash",
+            NoteGetArgsNoNoteProcessed => "note::get_args",
+            KernelSetItemFaucetReservedSlot => "kernel::set_account_item",
+            KernelStorageSlotIndexOutOfBounds => "kernel::get_account_storage_slot_type_info",
+            KernelSetMapItemFaucetReservedSlot => "kernel::set_account_map_item",
+            KernelSetArrayItemFaucetReservedSlot => "kernel::set_account_array_item",
+            KernelTotalIssuanceNotFungibleFaucet => "kernel::get_fungible_faucet_total_issuance",
+            LayoutStorageSlotIndexNotU32 => "layout::get_acct_storage_slot_type_data",
+        }
+    }
+
+    /// Returns a human-readable description of this error.
+    pub fn message(&self) -> &'static str {
+        use TransactionKernelError::*;
+
+        match self {
+            PrologueBlockHashMismatch => "block hash does not match the global inputs",
+            PrologueStorageSlotTypesCommitmentMismatch => {
+                "storage slot types do not match the slot types commitment"
+            }
+            PrologueStorageSlotArityInvalid => "storage slot entry arity exceeds 255",
+            PrologueStorageSlotTypeInvalid => "storage slot type is invalid",
+            PrologueNewAccountNonceNotZero => "nonce of new account is not zero",
+            PrologueNewAccountVaultNotEmpty => "vault of new account is not empty",
+            PrologueStorageSlotArityNotU32 => "storage slot entry arity is not a u32 value",
+            PrologueStorageSlotTypeNotU32 => "storage slot type is not a u32 value",
+            PrologueSlotTypesSlotInvalidType => "slot types reserved slot is not a scalar",
+            PrologueSlotTypesSlotInvalidArity => "slot types reserved slot is not of arity 64",
+            PrologueFungibleFaucetReservedSlotNotEmpty => {
+                "reserved slot of new fungible faucet is not empty"
+            }
+            PrologueFungibleFaucetReservedSlotInvalidType => {
+                "reserved slot of new fungible faucet is not a scalar of arity 0"
+            }
+            PrologueNonFungibleFaucetReservedSlotNotEmpty => {
+                "reserved slot of new non-fungible faucet is not an empty map"
+            }
+            PrologueNonFungibleFaucetReservedSlotInvalidType => {
+                "reserved slot of new non-fungible faucet is not a map of arity 0"
+            }
+            PrologueAccountHashMismatch => "account hash does not match the global inputs",
+            PrologueAccountIdMismatch => "account id does not match the global inputs",
+            PrologueNoteMmrLeafMismatch => "consumed note block does not match the chain MMR",
+            PrologueNoteTooManyAssets => "consumed note has too many assets",
+            PrologueNoteVaultHashMismatch => "consumed note assets do not match its vault hash",
+            PrologueTooManyConsumedNotes => "number of consumed notes exceeds max",
+            PrologueNumConsumedNotesNotU32 => "number of consumed notes is not a u32 value",
+            PrologueNullifierCommitmentMismatch => {
+                "nullifier commitment does not match the global inputs"
+            }
+            EpilogueNonceNotIncremented => "nonce not incremented",
+            EpilogueAssetsNotPreserved => "assets not preserved",
+            VaultGetBalanceNotFungibleFaucet => "balance queried for a non-fungible faucet",
+            VaultHasNonFungibleAssetNotNonFungible => "asset is not a non-fungible asset",
+            VaultFungibleMaxAmountExceeded => {
+                "fungible asset amount in vault would exceed max after adding the asset"
+            }
+            VaultAddFungibleAssetMismatch | VaultRemoveFungibleAssetMismatch => {
+                "vault asset does not match the advice provider"
+            }
+            VaultNonFungibleAssetAlreadyExists => "non-fungible asset already exists in vault",
+            VaultFungibleAssetAmountLessThanAmountToWithdraw => {
+                "fungible asset amount is less than amount to withdraw"
+            }
+            VaultNonFungibleAssetToRemoveNotFound => "non-fungible asset not found in vault",
+            FaucetNewTotalSupplyWouldExceedMaxAssetAmount => {
+                "total supply of faucet would exceed max asset amount"
+            }
+            FaucetBurnCannotExceedExistingTotalSupply => {
+                "burned amount exceeds total supply of faucet"
+            }
+            FaucetNonFungibleAssetAlreadyIssued => "non-fungible asset already issued",
+            FaucetBurnNonFungibleAssetNotNonFungibleFaucet => {
+                "non-fungible asset can only be burned by a non-fungible faucet"
+            }
+            FaucetNonFungibleAssetToBurnNotFound => "non-fungible asset to burn not found",
+            TxInvalidNoteType => "note type is invalid",
+            TxTooManyCreatedNotes => "number of created notes exceeds max",
+            AccountIdInsufficientOnes => "account id has too few ones",
+            AccountCodeIsNotUpdatable => "account code is not updatable",
+            AccountStorageSlotNotMap => "storage slot is not a map slot",
+            AccountStorageSlotNotArray => "storage slot is not an array slot",
+            AccountSeedIdMismatch => "account id does not match the id computed from the seed",
+            AccountSeedInsufficientPow => "account seed has insufficient proof of work",
+            AccountNonceIncrementNotU32 => "nonce increment is not a u32 value",
+            AssetFungibleElementOneNotZero => "second element of fungible asset is not zero",
+            AssetFungibleElementTwoNotZero => "third element of fungible asset is not zero",
+            AssetFungibleOriginNotFungibleFaucet => {
+                "origin of fungible asset is not a fungible faucet"
+            }
+            AssetFungibleMaxAmountExceeded => "amount of fungible asset exceeds max",
+            AssetNonFungibleOriginNotNonFungibleFaucet => {
+                "origin of non-fungible asset is not a non-fungible faucet"
+            }
+            AssetNonFungibleMostSignificantBitNotZero => {
+                "most significant bit of non-fungible asset is not zero"
+            }
+            AssetFungibleOriginMismatch | AssetNonFungibleOriginMismatch => {
+                "asset origin does not match the faucet id"
+            }
+            NoteGetSenderNoNoteProcessed
+            | NoteGetVaultInfoNoNoteProcessed
+            | NoteGetInputsHashNoNoteProcessed
+            | NoteGetArgsNoNoteProcessed => "no note is being processed",
+            KernelSetItemFaucetReservedSlot
+            | KernelSetMapItemFaucetReservedSlot
+            | KernelSetArrayItemFaucetReservedSlot => "reserved slot of faucet cannot be set",
+            KernelStorageSlotIndexOutOfBounds => "storage slot index is out of bounds",
+            KernelTotalIssuanceNotFungibleFaucet => {
+                "total issuance queried for a non-fungible faucet account"
+            }
+            LayoutStorageSlotIndexNotU32 => "storage slot index is not a u32 value",
+        }
+    }
+}
+
+impl fmt::Display for TransactionKernelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (error code {} in {})", self.message(), self.code(), self.procedure())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TransactionKernelError {}
@@ -1,2 +1,5 @@
 mod account_stub;
-pub use account_stub::{extract_account_storage_delta, parse_final_account_stub};
+pub use account_stub::parse_final_account_stub;
+
+mod errors;
+pub use errors::TransactionKernelError;
@@ -1,8 +1,9 @@
-use crate::{assembler::assembler, auth::AuthScheme};
+use crate::{
+    assembler::assembler,
+    auth::{create_auth_storage, AuthScheme},
+};
 use miden_objects::{
-    accounts::{
-        Account, AccountCode, AccountId, AccountStorage, AccountType, AccountVault, StorageSlotType,
-    },
+    accounts::{Account, AccountCode, AccountId, AccountType, AccountVault},
     assembly::ModuleAst,
     utils::{
         format,
@@ -22,7 +23,8 @@ use miden_objects::{
 ///
 /// Both methods require authentication. The authentication procedure is defined by the specified
 /// authentication scheme. Public key information for the scheme is stored in the account storage
-/// at slot 0.
+/// at slot 0. For multisig schemes, slot 0 holds a multi-word value consisting of the threshold
+/// followed by the public keys.
 pub fn create_basic_wallet(
     init_seed: [u8; 32],
     auth_scheme: AuthScheme,
@@ -34,9 +36,7 @@ pub fn create_basic_wallet(
         ));
     }
 
-    let (auth_scheme_procedure, storage_slot_0_data): (&str, Word) = match auth_scheme {
-        AuthScheme::RpoFalcon512 { pub_key } => ("basic::auth_tx_rpo_falcon512", pub_key.into()),
-    };
+    let auth_scheme_procedure = auth_scheme.procedure_name();
 
     let account_code_string: String = format!(
         "
@@ -45,7 +45,7 @@ pub fn create_basic_wallet(
 
     export.basic_wallet::receive_asset
     export.basic_wallet::send_asset
-    export.{auth_scheme_procedure}
+    export.basic::{auth_scheme_procedure}
 
     "
     );
@@ -56,10 +56,7 @@ pub fn create_basic_wallet(
     let account_assembler = assembler();
     let account_code = AccountCode::new(account_code_ast.clone(), &account_assembler)?;
 
-    let account_storage = AccountStorage::new(vec![(
-        0,
-        (StorageSlotType::Value { value_arity: 0 }, storage_slot_0_data),
-    )])?;
+    let account_storage = create_auth_storage(&auth_scheme, vec![])?;
     let account_vault = AccountVault::new(&[])?;
 
     let account_seed = AccountId::get_account_seed(
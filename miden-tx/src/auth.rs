@@ -0,0 +1,135 @@
+use super::AuthenticationError;
+use miden_objects::{
+    crypto::dsa::rpo_falcon512::{KeyPair, Polynomial},
+    utils::collections::BTreeMap,
+    Digest, Felt, Word,
+};
+
+// TRANSACTION AUTHENTICATOR
+// ================================================================================================
+
+/// Defines an authenticator for transactions.
+///
+/// The authenticator is invoked by the [TransactionHost](crate::TransactionHost) whenever a
+/// transaction requests a signature, i.e., when the `adv.push_sig` decorator is executed by the
+/// signature verification procedure invoked from the account's authentication procedure (e.g.,
+/// `miden::auth::basic::auth_tx_rpo_falcon512`).
+///
+/// This allows the signatures to be generated during transaction execution, without the secret
+/// keys ever being provided to the transaction via the advice provider.
+pub trait TransactionAuthenticator {
+    /// Signs the provided message with the secret key associated with the provided public key.
+    ///
+    /// Returns the signature in the form expected on the advice stack by the signature
+    /// verification procedure of the respective signature scheme.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The authenticator does not manage the secret key for the provided public key.
+    /// - The signature could not be generated.
+    fn get_signature(&self, pub_key: Word, message: Word)
+        -> Result<Vec<Felt>, AuthenticationError>;
+}
+
+// BASIC AUTHENTICATOR
+// ================================================================================================
+
+/// A [TransactionAuthenticator] backed by a local keystore of RPO Falcon512 key pairs.
+///
+/// The key pairs are indexed by their public keys.
+#[derive(Default)]
+pub struct BasicAuthenticator {
+    keys: BTreeMap<Digest, KeyPair>,
+}
+
+impl BasicAuthenticator {
+    /// Returns a new [BasicAuthenticator] instantiated with the provided key pairs.
+    pub fn new(keys: impl IntoIterator<Item = KeyPair>) -> Self {
+        let mut authenticator = Self::default();
+        for key_pair in keys {
+            authenticator.add_key(key_pair);
+        }
+        authenticator
+    }
+
+    /// Adds the provided key pair to the keystore of this authenticator.
+    ///
+    /// If the keystore already contained a key pair with the same public key, it is replaced.
+    pub fn add_key(&mut self, key_pair: KeyPair) {
+        let pub_key: Word = key_pair.public_key().into();
+        self.keys.insert(pub_key.into(), key_pair);
+    }
+}
+
+impl TransactionAuthenticator for BasicAuthenticator {
+    fn get_signature(
+        &self,
+        pub_key: Word,
+        message: Word,
+    ) -> Result<Vec<Felt>, AuthenticationError> {
+        let key_pair = self
+            .keys
+            .get(&Digest::from(pub_key))
+            .ok_or(AuthenticationError::UnknownKey(pub_key.into()))?;
+
+        get_falcon_signature(key_pair, message)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Signs the provided message with the provided key pair and returns the signature in the form
+/// expected on the advice stack by the `std::crypto::dsa::rpo_falcon512::verify` procedure.
+///
+/// The signature consists of the nonce, the expanded public key polynomial h, the signature
+/// polynomial s2, and the product of h and s2 in the ring of polynomials with coefficients in the
+/// Miden field. The elements are returned in reverse order, such that they can be pushed onto the
+/// advice stack one by one.
+pub fn get_falcon_signature(
+    key_pair: &KeyPair,
+    message: Word,
+) -> Result<Vec<Felt>, AuthenticationError> {
+    let sig = key_pair
+        .sign(message)
+        .map_err(|err| AuthenticationError::SignatureGenerationFailed(format!("{err:?}")))?;
+
+    let nonce = sig.nonce();
+    let s2 = sig.sig_poly();
+    let h = sig.pub_key_poly();
+    let pi = Polynomial::mul_modulo_p(&h, &s2);
+
+    let mut result: Vec<Felt> = nonce.to_vec();
+    result.extend(h.inner().iter().map(|a| Felt::from(*a)));
+    result.extend(s2.inner().iter().map(|a| Felt::from(*a)));
+    result.extend(pi.iter().map(|a| Felt::new(*a)));
+    result.reverse();
+
+    Ok(result)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{AuthenticationError, BasicAuthenticator, Digest, TransactionAuthenticator};
+    use miden_objects::{crypto::dsa::rpo_falcon512::KeyPair, Word, ONE, ZERO};
+
+    #[test]
+    fn basic_authenticator_get_signature() {
+        let key_pair = KeyPair::new().unwrap();
+        let pub_key: Word = key_pair.public_key().into();
+        let message = [ONE, ZERO, ONE, ZERO];
+
+        let authenticator = BasicAuthenticator::new([key_pair]);
+        assert!(authenticator.get_signature(pub_key, message).is_ok());
+
+        // signatures cannot be generated for keys not managed by the authenticator
+        let unknown_key: Word = KeyPair::new().unwrap().public_key().into();
+        assert!(matches!(
+            authenticator.get_signature(unknown_key, message),
+            Err(AuthenticationError::UnknownKey(key)) if key == Digest::from(unknown_key)
+        ));
+    }
+}
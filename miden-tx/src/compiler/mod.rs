@@ -66,6 +66,16 @@ impl TransactionCompiler {
         Ok(account_code)
     }
 
+    /// Compiles the provided account code into [AccountCode] without loading it into this
+    /// compiler.
+    pub fn compile_account_code(
+        &self,
+        account_code: ModuleAst,
+    ) -> Result<AccountCode, TransactionCompilerError> {
+        AccountCode::new(account_code, &self.assembler)
+            .map_err(TransactionCompilerError::LoadAccountFailed)
+    }
+
     /// Loads the provided account interface (vector of procedure digests) into the this compiler.
     /// Returns the old account interface if it previously existed.
     pub fn load_account_interface(
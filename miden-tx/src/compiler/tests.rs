@@ -2,8 +2,8 @@ use super::{AccountId, ModuleAst, ProgramAst, ScriptTarget, TransactionCompiler}
 use miden_objects::{
     accounts::ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
     assets::{Asset, FungibleAsset},
-    notes::{Note, NoteInclusionProof, RecordedNote},
-    Felt, FieldElement, Word,
+    notes::{Note, NoteInclusionProof, NoteTag, NoteType, RecordedNote},
+    Felt, Word,
 };
 
 // CONSTANTS
@@ -162,7 +162,8 @@ fn mock_consumed_notes(
         &[fungible_asset_1, fungible_asset_2, fungible_asset_3],
         SERIAL_NUM_1,
         sender,
-        Felt::ZERO,
+        NoteType::Public,
+        NoteTag::default(),
     )
     .unwrap();
 
@@ -173,7 +174,8 @@ fn mock_consumed_notes(
         &[fungible_asset_1, fungible_asset_2, fungible_asset_3],
         SERIAL_NUM_2,
         sender,
-        Felt::ZERO,
+        NoteType::Public,
+        NoteTag::default(),
     )
     .unwrap();
 
@@ -1,5 +1,5 @@
 use super::{Account, AccountId, BlockHeader, ChainMmr, DataStoreError, NoteOrigin};
-use miden_objects::{assembly::ModuleAst, notes::RecordedNote};
+use miden_objects::{assembly::ModuleAst, notes::RecordedNote, Word};
 use vm_processor::AdviceInputs;
 
 /// The [DataStore] trait defines the interface that transaction objects use to fetch data
@@ -16,4 +16,15 @@ pub trait DataStore {
 
     /// Returns the account code [ModuleAst] associated with the the specified [AccountId].
     fn get_account_code(&self, account_id: AccountId) -> Result<ModuleAst, DataStoreError>;
+
+    /// Returns the seed of the account with the specified [AccountId].
+    ///
+    /// The seed is required only when executing a transaction which creates a new account, and
+    /// thus, this method is invoked only for accounts with zero nonce. None may be returned if the
+    /// seed is not known.
+    ///
+    /// By default, the seed is assumed to be unknown.
+    fn get_account_seed(&self, _account_id: AccountId) -> Result<Option<Word>, DataStoreError> {
+        Ok(None)
+    }
 }
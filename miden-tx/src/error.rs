@@ -1,7 +1,8 @@
 use super::{AccountError, AccountId, Digest, ExecutionError, TransactionResultError};
-use core::fmt;
+use core::{fmt, ops::Range};
+use miden_lib::transaction::TransactionKernelError;
 use miden_objects::{
-    assembly::AssemblyError, crypto::merkle::NodeIndex, PreparedTransactionError,
+    assembly::AssemblyError, crypto::merkle::NodeIndex, NoteError, PreparedTransactionError,
     TransactionWitnessError,
 };
 use miden_verifier::VerificationError;
@@ -24,6 +25,23 @@ impl fmt::Display for TransactionError {
 #[cfg(feature = "std")]
 impl std::error::Error for TransactionError {}
 
+// AUTHENTICATION ERROR
+// ================================================================================================
+#[derive(Debug)]
+pub enum AuthenticationError {
+    SignatureGenerationFailed(String),
+    UnknownKey(Digest),
+}
+
+impl fmt::Display for AuthenticationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AuthenticationError {}
+
 // TRANSACTION COMPILER ERROR
 // ================================================================================================
 #[derive(Debug)]
@@ -56,22 +74,56 @@ pub enum TransactionExecutorError {
     ComipleTransactionScriptFailed(TransactionCompilerError),
     CompileTransactionError(TransactionCompilerError),
     ConstructPreparedTransactionFailed(PreparedTransactionError),
-    ExecuteTransactionProgramFailed(ExecutionError),
+    ExecuteTransactionProgramFailed(ExecutionError, Option<TransactionKernelError>),
     FetchAccountCodeFailed(DataStoreError),
     FetchTransactionDataFailed(DataStoreError),
+    InvalidNoteArgs(NoteError),
     LoadAccountFailed(TransactionCompilerError),
+    RegisterEventHandlerFailed(TransactionHostError),
     TransactionResultError(TransactionResultError),
 }
 
+impl TransactionExecutorError {
+    /// Returns a [TransactionExecutorError::ExecuteTransactionProgramFailed] error for the provided
+    /// [ExecutionError], decoding the error raised by the transaction kernel if there is one.
+    pub(crate) fn execute_transaction_program_failed(error: ExecutionError) -> Self {
+        let kernel_error = TransactionKernelError::from_execution_error(&error);
+        Self::ExecuteTransactionProgramFailed(error, kernel_error)
+    }
+}
+
 impl fmt::Display for TransactionExecutorError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::ExecuteTransactionProgramFailed(_, Some(kernel_error)) => {
+                write!(f, "ExecuteTransactionProgramFailed: {kernel_error}")
+            }
+            _ => write!(f, "{:?}", self),
+        }
     }
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for TransactionExecutorError {}
 
+// TRANSACTION HOST ERROR
+// ================================================================================================
+#[derive(Debug)]
+pub enum TransactionHostError {
+    DuplicateEventIdRange(Range<u32>),
+    EmptyEventIdRange(Range<u32>),
+    ReservedEventIdRange(Range<u32>),
+}
+
+impl fmt::Display for TransactionHostError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TransactionHostError {}
+
 // TRANSACTION PROVER ERROR
 // ================================================================================================
 #[derive(Debug)]
@@ -1,17 +1,27 @@
 use super::{
-    AccountCode, AccountId, DataStore, Digest, NoteOrigin, NoteScript, PreparedTransaction,
-    RecAdviceProvider, ScriptTarget, TransactionCompiler, TransactionExecutorError,
-    TransactionHost, TransactionResult,
+    AccountCode, AccountId, DataStore, DebugOptions, Digest, NoteOrigin, NoteScript,
+    PreparedTransaction, RecAdviceProvider, ScriptTarget, TraceEntry, TransactionAuthenticator,
+    TransactionCompiler, TransactionExecutorError, TransactionHost, TransactionResult,
+    UserEventHandler,
 };
-use crate::{host::EventHandler, TryFromVmResult};
-use miden_lib::{outputs::TX_SCRIPT_ROOT_WORD_IDX, transaction::extract_account_storage_delta};
+use crate::{
+    host::{EventHandler, UserEventHandlers},
+    TryFromVmResult,
+};
+use core::ops::Range;
+use miden_lib::outputs::TX_SCRIPT_ROOT_WORD_IDX;
 use miden_objects::{
-    accounts::{Account, AccountDelta},
-    assembly::ProgramAst,
+    accounts::{
+        Account, AccountCodeDelta, AccountDelta, AccountStorage, AccountStorageDelta,
+        StorageSlotType,
+    },
+    assembly::{ModuleAst, ProgramAst},
     transaction::{ConsumedNotes, CreatedNotes, FinalAccountStub, TransactionScript},
-    Felt, TransactionResultError, Word, WORD_SIZE,
+    utils::collections::BTreeMap,
+    Felt, TransactionResultError, Word, EMPTY_WORD, WORD_SIZE, ZERO,
 };
-use vm_core::{Program, StackOutputs, StarkField};
+use std::rc::Rc;
+use vm_core::{utils::group_slice_elements, Program, StackOutputs, StarkField};
 
 /// The transaction executor is responsible for executing Miden rollup transactions.
 ///
@@ -26,9 +36,32 @@ use vm_core::{Program, StackOutputs, StarkField};
 /// The [TransactionExecutor::execute_transaction()] method is the main entry point for the
 /// executor and produces a [TransactionWitness] for the transaction. The TransactionWitness can
 /// then be used to by the prover to generate a proof transaction execution.
+///
+/// The executor also keeps a cache of account code which transactions may set as the new code of
+/// an account (see [TransactionExecutor::load_account_code()]). When a transaction updates the
+/// code of an account to one of the cached code objects, the full new code is included in the
+/// account delta of the transaction result.
+///
+/// Handlers of user-defined events can be registered with the executor via
+/// [TransactionExecutor::register_event_handler()]. The logs recorded by these handlers are
+/// included in the transaction result.
+///
+/// When the executor is configured with [DebugOptions] (see
+/// [TransactionExecutor::with_debug_options()]), the trace entries emitted by the most recently
+/// executed transaction can be retrieved via [TransactionExecutor::trace()]. The trace is
+/// available regardless of whether the execution of the transaction succeeded.
+///
+/// When the executor is configured with a [TransactionAuthenticator] (see
+/// [TransactionExecutor::with_authenticator()]), the signatures requested by the executed
+/// transactions are generated by the authenticator during execution.
 pub struct TransactionExecutor<D: DataStore> {
     compiler: TransactionCompiler,
     data_store: D,
+    account_codes: BTreeMap<Digest, AccountCode>,
+    user_event_handlers: UserEventHandlers,
+    debug_options: DebugOptions,
+    trace: Vec<TraceEntry>,
+    authenticator: Option<Rc<dyn TransactionAuthenticator>>,
 }
 
 impl<D: DataStore> TransactionExecutor<D> {
@@ -40,9 +73,39 @@ impl<D: DataStore> TransactionExecutor<D> {
         Self {
             compiler,
             data_store,
+            account_codes: BTreeMap::new(),
+            user_event_handlers: UserEventHandlers::default(),
+            debug_options: DebugOptions::default(),
+            trace: Vec::new(),
+            authenticator: None,
         }
     }
 
+    /// Returns a new [TransactionExecutor] which uses the provided [TransactionAuthenticator] to
+    /// generate the signatures requested by the executed transactions.
+    pub fn with_authenticator(mut self, authenticator: Rc<dyn TransactionAuthenticator>) -> Self {
+        self.authenticator = Some(authenticator);
+        self
+    }
+
+    /// Returns a new [TransactionExecutor] which records the debug information specified by the
+    /// provided [DebugOptions] when executing transactions.
+    pub fn with_debug_options(mut self, options: DebugOptions) -> Self {
+        self.debug_options = options;
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the trace entries recorded during the execution of the most recent transaction.
+    ///
+    /// The trace is empty unless recording of the trace or of the call path was enabled via
+    /// [TransactionExecutor::with_debug_options()].
+    pub fn trace(&self) -> &[TraceEntry] {
+        &self.trace
+    }
+
     // MODIFIERS
     // --------------------------------------------------------------------------------------------
 
@@ -79,6 +142,47 @@ impl<D: DataStore> TransactionExecutor<D> {
         self.compiler.load_account_interface(account_id, procedures)
     }
 
+    /// Compiles the provided account code and loads it into the internal cache of account code
+    /// which may be set as the new code of an account by a transaction.
+    ///
+    /// If a transaction executed by this executor updates the code of an account to the loaded
+    /// code, the account delta of the transaction will contain the full new [AccountCode] rather
+    /// than only its root.
+    ///
+    /// # Errors:
+    /// Returns an error if the account code fails to compile.
+    pub fn load_account_code(
+        &mut self,
+        account_code: ModuleAst,
+    ) -> Result<AccountCode, TransactionExecutorError> {
+        let account_code = self
+            .compiler
+            .compile_account_code(account_code)
+            .map_err(TransactionExecutorError::LoadAccountFailed)?;
+        self.account_codes.insert(account_code.root(), account_code.clone());
+        Ok(account_code)
+    }
+
+    /// Registers the provided handler for the user-defined events with IDs in the specified range.
+    ///
+    /// The handler is invoked whenever a transaction executed by this executor emits an event with
+    /// an ID in the specified range.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - The range of event IDs is empty.
+    /// - The range overlaps with the range of event IDs reserved for the kernel events.
+    /// - The range overlaps with the range of a previously registered handler.
+    pub fn register_event_handler(
+        &mut self,
+        event_ids: Range<u32>,
+        handler: Box<dyn UserEventHandler>,
+    ) -> Result<(), TransactionExecutorError> {
+        self.user_event_handlers
+            .register(event_ids, handler)
+            .map_err(TransactionExecutorError::RegisterEventHandlerFailed)
+    }
+
     /// Compiles the provided program into the [NoteScript] and checks (to the extent possible)
     /// if a note could be executed against all accounts with the specified interfaces.
     pub fn compile_note_script(
@@ -111,6 +215,10 @@ impl<D: DataStore> TransactionExecutor<D> {
     /// Prepares and executes a transaction specified by the provided arguments and returns a
     /// [TransactionWitness].
     ///
+    /// The notes to be consumed are specified by their origins, each accompanied by optional note
+    /// args which the note script can read via `note::get_args`. If no note args are provided for
+    /// a note, an empty word is used.
+    ///
     /// The method first fetches the data required to execute the transaction from the [DataStore]
     /// and compile the transaction into an executable program. Then it executes the transaction
     /// program and creates a [TransactionWitness].
@@ -124,26 +232,38 @@ impl<D: DataStore> TransactionExecutor<D> {
         &mut self,
         account_id: AccountId,
         block_ref: u32,
-        note_origins: &[NoteOrigin],
+        notes: &[(NoteOrigin, Option<Word>)],
         tx_script: Option<TransactionScript>,
     ) -> Result<TransactionResult, TransactionExecutorError> {
-        let transaction =
-            self.prepare_transaction(account_id, block_ref, note_origins, tx_script)?;
+        let transaction = self.prepare_transaction(account_id, block_ref, notes, tx_script)?;
 
         let advice_recorder: RecAdviceProvider = transaction.advice_provider_inputs().into();
-        let mut host = TransactionHost::new(advice_recorder);
+        let user_event_handlers = core::mem::take(&mut self.user_event_handlers);
+        let mut host =
+            TransactionHost::with_user_event_handlers(advice_recorder, user_event_handlers)
+                .with_debug_options(self.debug_options);
+        if let Some(authenticator) = &self.authenticator {
+            host = host.with_authenticator(authenticator.clone());
+        }
         let result = vm_processor::execute(
             transaction.tx_program(),
             transaction.stack_inputs(),
             &mut host,
             Default::default(),
-        )
-        .map_err(TransactionExecutorError::ExecuteTransactionProgramFailed)?;
+        );
+
+        // return the handlers of the user-defined events and the recorded trace to the executor
+        // regardless of whether the transaction was executed successfully
+        let (advice_recorder, mut event_handler) = host.into_parts();
+        self.user_event_handlers = event_handler.take_user_event_handlers();
+        self.trace = event_handler.take_trace();
+
+        let result =
+            result.map_err(TransactionExecutorError::execute_transaction_program_failed)?;
 
         let (account, block_header, _block_chain, consumed_notes, tx_program, tx_script) =
             transaction.into_parts();
 
-        let (advice_recorder, event_handler) = host.into_parts();
         create_transaction_result(
             account,
             consumed_notes,
@@ -153,6 +273,7 @@ impl<D: DataStore> TransactionExecutor<D> {
             advice_recorder,
             result.stack_outputs().clone(),
             event_handler,
+            &self.account_codes,
         )
         .map_err(TransactionExecutorError::TransactionResultError)
     }
@@ -165,29 +286,51 @@ impl<D: DataStore> TransactionExecutor<D> {
     /// Returns an error if:
     /// - If required data can not be fetched from the [DataStore].
     /// - If the transaction can not be compiled.
+    /// - If note args are provided for a note which is not returned by the [DataStore].
     pub fn prepare_transaction(
         &mut self,
         account_id: AccountId,
         block_ref: u32,
-        note_origins: &[NoteOrigin],
+        notes: &[(NoteOrigin, Option<Word>)],
         tx_script: Option<TransactionScript>,
     ) -> Result<PreparedTransaction, TransactionExecutorError> {
+        let note_origins = notes.iter().map(|(origin, _)| origin.clone()).collect::<Vec<_>>();
+        let note_args = notes
+            .iter()
+            .filter_map(|(origin, note_args)| {
+                note_args.map(|note_args| (origin.clone(), note_args))
+            })
+            .collect::<Vec<_>>();
+
         let (account, block_header, block_chain, notes, auxiliary_data) = self
             .data_store
-            .get_transaction_data(account_id, block_ref, note_origins)
+            .get_transaction_data(account_id, block_ref, &note_origins)
             .map_err(TransactionExecutorError::FetchTransactionDataFailed)?;
 
+        // the seed is required only if the account is created by this transaction
+        let account_seed = if account.is_new() {
+            self.data_store
+                .get_account_seed(account_id)
+                .map_err(TransactionExecutorError::FetchTransactionDataFailed)?
+        } else {
+            None
+        };
+
         let tx_program = self
             .compiler
             .compile_transaction(account_id, &notes, tx_script.as_ref().map(|x| x.code()))
             .map_err(TransactionExecutorError::CompileTransactionError)?;
 
+        // the note args are matched to the notes returned by the data store by note origin
+        let consumed_notes = ConsumedNotes::with_note_args(notes, &note_args)
+            .map_err(TransactionExecutorError::InvalidNoteArgs)?;
+
         PreparedTransaction::new(
             account,
-            None,
+            account_seed,
             block_header,
             block_chain,
-            notes,
+            consumed_notes,
             tx_script,
             tx_program,
             auxiliary_data,
@@ -198,6 +341,10 @@ impl<D: DataStore> TransactionExecutor<D> {
 
 #[allow(clippy::too_many_arguments)]
 /// Creates a new [TransactionResult] from the provided data, advice provider and stack outputs.
+///
+/// If the code of the account was updated by the transaction, the new code is looked up in the
+/// provided `account_codes` map. If it is not found there, only the root of the new code is
+/// included in the account delta.
 pub fn create_transaction_result(
     initial_account: Account,
     consumed_notes: ConsumedNotes,
@@ -207,6 +354,7 @@ pub fn create_transaction_result(
     advice_provider: RecAdviceProvider,
     stack_outputs: StackOutputs,
     event_handler: EventHandler,
+    account_codes: &BTreeMap<Digest, AccountCode>,
 ) -> Result<TransactionResult, TransactionResultError> {
     // finalize the advice recorder
     let (advice_witness, stack, map, store) = advice_provider.finalize();
@@ -227,10 +375,35 @@ pub fn create_transaction_result(
             [TX_SCRIPT_ROOT_WORD_IDX * WORD_SIZE..(TX_SCRIPT_ROOT_WORD_IDX + 1) * WORD_SIZE]
     );
 
-    // TODO: Fix delta extraction for new account creation
-    // extract the account storage delta
-    let storage_delta =
-        extract_account_storage_delta(&store, &initial_account, &final_account_stub)?;
+    // check whether the account was created by this transaction; if so, the account delta must
+    // contain the full initial state of the account
+    let is_new_account = initial_account.is_new() && final_account_stub.0.nonce() != ZERO;
+
+    // finalize the event handler and extract the words of the multi-word values set by the
+    // transaction from the advice map
+    let (mut storage_delta, vault_delta, logs) = event_handler.finalize();
+    storage_delta.updated_words =
+        extract_storage_words(initial_account.storage(), &storage_delta, &map);
+
+    // if the account was created by this transaction, prepend the initial state of the storage
+    let storage_delta = if is_new_account {
+        initial_storage_delta(initial_account.storage()).merge(storage_delta)
+    } else {
+        storage_delta
+    };
+
+    // extract the code delta
+    let final_code_root = final_account_stub.0.code_root();
+    let code_delta = if initial_account.code().root() != final_code_root {
+        Some(AccountCodeDelta {
+            code_root: final_code_root,
+            code: account_codes.get(&final_code_root).cloned(),
+        })
+    } else if is_new_account {
+        Some(AccountCodeDelta::from(initial_account.code().clone()))
+    } else {
+        None
+    };
 
     // extract the nonce delta
     let nonce_delta = if initial_account.nonce() != final_account_stub.0.nonce() {
@@ -239,12 +412,9 @@ pub fn create_transaction_result(
         None
     };
 
-    // finalize the event handler
-    let vault_delta = event_handler.finalize();
-
     // construct the account delta
-    let account_delta =
-        AccountDelta::new(storage_delta, vault_delta, nonce_delta).expect("invalid account delta");
+    let account_delta = AccountDelta::new(storage_delta, vault_delta, code_delta, nonce_delta)
+        .expect("invalid account delta");
 
     TransactionResult::new(
         initial_account,
@@ -256,5 +426,79 @@ pub fn create_transaction_result(
         program,
         tx_script_root,
         advice_witness,
+        logs,
     )
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a storage delta which sets all non-empty slots of the provided storage, including the
+/// slot types commitment slot. The delta also contains the words of all known multi-word values,
+/// and the entries and items of all non-empty maps and arrays.
+///
+/// This is used to include the initial state of the account storage into the delta of a
+/// transaction which creates the account.
+fn initial_storage_delta(storage: &AccountStorage) -> AccountStorageDelta {
+    let updated_items = storage
+        .slots()
+        .leaves()
+        .filter(|(_, value)| **value != EMPTY_WORD)
+        .map(|(idx, value)| (idx as u8, *value))
+        .collect::<Vec<_>>();
+
+    let updated_words = updated_items
+        .iter()
+        .filter_map(|(idx, _)| storage.get_item_words(*idx).map(|words| (*idx, words.to_vec())))
+        .collect();
+
+    let updated_maps = storage
+        .maps()
+        .iter()
+        .map(|(idx, map)| (*idx, map.entries().map(|(key, value)| (Word::from(*key), *value))))
+        .map(|(idx, entries)| (idx, entries.collect::<Vec<_>>()))
+        .filter(|(_, entries)| !entries.is_empty())
+        .collect();
+
+    let updated_arrays = storage
+        .arrays()
+        .iter()
+        .map(|(idx, array)| (*idx, array.items().map(|(item_idx, value)| (item_idx, *value))))
+        .map(|(idx, items)| (idx, items.collect::<Vec<_>>()))
+        .filter(|(_, items)| !items.is_empty())
+        .collect();
+
+    AccountStorageDelta {
+        cleared_items: vec![],
+        updated_items,
+        updated_words,
+        updated_maps,
+        updated_arrays,
+    }
+}
+
+/// Returns the words of the multi-word values set by the provided storage delta.
+///
+/// When a multi-word value is set via `account::set_item_words`, its words are inserted into the
+/// advice map under the commitment to these words. Values whose words are not in the advice map
+/// are not included in the result.
+fn extract_storage_words(
+    storage: &AccountStorage,
+    storage_delta: &AccountStorageDelta,
+    advice_map: &BTreeMap<[u8; 32], Vec<Felt>>,
+) -> Vec<(u8, Vec<Word>)> {
+    storage_delta
+        .updated_items
+        .iter()
+        .filter_map(|(idx, value)| {
+            let num_words = match storage.slot_types()[*idx as usize] {
+                StorageSlotType::Value { value_arity } if value_arity > 0 => value_arity as usize,
+                _ => return None,
+            };
+
+            let elements = advice_map.get(&Digest::from(*value).as_bytes())?;
+            (elements.len() == num_words * WORD_SIZE)
+                .then(|| (*idx, group_slice_elements::<Felt, WORD_SIZE>(elements).to_vec()))
+        })
+        .collect()
+}
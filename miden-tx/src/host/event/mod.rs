@@ -1,6 +1,19 @@
-use miden_objects::{accounts::AccountVaultDelta, transaction::Event};
+use miden_objects::{
+    accounts::{AccountStorageDelta, AccountVaultDelta},
+    transaction::{Event, TransactionLog},
+};
 use vm_processor::{ExecutionError, HostResponse, ProcessState};
 
+mod storage_delta;
+use storage_delta::AccountStorageDeltaHandler;
+
+mod trace;
+use trace::TraceHandler;
+pub use trace::{DebugOptions, TraceEntry};
+
+mod user_event;
+pub use user_event::{UserEventHandler, UserEventHandlers};
+
 mod vault_delta;
 use vault_delta::AccountVaultDeltaHandler;
 
@@ -12,33 +25,116 @@ use vault_delta::AccountVaultDeltaHandler;
 ///
 /// Below we define the sub-handlers and their associated event types:
 ///
-/// - [VaultDeltaHandler]:
+/// - [AccountVaultDeltaHandler]:
 ///    - [Event::AddAssetToAccountVault]
 ///    - [Event::RemoveAssetFromAccountVault]
-#[derive(Default, Debug)]
+/// - [AccountStorageDeltaHandler]:
+///    - [Event::SetAccountStorageItem]
+///    - [Event::SetAccountStorageMapItem]
+///    - [Event::SetAccountStorageArrayItem]
+/// - [TraceHandler]:
+///    - [Event::TraceStack]
+///    - [Event::TraceMemory]
+///    - [Event::TraceValue]
+///    - [Event::TraceProcedureCall]
+///
+/// Events with IDs outside of [Event::KERNEL_EVENT_ID_RANGE] are user-defined events. These are
+/// dispatched to the [UserEventHandler] registered for the event ID, and the data returned by the
+/// handler is recorded as a [TransactionLog]. User-defined events for which no handler is
+/// registered are ignored.
+#[derive(Default)]
 pub struct EventHandler {
     acct_vault_delta_handler: AccountVaultDeltaHandler,
+    acct_storage_delta_handler: AccountStorageDeltaHandler,
+    trace_handler: TraceHandler,
+    user_event_handlers: UserEventHandlers,
+    logs: Vec<TransactionLog>,
 }
 
 impl EventHandler {
+    /// Returns a new [EventHandler] which dispatches user-defined events to the provided handlers.
+    pub fn new(user_event_handlers: UserEventHandlers) -> Self {
+        Self {
+            user_event_handlers,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the [DebugOptions] which control the debug information recorded by this
+    /// [EventHandler].
+    pub fn set_debug_options(&mut self, options: DebugOptions) {
+        self.trace_handler = TraceHandler::new(options);
+    }
+
+    /// Returns a mutable reference to the handlers of the user-defined events.
+    pub fn user_event_handlers_mut(&mut self) -> &mut UserEventHandlers {
+        &mut self.user_event_handlers
+    }
+
+    /// Removes the handlers of the user-defined events from this [EventHandler] and returns them.
+    pub fn take_user_event_handlers(&mut self) -> UserEventHandlers {
+        core::mem::take(&mut self.user_event_handlers)
+    }
+
+    /// Removes the trace entries recorded by this [EventHandler] and returns them.
+    pub fn take_trace(&mut self) -> Vec<TraceEntry> {
+        self.trace_handler.take_entries()
+    }
+
     /// Handles the event with the provided event ID.
     pub fn handle_event<S: ProcessState>(
         &mut self,
         process: &S,
         event_id: u32,
     ) -> Result<HostResponse, ExecutionError> {
+        if !Event::is_kernel_event(event_id) {
+            return self.handle_user_event(process, event_id);
+        }
+
         match Event::try_from(event_id)? {
             Event::AddAssetToAccountVault => self.acct_vault_delta_handler.add_asset(process),
             Event::RemoveAssetFromAccountVault => {
                 self.acct_vault_delta_handler.remove_asset(process)
             }
+            Event::SetAccountStorageItem => self.acct_storage_delta_handler.set_item(process),
+            Event::SetAccountStorageMapItem => {
+                self.acct_storage_delta_handler.set_map_item(process)
+            }
+            Event::SetAccountStorageArrayItem => {
+                self.acct_storage_delta_handler.set_array_item(process)
+            }
+            Event::TraceStack => self.trace_handler.trace_stack(process),
+            Event::TraceMemory => self.trace_handler.trace_memory(process),
+            Event::TraceValue => self.trace_handler.trace_value(process),
+            Event::TraceProcedureCall => self.trace_handler.trace_procedure_call(process),
+        }
+    }
+
+    /// Dispatches the user-defined event with the provided event ID to the handler registered for
+    /// it, and records the data returned by the handler as a [TransactionLog].
+    fn handle_user_event<S: ProcessState>(
+        &mut self,
+        process: &S,
+        event_id: u32,
+    ) -> Result<HostResponse, ExecutionError> {
+        if let Some(handler) = self.user_event_handlers.get_mut(event_id) {
+            if let Some(data) = handler.on_event(process, event_id)? {
+                self.logs.push(TransactionLog::new(event_id, data));
+            }
         }
+
+        Ok(HostResponse::None)
     }
 
     /// Consumes the [EventHandler] and finalizes the sub-handlers it is composed of.
     ///
-    /// Returns the result of finalizing the sub-handlers.
-    pub fn finalize(self) -> AccountVaultDelta {
-        self.acct_vault_delta_handler.finalize()
+    /// Returns the result of finalizing the sub-handlers, as well as the logs recorded by the
+    /// handlers of the user-defined events.
+    pub fn finalize(self) -> (AccountStorageDelta, AccountVaultDelta, Vec<TransactionLog>) {
+        (
+            self.acct_storage_delta_handler.finalize(),
+            self.acct_vault_delta_handler.finalize(),
+            self.logs,
+        )
     }
 }
@@ -0,0 +1,248 @@
+use miden_objects::{
+    accounts::AccountStorageDelta,
+    utils::collections::{btree_map::Entry, BTreeMap},
+    Digest, StarkField, Word, EMPTY_WORD,
+};
+use vm_processor::{ContextId, ExecutionError, HostResponse, ProcessState};
+
+/// The [AccountStorageDeltaHandler] is responsible for tracking changes to the storage of the
+/// account the transaction is being executed against.
+///
+/// The handler records the changes in [AccountStorageDeltaHandler::slot_updates] map, where the
+/// key is the index of the updated storage slot, and the value is a tuple of the value of the
+/// slot before the first update and the value of the slot after the latest update.
+///
+/// The entries set in storage maps and the items set in storage arrays are recorded in the
+/// [AccountStorageDeltaHandler::map_updates] and [AccountStorageDeltaHandler::array_updates] maps
+/// respectively, where the key is the index of the storage slot, and the value contains the latest
+/// value set for each key or item index.
+#[derive(Default, Debug)]
+pub struct AccountStorageDeltaHandler {
+    slot_updates: BTreeMap<u8, (Word, Word)>,
+    map_updates: BTreeMap<u8, BTreeMap<Digest, Word>>,
+    array_updates: BTreeMap<u8, BTreeMap<u64, Word>>,
+}
+
+impl AccountStorageDeltaHandler {
+    // MODIFIERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Extracts the index of the storage slot being set, as well as the old and the new values of
+    /// the slot from the process state and updates the
+    /// [AccountStorageDeltaHandler::slot_updates] map.
+    pub fn set_item<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        if process.ctx() != ContextId::root() {
+            return Err(ExecutionError::EventError(
+                "SetAccountStorageItem event can only be emitted from the root context".into(),
+            ));
+        }
+
+        // the stack is expected to be [OLD_VALUE, NEW_VALUE, index, ...]
+        let old_value = process.get_stack_word(0);
+        let new_value = process.get_stack_word(1);
+        let index = get_slot_index(process.get_stack_item(8).as_int())?;
+
+        self.record_slot_update(index, old_value, new_value);
+
+        Ok(HostResponse::None)
+    }
+
+    /// Extracts the index of the map slot, as well as the key and the new value of the map entry
+    /// being set from the process state and updates the [AccountStorageDeltaHandler::map_updates]
+    /// map.
+    pub fn set_map_item<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        if process.ctx() != ContextId::root() {
+            return Err(ExecutionError::EventError(
+                "SetAccountStorageMapItem event can only be emitted from the root context".into(),
+            ));
+        }
+
+        // the stack is expected to be [KEY, NEW_VALUE, index, ...]
+        let key = process.get_stack_word(0);
+        let new_value = process.get_stack_word(1);
+        let index = get_slot_index(process.get_stack_item(8).as_int())?;
+
+        self.map_updates.entry(index).or_default().insert(key.into(), new_value);
+
+        Ok(HostResponse::None)
+    }
+
+    /// Extracts the index of the array slot, as well as the position and the new value of the
+    /// array item being set from the process state and updates the
+    /// [AccountStorageDeltaHandler::array_updates] map.
+    pub fn set_array_item<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        if process.ctx() != ContextId::root() {
+            return Err(ExecutionError::EventError(
+                "SetAccountStorageArrayItem event can only be emitted from the root context".into(),
+            ));
+        }
+
+        // the stack is expected to be [NEW_VALUE, index, item_index, ...]
+        let new_value = process.get_stack_word(0);
+        let index = get_slot_index(process.get_stack_item(4).as_int())?;
+        let item_index = process.get_stack_item(5).as_int();
+
+        self.array_updates.entry(index).or_default().insert(item_index, new_value);
+
+        Ok(HostResponse::None)
+    }
+
+    // ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the index, the value before the first update, and the value after the latest update
+    /// of every storage slot whose value was changed.
+    ///
+    /// Slots which were set back to their original values are not included.
+    pub fn updated_slots(&self) -> impl Iterator<Item = (u8, Word, Word)> + '_ {
+        self.slot_updates
+            .iter()
+            .filter(|(_, (old_value, new_value))| old_value != new_value)
+            .map(|(index, (old_value, new_value))| (*index, *old_value, *new_value))
+    }
+
+    // CONSUMERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Consumes the [AccountStorageDeltaHandler] and returns the [AccountStorageDelta] that
+    /// represents the changes to the account's storage.
+    ///
+    /// Slots which were set back to their original values are not included in the delta, and
+    /// neither are the map entries and the array items set in such slots. The words of multi-word
+    /// values are not known to the handler, and thus are not included in the delta either.
+    pub fn finalize(mut self) -> AccountStorageDelta {
+        let mut cleared_items = Vec::new();
+        let mut updated_items = Vec::new();
+        let mut updated_maps = Vec::new();
+        let mut updated_arrays = Vec::new();
+
+        for (index, _, new_value) in self.updated_slots().collect::<Vec<_>>() {
+            if new_value == EMPTY_WORD {
+                cleared_items.push(index);
+                continue;
+            }
+
+            updated_items.push((index, new_value));
+
+            if let Some(entries) = self.map_updates.remove(&index) {
+                let entries = entries.into_iter().map(|(key, value)| (key.into(), value));
+                updated_maps.push((index, entries.collect()));
+            }
+
+            if let Some(items) = self.array_updates.remove(&index) {
+                updated_arrays.push((index, items.into_iter().collect()));
+            }
+        }
+
+        AccountStorageDelta {
+            cleared_items,
+            updated_items,
+            updated_words: Vec::new(),
+            updated_maps,
+            updated_arrays,
+        }
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Records the update of the storage slot at the specified index, keeping the value of the
+    /// slot before the first update.
+    fn record_slot_update(&mut self, index: u8, old_value: Word, new_value: Word) {
+        match self.slot_updates.entry(index) {
+            Entry::Occupied(mut entry) => entry.get_mut().1 = new_value,
+            Entry::Vacant(entry) => {
+                entry.insert((old_value, new_value));
+            }
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Converts the provided value into a storage slot index.
+fn get_slot_index(index: u64) -> Result<u8, ExecutionError> {
+    u8::try_from(index).map_err(|_| {
+        ExecutionError::EventError(format!(
+            "Failed to apply account storage delta - storage slot index {index} is invalid"
+        ))
+    })
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{AccountStorageDeltaHandler, EMPTY_WORD};
+    use miden_objects::{Felt, ONE, ZERO};
+
+    #[test]
+    fn storage_delta_handler_updated_slots() {
+        let value_1 = [ONE, ONE, ONE, ONE];
+        let value_2 = [ONE, ZERO, ZERO, ZERO];
+        let value_3 = [Felt::new(3), ZERO, ZERO, ZERO];
+
+        let mut handler = AccountStorageDeltaHandler::default();
+
+        // slot 1 is updated twice; only the first old value and the latest new value are kept
+        handler.record_slot_update(1, value_1, value_2);
+        handler.record_slot_update(1, value_2, value_3);
+
+        // slot 2 is set back to its original value
+        handler.record_slot_update(2, value_1, value_2);
+        handler.record_slot_update(2, value_2, value_1);
+
+        // slot 3 is cleared
+        handler.record_slot_update(3, value_1, EMPTY_WORD);
+
+        assert_eq!(
+            handler.updated_slots().collect::<Vec<_>>(),
+            vec![(1, value_1, value_3), (3, value_1, EMPTY_WORD)]
+        );
+
+        let delta = handler.finalize();
+        assert_eq!(delta.updated_items, vec![(1, value_3)]);
+        assert_eq!(delta.cleared_items, vec![3]);
+    }
+
+    #[test]
+    fn storage_delta_handler_slot_set_back_to_original_value() {
+        let value_1 = [ONE, ONE, ONE, ONE];
+        let value_2 = [ONE, ZERO, ZERO, ZERO];
+
+        let mut handler = AccountStorageDeltaHandler::default();
+        handler.record_slot_update(2, value_1, value_2);
+        handler.record_slot_update(2, value_2, EMPTY_WORD);
+        handler.record_slot_update(2, EMPTY_WORD, value_1);
+        assert_eq!(handler.updated_slots().count(), 0);
+
+        let delta = handler.finalize();
+        assert!(delta.is_empty());
+    }
+
+    #[test]
+    fn storage_delta_handler_slot_cleared() {
+        let value_1 = [ONE, ONE, ONE, ONE];
+        let value_2 = [ONE, ZERO, ZERO, ZERO];
+
+        let mut handler = AccountStorageDeltaHandler::default();
+        handler.record_slot_update(4, value_1, value_2);
+        handler.record_slot_update(4, value_2, EMPTY_WORD);
+        assert_eq!(handler.updated_slots().collect::<Vec<_>>(), vec![(4, value_1, EMPTY_WORD)]);
+
+        let delta = handler.finalize();
+        assert_eq!(delta.cleared_items, vec![4]);
+        assert!(delta.updated_items.is_empty());
+    }
+}
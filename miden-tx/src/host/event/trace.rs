@@ -0,0 +1,247 @@
+use core::fmt;
+use miden_objects::{Digest, Felt, StarkField, Word, EMPTY_WORD};
+use vm_processor::{ExecutionError, HostResponse, ProcessState};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The maximum number of stack elements which can be recorded by a single trace stack event.
+///
+/// The number of elements to record is itself located at the top of the stack, and thus only the
+/// remaining 15 elements of the top 16 stack elements can be recorded.
+pub const MAX_TRACED_STACK_ELEMENTS: u64 = 15;
+
+/// The maximum number of memory words which can be recorded by a single trace memory event.
+pub const MAX_TRACED_MEMORY_WORDS: u64 = 256;
+
+// DEBUG OPTIONS
+// ================================================================================================
+
+/// Options which control the debug information collected by the transaction host during
+/// transaction execution.
+///
+/// - record_trace: if set, trace entries emitted by the transaction via the
+///   `miden::sat::debug` procedures are recorded. Otherwise, these events are ignored.
+/// - record_call_path: if set, the roots of the note scripts and the transaction script invoked
+///   by the kernel, as well as the roots of the account procedures invoking state-mutating kernel
+///   procedures, are recorded.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DebugOptions {
+    pub record_trace: bool,
+    pub record_call_path: bool,
+}
+
+impl DebugOptions {
+    /// Returns [DebugOptions] with the recording of both the trace entries and the call path
+    /// enabled.
+    pub fn full() -> Self {
+        Self {
+            record_trace: true,
+            record_call_path: true,
+        }
+    }
+}
+
+// TRACE ENTRY
+// ================================================================================================
+
+/// A single entry of the debug trace recorded during transaction execution.
+///
+/// Each entry contains the clock cycle at which the corresponding trace event was emitted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TraceEntry {
+    /// Elements from the top of the stack, with the topmost element first.
+    Stack { clk: u32, values: Vec<Felt> },
+    /// A range of memory words in the context the event was emitted from.
+    Memory {
+        clk: u32,
+        start_addr: u32,
+        words: Vec<Word>,
+    },
+    /// A single value annotated with a user-provided label.
+    Value { clk: u32, label: Felt, value: Felt },
+    /// The root of a procedure on the call path of the transaction.
+    ProcedureCall { clk: u32, root: Digest },
+}
+
+impl TraceEntry {
+    /// Returns the clock cycle at which this entry was recorded.
+    pub fn clk(&self) -> u32 {
+        match self {
+            Self::Stack { clk, .. }
+            | Self::Memory { clk, .. }
+            | Self::Value { clk, .. }
+            | Self::ProcedureCall { clk, .. } => *clk,
+        }
+    }
+}
+
+impl fmt::Display for TraceEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Stack { clk, values } => {
+                let values = values.iter().map(|value| value.as_int()).collect::<Vec<_>>();
+                write!(f, "[{clk}] stack: {values:?}")
+            }
+            Self::Memory {
+                clk,
+                start_addr,
+                words,
+            } => {
+                write!(f, "[{clk}] memory:")?;
+                for (addr, word) in (*start_addr..).zip(words.iter()) {
+                    let word = word.iter().map(|value| value.as_int()).collect::<Vec<_>>();
+                    write!(f, " {addr}: {word:?}")?;
+                }
+                Ok(())
+            }
+            Self::Value { clk, label, value } => {
+                write!(f, "[{clk}] value {}: {}", label.as_int(), value.as_int())
+            }
+            Self::ProcedureCall { clk, root } => write!(f, "[{clk}] call: {root}"),
+        }
+    }
+}
+
+// TRACE HANDLER
+// ================================================================================================
+
+/// The [TraceHandler] is responsible for recording the trace entries emitted by a transaction in
+/// the order in which they were emitted.
+///
+/// What is recorded is controlled by the [DebugOptions] the handler was instantiated with. Trace
+/// events which are not enabled are ignored, and thus the handler does not affect the execution of
+/// transactions when debugging is disabled.
+#[derive(Default, Debug)]
+pub struct TraceHandler {
+    options: DebugOptions,
+    entries: Vec<TraceEntry>,
+}
+
+impl TraceHandler {
+    /// Returns a new [TraceHandler] instantiated with the provided [DebugOptions].
+    pub fn new(options: DebugOptions) -> Self {
+        Self {
+            options,
+            entries: Vec::new(),
+        }
+    }
+
+    // MODIFIERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Records the top `n` stack elements below `n`.
+    ///
+    /// The stack is expected to be [n, a_0, ..., a_{n-1}, ...].
+    pub fn trace_stack<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        if !self.options.record_trace {
+            return Ok(HostResponse::None);
+        }
+
+        let num_elements = process.get_stack_item(0).as_int();
+        if num_elements > MAX_TRACED_STACK_ELEMENTS {
+            return Err(ExecutionError::EventError(format!(
+                "Failed to trace stack - number of elements {num_elements} exceeds the maximum of \
+                {MAX_TRACED_STACK_ELEMENTS}"
+            )));
+        }
+
+        let values = (1..=num_elements as usize).map(|pos| process.get_stack_item(pos)).collect();
+        self.entries.push(TraceEntry::Stack {
+            clk: process.clk(),
+            values,
+        });
+
+        Ok(HostResponse::None)
+    }
+
+    /// Records a range of memory words in the current context.
+    ///
+    /// The stack is expected to be [start_addr, num_words, ...]. Memory words which were never
+    /// written to are recorded as empty words.
+    pub fn trace_memory<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        if !self.options.record_trace {
+            return Ok(HostResponse::None);
+        }
+
+        let start_addr = process.get_stack_item(0).as_int();
+        let num_words = process.get_stack_item(1).as_int();
+        if num_words > MAX_TRACED_MEMORY_WORDS {
+            return Err(ExecutionError::EventError(format!(
+                "Failed to trace memory - number of words {num_words} exceeds the maximum of \
+                {MAX_TRACED_MEMORY_WORDS}"
+            )));
+        }
+
+        let start_addr = u32::try_from(start_addr)
+            .ok()
+            .filter(|addr| addr.checked_add(num_words as u32).is_some())
+            .ok_or_else(|| {
+                ExecutionError::EventError(format!(
+                    "Failed to trace memory - address range starting at {start_addr} is invalid"
+                ))
+            })?;
+
+        let ctx = process.ctx();
+        let words = (start_addr..start_addr + num_words as u32)
+            .map(|addr| process.get_mem_value(ctx, addr).unwrap_or(EMPTY_WORD))
+            .collect();
+        self.entries.push(TraceEntry::Memory {
+            clk: process.clk(),
+            start_addr,
+            words,
+        });
+
+        Ok(HostResponse::None)
+    }
+
+    /// Records a value annotated with a label.
+    ///
+    /// The stack is expected to be [label, value, ...].
+    pub fn trace_value<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        if !self.options.record_trace {
+            return Ok(HostResponse::None);
+        }
+
+        self.entries.push(TraceEntry::Value {
+            clk: process.clk(),
+            label: process.get_stack_item(0),
+            value: process.get_stack_item(1),
+        });
+
+        Ok(HostResponse::None)
+    }
+
+    /// Records the root of a procedure on the call path of the transaction.
+    ///
+    /// The stack is expected to be [ROOT, ...].
+    pub fn trace_procedure_call<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        if !self.options.record_call_path {
+            return Ok(HostResponse::None);
+        }
+
+        self.entries.push(TraceEntry::ProcedureCall {
+            clk: process.clk(),
+            root: process.get_stack_word(0).into(),
+        });
+
+        Ok(HostResponse::None)
+    }
+
+    /// Removes the recorded trace entries from this [TraceHandler] and returns them.
+    pub fn take_entries(&mut self) -> Vec<TraceEntry> {
+        core::mem::take(&mut self.entries)
+    }
+}
@@ -0,0 +1,83 @@
+use crate::TransactionHostError;
+use core::ops::Range;
+use miden_objects::{transaction::Event, Felt};
+use vm_processor::{ExecutionError, ProcessState};
+
+// USER EVENT HANDLER
+// ================================================================================================
+
+/// A handler of user-defined events emitted by a transaction.
+///
+/// User-defined events are events with IDs outside of [Event::KERNEL_EVENT_ID_RANGE]. Such events
+/// can be emitted by account code, note scripts, and transaction scripts via the `emit.<event_id>`
+/// instruction.
+pub trait UserEventHandler {
+    /// Handles the event with the specified ID emitted by a transaction.
+    ///
+    /// The handler has access to the state of the process at the time the event was emitted. If
+    /// the handler returns data, the data is recorded in the logs of the transaction.
+    ///
+    /// # Errors
+    /// Returning an error aborts the execution of the transaction.
+    fn on_event(
+        &mut self,
+        process: &dyn ProcessState,
+        event_id: u32,
+    ) -> Result<Option<Vec<Felt>>, ExecutionError>;
+}
+
+// USER EVENT HANDLERS
+// ================================================================================================
+
+/// A registry of [UserEventHandler]s, each of which is responsible for a non-overlapping range of
+/// event IDs.
+#[derive(Default)]
+pub struct UserEventHandlers {
+    handlers: Vec<(Range<u32>, Box<dyn UserEventHandler>)>,
+}
+
+impl UserEventHandlers {
+    /// Registers the provided handler for the specified range of event IDs.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The range of event IDs is empty.
+    /// - The range overlaps with the range of event IDs reserved for the kernel events.
+    /// - The range overlaps with the range of a previously registered handler.
+    pub fn register(
+        &mut self,
+        event_ids: Range<u32>,
+        handler: Box<dyn UserEventHandler>,
+    ) -> Result<(), TransactionHostError> {
+        if event_ids.is_empty() {
+            return Err(TransactionHostError::EmptyEventIdRange(event_ids));
+        }
+
+        if overlaps(&event_ids, &Event::KERNEL_EVENT_ID_RANGE) {
+            return Err(TransactionHostError::ReservedEventIdRange(event_ids));
+        }
+
+        if self.handlers.iter().any(|(range, _)| overlaps(&event_ids, range)) {
+            return Err(TransactionHostError::DuplicateEventIdRange(event_ids));
+        }
+
+        self.handlers.push((event_ids, handler));
+        Ok(())
+    }
+
+    /// Returns the handler registered for the specified event ID, if any.
+    pub fn get_mut(&mut self, event_id: u32) -> Option<&mut Box<dyn UserEventHandler>> {
+        self.handlers
+            .iter_mut()
+            .find(|(range, _)| range.contains(&event_id))
+            .map(|(_, handler)| handler)
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns true if the two provided ranges have at least one element in common.
+fn overlaps(a: &Range<u32>, b: &Range<u32>) -> bool {
+    a.start < b.end && b.start < a.end
+}
@@ -1,10 +1,14 @@
+use crate::{TransactionAuthenticator, TransactionHostError};
+use core::ops::Range;
+use std::rc::Rc;
 use vm_processor::{
-    AdviceExtractor, AdviceInjector, AdviceProvider, ExecutionError, Host, HostResponse,
-    ProcessState,
+    AdviceExtractor, AdviceInjector, AdviceProvider, AdviceSource, ExecutionError, Host,
+    HostResponse, ProcessState,
 };
 
 mod event;
-pub(crate) use event::EventHandler;
+pub use event::{DebugOptions, TraceEntry, UserEventHandler};
+pub(crate) use event::{EventHandler, UserEventHandlers};
 
 /// The [TransactionHost] is responsible for handling [Host] requests made by a transaction.
 ///
@@ -14,10 +18,15 @@ pub(crate) use event::EventHandler;
 /// - [TransactionHost::event_handler] - an [EventHandler] which is used to handle events emitted
 /// by the transaction runtime.
 ///
+/// Optionally, the [TransactionHost] can be provided with a [TransactionAuthenticator]. If
+/// provided, the signatures requested by a transaction are generated by the authenticator rather
+/// than by the [AdviceProvider].
+///
 /// The [TransactionHost] implements the [Host] trait.
 pub struct TransactionHost<A> {
     adv_provider: A,
     event_handler: EventHandler,
+    authenticator: Option<Rc<dyn TransactionAuthenticator>>,
 }
 
 impl<A: AdviceProvider> TransactionHost<A> {
@@ -26,9 +35,77 @@ impl<A: AdviceProvider> TransactionHost<A> {
         Self {
             adv_provider,
             event_handler: EventHandler::default(),
+            authenticator: None,
+        }
+    }
+
+    /// Returns a new [TransactionHost] instance with the provided [AdviceProvider] and handlers of
+    /// the user-defined events.
+    pub(crate) fn with_user_event_handlers(
+        adv_provider: A,
+        user_event_handlers: UserEventHandlers,
+    ) -> Self {
+        Self {
+            adv_provider,
+            event_handler: EventHandler::new(user_event_handlers),
+            authenticator: None,
         }
     }
 
+    /// Sets the [DebugOptions] which control the debug information recorded by this host during
+    /// transaction execution.
+    ///
+    /// The recorded trace entries can be retrieved via [EventHandler::take_trace()] after
+    /// execution.
+    pub fn with_debug_options(mut self, options: DebugOptions) -> Self {
+        self.event_handler.set_debug_options(options);
+        self
+    }
+
+    /// Sets the [TransactionAuthenticator] used to generate the signatures requested by the
+    /// transaction.
+    pub fn with_authenticator(mut self, authenticator: Rc<dyn TransactionAuthenticator>) -> Self {
+        self.authenticator = Some(authenticator);
+        self
+    }
+
+    /// Registers the provided handler for the user-defined events with IDs in the specified range.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The range of event IDs is empty.
+    /// - The range overlaps with the range of event IDs reserved for the kernel events.
+    /// - The range overlaps with the range of a previously registered handler.
+    pub fn register_event_handler(
+        &mut self,
+        event_ids: Range<u32>,
+        handler: Box<dyn UserEventHandler>,
+    ) -> Result<(), TransactionHostError> {
+        self.event_handler.user_event_handlers_mut().register(event_ids, handler)
+    }
+
+    /// Generates the signature requested by the transaction using the [TransactionAuthenticator]
+    /// and pushes it onto the advice stack.
+    ///
+    /// The operand stack is expected to be [PUB_KEY, MSG, ...].
+    fn push_signature<S: ProcessState>(
+        &mut self,
+        process: &S,
+        authenticator: &dyn TransactionAuthenticator,
+    ) -> Result<HostResponse, ExecutionError> {
+        let pub_key = process.get_stack_word(0);
+        let message = process.get_stack_word(1);
+        let signature = authenticator.get_signature(pub_key, message).map_err(|err| {
+            ExecutionError::EventError(format!("Failed to generate signature - {err}"))
+        })?;
+
+        for element in signature {
+            self.adv_provider.push_stack(AdviceSource::Value(element))?;
+        }
+
+        Ok(HostResponse::None)
+    }
+
     /// Consumes the [TransactionHost] and returns the [AdviceProvider] and [EventHandler] it was
     /// composed of.
     pub fn into_parts(self) -> (A, EventHandler) {
@@ -50,6 +127,12 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
         process: &S,
         injector: AdviceInjector,
     ) -> Result<HostResponse, ExecutionError> {
+        if let (AdviceInjector::SigToStack { .. }, Some(authenticator)) =
+            (&injector, self.authenticator.clone())
+        {
+            return self.push_signature(process, authenticator.as_ref());
+        }
+
         self.adv_provider.set_advice(process, &injector)
     }
 
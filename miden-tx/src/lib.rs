@@ -10,6 +10,9 @@ use miden_objects::{
 use vm_core::Program;
 use vm_processor::{ExecutionError, RecAdviceProvider};
 
+mod auth;
+pub use auth::{get_falcon_signature, BasicAuthenticator, TransactionAuthenticator};
+
 mod compiler;
 pub use compiler::{ScriptTarget, TransactionCompiler};
 
@@ -20,7 +23,7 @@ mod executor;
 pub use executor::TransactionExecutor;
 
 pub mod host;
-pub use host::TransactionHost;
+pub use host::{DebugOptions, TraceEntry, TransactionHost, UserEventHandler};
 
 mod prover;
 pub use prover::{ProvingOptions, TransactionProver};
@@ -33,8 +36,9 @@ pub use verifier::TransactionVerifier;
 
 mod error;
 pub use error::{
-    DataStoreError, TransactionCompilerError, TransactionError, TransactionExecutorError,
-    TransactionProverError, TransactionVerifierError,
+    AuthenticationError, DataStoreError, TransactionCompilerError, TransactionError,
+    TransactionExecutorError, TransactionHostError, TransactionProverError,
+    TransactionVerifierError,
 };
 
 #[cfg(test)]
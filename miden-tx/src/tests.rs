@@ -1,13 +1,15 @@
 use super::{
-    Account, AccountId, BlockHeader, ChainMmr, DataStore, DataStoreError, NoteOrigin,
-    TransactionExecutor, TransactionHost, TransactionProver, TransactionVerifier, TryFromVmResult,
+    Account, AccountId, BlockHeader, ChainMmr, DataStore, DataStoreError, DebugOptions, NoteOrigin,
+    TraceEntry, TransactionExecutor, TransactionExecutorError, TransactionHost, TransactionProver,
+    TransactionVerifier, TryFromVmResult, UserEventHandler,
 };
+use miden_lib::transaction::TransactionKernelError;
 use miden_objects::{
-    accounts::AccountCode,
+    accounts::{AccountCode, AccountCodeDelta},
     assembly::{Assembler, ModuleAst, ProgramAst},
     assets::{Asset, FungibleAsset},
-    notes::RecordedNote,
-    transaction::{CreatedNotes, FinalAccountStub},
+    notes::{NoteType, RecordedNote},
+    transaction::{CreatedNotes, Event, FinalAccountStub, TransactionLog},
     Felt, StarkField, Word,
 };
 use miden_prover::ProvingOptions;
@@ -16,14 +18,15 @@ use mock::{
         non_fungible_asset, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
         ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
         ACCOUNT_PROCEDURE_INCR_NONCE_PROC_IDX, ACCOUNT_PROCEDURE_SET_CODE_PROC_IDX,
-        ACCOUNT_PROCEDURE_SET_ITEM_PROC_IDX, CHILD_ROOT_PARENT_LEAF_INDEX, CHILD_SMT_DEPTH,
-        CHILD_STORAGE_INDEX_0, FUNGIBLE_ASSET_AMOUNT,
+        ACCOUNT_PROCEDURE_SET_ITEM_PROC_IDX, ACCOUNT_PROCEDURE_SET_ITEM_WORDS_PROC_IDX,
+        CHILD_ROOT_PARENT_LEAF_INDEX, CHILD_SMT_DEPTH, CHILD_STORAGE_INDEX_0,
+        FUNGIBLE_ASSET_AMOUNT, STORAGE_INDEX_4,
     },
     mock::{account::MockAccountType, notes::AssetPreservationStatus, transaction::mock_inputs},
     utils::prepare_word,
 };
 use vm_core::utils::to_hex;
-use vm_processor::{AdviceInputs, MemAdviceProvider};
+use vm_processor::{AdviceInputs, ExecutionError, MemAdviceProvider, ProcessState};
 
 // TESTS
 // ================================================================================================
@@ -37,8 +40,11 @@ fn test_transaction_executor_witness() {
     executor.load_account(account_id).unwrap();
 
     let block_ref = data_store.block_header.block_num().as_int() as u32;
-    let note_origins =
-        data_store.notes.iter().map(|note| note.origin().clone()).collect::<Vec<_>>();
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
 
     // execute the transaction and get the witness
     let transaction_result = executor
@@ -185,21 +191,24 @@ fn test_transaction_result_account_delta() {
             ## ------------------------------------------------------------------------------------
             # partially deplete fungible asset balance
             push.0.1.2.3
+            push.{NOTE_TYPE}
             push.999
             push.{REMOVED_ASSET_1}
-            call.wallet::send_asset drop dropw dropw
+            call.wallet::send_asset drop dropw dropw drop
 
             # totally deplete fungible asset balance
             push.0.1.2.3
+            push.{NOTE_TYPE}
             push.999
             push.{REMOVED_ASSET_2}
-            call.wallet::send_asset drop dropw dropw
+            call.wallet::send_asset drop dropw dropw drop
 
             # send non-fungible asset
             push.0.1.2.3
+            push.{NOTE_TYPE}
             push.999
             push.{REMOVED_ASSET_3}
-            call.wallet::send_asset drop dropw dropw
+            call.wallet::send_asset drop dropw dropw drop
 
             ## Update account code
             ## ------------------------------------------------------------------------------------
@@ -212,6 +221,7 @@ fn test_transaction_result_account_delta() {
         end
     ",
         NEW_ACCOUNT_ROOT = prepare_word(&*new_acct_code.root()),
+        NOTE_TYPE = NoteType::OffChain,
         REMOVED_ASSET_1 = prepare_word(&Word::from(removed_asset_1)),
         REMOVED_ASSET_2 = prepare_word(&Word::from(removed_asset_2)),
         REMOVED_ASSET_3 = prepare_word(&Word::from(removed_asset_3)),
@@ -220,8 +230,11 @@ fn test_transaction_result_account_delta() {
     let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
 
     let block_ref = data_store.block_header.block_num().as_int() as u32;
-    let note_origins =
-        data_store.notes.iter().map(|note| note.origin().clone()).collect::<Vec<_>>();
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
 
     // expected delta
     // --------------------------------------------------------------------------------------------
@@ -278,6 +291,155 @@ fn test_transaction_result_account_delta() {
     );
 }
 
+#[test]
+fn test_transaction_result_code_delta() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let new_acct_code_src = "\
+    export.account_proc_1
+        push.9.9.9.9
+        dropw
+    end
+    ";
+    let new_acct_code_ast = ModuleAst::parse(new_acct_code_src).unwrap();
+    let new_acct_code =
+        AccountCode::new(new_acct_code_ast.clone(), &mut Assembler::default()).unwrap();
+
+    let account_procedure_set_code_mast_root = to_hex(
+        &data_store.account.code().procedures()[ACCOUNT_PROCEDURE_SET_CODE_PROC_IDX].as_bytes(),
+    )
+    .unwrap();
+    let account_procedure_incr_nonce_mast_root = to_hex(
+        &data_store.account.code().procedures()[ACCOUNT_PROCEDURE_INCR_NONCE_PROC_IDX].as_bytes(),
+    )
+    .unwrap();
+
+    let tx_script_src = format!(
+        "\
+        begin
+            # update the account code
+            push.{NEW_ACCOUNT_ROOT} call.0x{account_procedure_set_code_mast_root} dropw
+            # => []
+
+            # update the account nonce
+            push.1 call.0x{account_procedure_incr_nonce_mast_root} drop
+            # => []
+        end
+    ",
+        NEW_ACCOUNT_ROOT = prepare_word(&*new_acct_code.root()),
+    );
+
+    let block_ref = data_store.block_header.block_num().as_int() as u32;
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
+
+    // if the new code is not known to the executor, only the new code root is in the delta
+    let tx_script_code = ProgramAst::parse(&tx_script_src).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let transaction_result = executor
+        .execute_transaction(account_id, block_ref, &note_origins, Some(tx_script))
+        .unwrap();
+
+    let code_delta = transaction_result.account_delta().code().unwrap();
+    assert_eq!(code_delta.code_root, new_acct_code.root());
+    assert!(code_delta.code.is_none());
+
+    // if the new code was loaded into the executor, the full new code is in the delta
+    executor.load_account_code(new_acct_code_ast).unwrap();
+    let tx_script_code = ProgramAst::parse(&tx_script_src).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let transaction_result = executor
+        .execute_transaction(account_id, block_ref, &note_origins, Some(tx_script))
+        .unwrap();
+
+    assert_eq!(
+        transaction_result.account_delta().code(),
+        Some(&AccountCodeDelta::from(new_acct_code))
+    );
+
+    // the account with the new code can be rebuilt from the delta
+    let mut account = data_store.account.clone();
+    account
+        .apply_delta(transaction_result.account_delta(), transaction_result.final_account_hash())
+        .unwrap();
+    assert_eq!(account.code().root(), code_delta.code_root);
+}
+
+#[test]
+fn test_transaction_result_storage_words() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let new_words = vec![
+        [Felt::new(9), Felt::new(8), Felt::new(7), Felt::new(6)],
+        [Felt::new(5), Felt::new(4), Felt::new(3), Felt::new(2)],
+        [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)],
+    ];
+
+    let account_procedure_set_item_words_mast_root = to_hex(
+        &data_store.account.code().procedures()[ACCOUNT_PROCEDURE_SET_ITEM_WORDS_PROC_IDX]
+            .as_bytes(),
+    )
+    .unwrap();
+    let account_procedure_incr_nonce_mast_root = to_hex(
+        &data_store.account.code().procedures()[ACCOUNT_PROCEDURE_INCR_NONCE_PROC_IDX].as_bytes(),
+    )
+    .unwrap();
+
+    let tx_script = format!(
+        "\
+        begin
+            # set the words of the multi-word value
+            push.0.0.0 push.{WORD_2} push.{WORD_1} push.{WORD_0} push.{STORAGE_INDEX_4}
+            call.0x{account_procedure_set_item_words_mast_root}
+            # => [R', OLD_COMMITMENT, 0, 0, 0, 0, 0, 0, 0, 0]
+
+            dropw dropw dropw dropw
+            # => []
+
+            # update the account nonce
+            push.1 call.0x{account_procedure_incr_nonce_mast_root} drop
+            # => []
+        end
+    ",
+        WORD_0 = prepare_word(&new_words[0]),
+        WORD_1 = prepare_word(&new_words[1]),
+        WORD_2 = prepare_word(&new_words[2]),
+    );
+    let tx_script_code = ProgramAst::parse(&tx_script).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+
+    let block_ref = data_store.block_header.block_num().as_int() as u32;
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
+
+    let transaction_result = executor
+        .execute_transaction(account_id, block_ref, &note_origins, Some(tx_script))
+        .unwrap();
+
+    // the words of the value are included in the storage delta
+    let storage_delta = transaction_result.account_delta().storage();
+    assert_eq!(storage_delta.updated_words, vec![(STORAGE_INDEX_4, new_words.clone())]);
+
+    // the words can be read back after applying the delta to the initial account
+    let mut account = data_store.account.clone();
+    account
+        .apply_delta(transaction_result.account_delta(), transaction_result.final_account_hash())
+        .unwrap();
+    assert_eq!(account.storage().get_item_words(STORAGE_INDEX_4), Some(new_words.as_slice()));
+}
+
 #[test]
 fn test_prove_witness_and_verify() {
     let data_store = MockDataStore::default();
@@ -287,8 +449,11 @@ fn test_prove_witness_and_verify() {
     executor.load_account(account_id).unwrap();
 
     let block_ref = data_store.block_header.block_num().as_int() as u32;
-    let note_origins =
-        data_store.notes.iter().map(|note| note.origin().clone()).collect::<Vec<_>>();
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
 
     // execute the transaction and get the witness
     let transaction_result = executor
@@ -314,8 +479,11 @@ fn test_prove_and_verify_with_tx_executor() {
     executor.load_account(account_id).unwrap();
 
     let block_ref = data_store.block_header.block_num().as_int() as u32;
-    let note_origins =
-        data_store.notes.iter().map(|note| note.origin().clone()).collect::<Vec<_>>();
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
 
     // prove the transaction with the executor
     let prepared_transaction = executor
@@ -343,8 +511,11 @@ fn test_tx_script() {
     executor.load_account(account_id).unwrap();
 
     let block_ref = data_store.block_header.block_num().as_int() as u32;
-    let note_origins =
-        data_store.notes.iter().map(|note| note.origin().clone()).collect::<Vec<_>>();
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
 
     let tx_script_input_key = [Felt::new(9999), Felt::new(8888), Felt::new(9999), Felt::new(8888)];
     let tx_script_input_value = [Felt::new(9), Felt::new(8), Felt::new(7), Felt::new(6)];
@@ -381,6 +552,193 @@ fn test_tx_script() {
     assert!(transaction_result.is_ok());
 }
 
+#[test]
+fn test_user_event_handler() {
+    /// Records the value at the top of the stack whenever the event is emitted.
+    struct StackTopRecorder;
+
+    impl UserEventHandler for StackTopRecorder {
+        fn on_event(
+            &mut self,
+            process: &dyn ProcessState,
+            _event_id: u32,
+        ) -> Result<Option<Vec<Felt>>, ExecutionError> {
+            Ok(Some(vec![process.get_stack_item(0)]))
+        }
+    }
+
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    // event ID ranges which are empty, reserved for the kernel, or already taken are rejected
+    executor.register_event_handler(1000..1001, Box::new(StackTopRecorder)).unwrap();
+    assert!(matches!(
+        executor.register_event_handler(1000..1001, Box::new(StackTopRecorder)),
+        Err(TransactionExecutorError::RegisterEventHandlerFailed(_))
+    ));
+    assert!(matches!(
+        executor.register_event_handler(2000..2000, Box::new(StackTopRecorder)),
+        Err(TransactionExecutorError::RegisterEventHandlerFailed(_))
+    ));
+    assert!(matches!(
+        executor.register_event_handler(Event::KERNEL_EVENT_ID_RANGE, Box::new(StackTopRecorder)),
+        Err(TransactionExecutorError::RegisterEventHandlerFailed(_))
+    ));
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num().as_int() as u32;
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
+
+    let tx_script_code = ProgramAst::parse(
+        "
+    begin
+        push.7 emit.1000 drop
+
+        # events without a registered handler are ignored
+        emit.2000
+    end
+",
+    )
+    .unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+
+    // execute the transaction
+    let transaction_result = executor
+        .execute_transaction(account_id, block_ref, &note_origins, Some(tx_script))
+        .unwrap();
+
+    assert_eq!(transaction_result.logs(), &[TransactionLog::new(1000, vec![Felt::new(7)])]);
+}
+
+#[test]
+fn test_kernel_error_decoding() {
+    let data_store = MockDataStore::new(AssetPreservationStatus::TooManyFungibleInput);
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num().as_int() as u32;
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
+
+    // the consumed notes contain more assets than the created notes, and thus the asset
+    // preservation check in the epilogue fails
+    let error = executor
+        .execute_transaction(account_id, block_ref, &note_origins, None)
+        .err()
+        .unwrap();
+
+    match error {
+        TransactionExecutorError::ExecuteTransactionProgramFailed(_, Some(kernel_error)) => {
+            assert_eq!(kernel_error, TransactionKernelError::EpilogueAssetsNotPreserved);
+            assert_eq!(kernel_error.procedure(), "epilogue::finalize_transaction");
+        }
+        err => panic!("unexpected error: {err}"),
+    }
+}
+
+#[test]
+fn test_debug_trace() {
+    let data_store = MockDataStore::default();
+    let mut executor =
+        TransactionExecutor::new(data_store.clone()).with_debug_options(DebugOptions::full());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num().as_int() as u32;
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
+
+    let tx_script_code = ProgramAst::parse(
+        "
+    use.miden::sat::debug
+
+    begin
+        push.3.2.1 push.3 exec.debug::trace_stack drop drop drop
+
+        push.5.6.7.8 push.100 mem_storew dropw
+        push.1.100 exec.debug::trace_mem
+
+        push.42 push.1 exec.debug::trace_value drop
+    end
+",
+    )
+    .unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_script_root = *tx_script.hash();
+
+    executor
+        .execute_transaction(account_id, block_ref, &note_origins, Some(tx_script))
+        .unwrap();
+
+    // the transaction script is the last procedure on the call path before the traced values
+    let trace = executor.trace();
+    let script_call_idx = trace
+        .iter()
+        .position(|entry| {
+            matches!(entry, TraceEntry::ProcedureCall { root, .. } if *root == tx_script_root)
+        })
+        .unwrap();
+    assert!(trace[..script_call_idx]
+        .iter()
+        .all(|entry| matches!(entry, TraceEntry::ProcedureCall { .. })));
+
+    let traced_values = &trace[script_call_idx + 1..];
+    assert_eq!(traced_values.len(), 3);
+    assert!(matches!(
+        &traced_values[0],
+        TraceEntry::Stack { values, .. }
+            if values == &[Felt::new(1), Felt::new(2), Felt::new(3)]
+    ));
+    assert!(matches!(
+        &traced_values[1],
+        TraceEntry::Memory { start_addr: 100, words, .. }
+            if words == &[[Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)]]
+    ));
+    assert!(matches!(
+        traced_values[2],
+        TraceEntry::Value { label, value, .. } if label == Felt::new(1) && value == Felt::new(42)
+    ));
+    assert!(traced_values.windows(2).all(|entries| entries[0].clk() < entries[1].clk()));
+
+    // the trace is available even if the transaction fails
+    let failing_tx_script_code = ProgramAst::parse(
+        "
+    use.miden::sat::debug
+
+    begin
+        push.0 push.7 exec.debug::trace_value assert
+    end
+",
+    )
+    .unwrap();
+    let failing_tx_script =
+        executor.compile_tx_script(failing_tx_script_code, vec![], vec![]).unwrap();
+
+    assert!(executor
+        .execute_transaction(account_id, block_ref, &note_origins, Some(failing_tx_script))
+        .is_err());
+    assert!(matches!(
+        executor.trace().last(),
+        Some(TraceEntry::Value { label, value, .. })
+            if *label == Felt::new(7) && *value == Felt::new(0)
+    ));
+}
+
 // MOCK DATA STORE
 // ================================================================================================
 
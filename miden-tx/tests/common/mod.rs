@@ -5,17 +5,18 @@ use miden_objects::{
     assembly::ProgramAst,
     assets::{Asset, FungibleAsset},
     crypto::{dsa::rpo_falcon512::KeyPair, utils::Serializable},
-    notes::{Note, NoteOrigin, NoteScript, RecordedNote},
+    notes::{Note, NoteOrigin, NoteScript, NoteTag, NoteType, RecordedNote},
+    utils::collections::BTreeMap,
     BlockHeader, ChainMmr, Felt, StarkField, Word,
 };
-use miden_tx::{DataStore, DataStoreError};
+use miden_tx::{DataStore, DataStoreError, TransactionExecutorError};
 use mock::{
     constants::{ACCOUNT_ID_SENDER, DEFAULT_ACCOUNT_CODE},
     mock::account::MockAccountType,
     mock::notes::AssetPreservationStatus,
     mock::transaction::{mock_inputs, mock_inputs_with_existing},
 };
-use vm_processor::AdviceInputs;
+use vm_processor::{AdviceInputs, ExecutionError};
 
 // MOCK DATA STORE
 // ================================================================================================
@@ -23,6 +24,7 @@ use vm_processor::AdviceInputs;
 #[derive(Clone)]
 pub struct MockDataStore {
     pub account: Account,
+    pub account_seed: Option<Word>,
     pub block_header: BlockHeader,
     pub block_chain: ChainMmr,
     pub notes: Vec<RecordedNote>,
@@ -35,6 +37,7 @@ impl MockDataStore {
             mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
         Self {
             account,
+            account_seed: None,
             block_header,
             block_chain,
             notes: consumed_notes,
@@ -59,6 +62,7 @@ impl MockDataStore {
         }
         Self {
             account,
+            account_seed: None,
             block_header,
             block_chain,
             notes: consumed_notes,
@@ -99,6 +103,11 @@ impl DataStore for MockDataStore {
         assert_eq!(account_id, self.account.id());
         Ok(self.account.code().module().clone())
     }
+
+    fn get_account_seed(&self, account_id: AccountId) -> Result<Option<Word>, DataStoreError> {
+        assert_eq!(account_id, self.account.id());
+        Ok(self.account_seed)
+    }
 }
 
 // HELPER FUNCTIONS
@@ -125,9 +134,12 @@ pub fn get_account_with_default_account_code(
     let mut account_assembler = assembler();
 
     let account_code = AccountCode::new(account_code_ast.clone(), &mut account_assembler).unwrap();
-    let account_storage =
-        AccountStorage::new(vec![(0, (StorageSlotType::Value { value_arity: 0 }, public_key))])
-            .unwrap();
+    let account_storage = AccountStorage::new(
+        vec![(0, (StorageSlotType::Value { value_arity: 0 }, public_key))],
+        BTreeMap::new(),
+        BTreeMap::new(),
+    )
+    .unwrap();
 
     let account_vault = match assets {
         Some(asset) => AccountVault::new(&vec![asset.into()]).unwrap(),
@@ -154,7 +166,21 @@ pub fn get_note_with_fungible_asset_and_script(
         &vec![fungible_asset.into()],
         SERIAL_NUM,
         sender_id,
-        Felt::new(1),
+        NoteType::Public,
+        NoteTag::from(1),
     )
     .unwrap()
 }
+
+/// Returns the error code of the assertion which made the transaction fail, or None if the
+/// transaction did not fail due to a failed assertion.
+#[allow(dead_code)]
+pub fn get_failed_assertion_code<T>(result: Result<T, TransactionExecutorError>) -> Option<u32> {
+    match result {
+        Err(TransactionExecutorError::ExecuteTransactionProgramFailed(
+            ExecutionError::FailedAssertion { err_code, .. },
+            _,
+        )) => Some(err_code),
+        _ => None,
+    }
+}
@@ -7,15 +7,17 @@ use miden_objects::{
     assembly::{ModuleAst, ProgramAst},
     assets::{Asset, FungibleAsset, TokenSymbol},
     crypto::dsa::rpo_falcon512::{KeyPair, PublicKey},
-    notes::{NoteMetadata, NoteStub, NoteVault},
-    Felt, StarkField, Word, ZERO,
+    notes::{NoteMetadata, NoteStub, NoteTag, NoteType, NoteVault},
+    utils::collections::BTreeMap,
+    Felt, StarkField, Word, ONE, ZERO,
 };
 use miden_tx::TransactionExecutor;
 use mock::{constants::ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, utils::prepare_word};
 
 mod common;
 use common::{
-    get_new_key_pair_with_advice_map, get_note_with_fungible_asset_and_script, MockDataStore,
+    get_failed_assertion_code, get_new_key_pair_with_advice_map,
+    get_note_with_fungible_asset_and_script, MockDataStore,
 };
 
 #[test]
@@ -32,11 +34,15 @@ fn test_faucet_contract_mint_fungible_asset_succeeds() {
     executor.load_account(faucet_account.id()).unwrap();
 
     let block_ref = data_store.block_header.block_num().as_int() as u32;
-    let note_origins =
-        data_store.notes.iter().map(|note| note.origin().clone()).collect::<Vec<_>>();
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
 
     let recipient = [Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)];
-    let tag = Felt::new(4);
+    let note_type = NoteType::OffChain;
+    let tag = NoteTag::from(4);
     let amount = Felt::new(100);
 
     let tx_script_code = ProgramAst::parse(
@@ -48,6 +54,7 @@ fn test_faucet_contract_mint_fungible_asset_succeeds() {
             begin
 
                 push.{recipient}
+                push.{note_type}
                 push.{tag}
                 push.{amount}
                 call.faucet::distribute
@@ -58,6 +65,7 @@ fn test_faucet_contract_mint_fungible_asset_succeeds() {
             end
             ",
             recipient = prepare_word(&recipient),
+            note_type = note_type,
             tag = tag,
             amount = amount,
         )
@@ -79,7 +87,7 @@ fn test_faucet_contract_mint_fungible_asset_succeeds() {
     let expected_note = NoteStub::new(
         recipient.into(),
         NoteVault::new(&[fungible_asset]).unwrap(),
-        NoteMetadata::new(faucet_account.id(), tag, Felt::new(1)),
+        NoteMetadata::new(faucet_account.id(), note_type, tag, Felt::new(1)),
     )
     .unwrap();
 
@@ -103,10 +111,14 @@ fn test_faucet_contract_mint_fungible_asset_fails_exceeds_max_supply() {
     executor.load_account(faucet_account.id()).unwrap();
 
     let block_ref = data_store.block_header.block_num().as_int() as u32;
-    let note_origins =
-        data_store.notes.iter().map(|note| note.origin().clone()).collect::<Vec<_>>();
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
 
     let recipient = [Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)];
+    let note_type = NoteType::OffChain;
     let tag = Felt::new(4);
     let amount = Felt::new(250);
 
@@ -119,6 +131,7 @@ fn test_faucet_contract_mint_fungible_asset_fails_exceeds_max_supply() {
             begin
 
                 push.{recipient}
+                push.{note_type}
                 push.{tag}
                 push.{amount}
                 call.faucet::distribute
@@ -129,6 +142,7 @@ fn test_faucet_contract_mint_fungible_asset_fails_exceeds_max_supply() {
             end
             ",
             recipient = prepare_word(&recipient),
+            note_type = note_type,
             tag = tag,
             amount = amount,
         )
@@ -202,8 +216,11 @@ fn test_faucet_contract_burn_fungible_asset_succeeds() {
     executor.load_account(faucet_account.id()).unwrap();
 
     let block_ref = data_store.block_header.block_num().as_int() as u32;
-    let note_origins =
-        data_store.notes.iter().map(|note| note.origin().clone()).collect::<Vec<_>>();
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
 
     // Execute the transaction and get the witness
     let transaction_result = executor
@@ -278,10 +295,14 @@ fn get_faucet_account_with_max_supply_and_total_issuance(
         AccountCode::new(faucet_account_code_ast.clone(), &mut account_assembler).unwrap();
 
     let faucet_storage_slot_1 = [Felt::new(max_supply), Felt::new(0), Felt::new(0), Felt::new(0)];
-    let mut faucet_account_storage = AccountStorage::new(vec![
-        (0, (StorageSlotType::Value { value_arity: 0 }, public_key)),
-        (1, (StorageSlotType::Value { value_arity: 0 }, faucet_storage_slot_1)),
-    ])
+    let mut faucet_account_storage = AccountStorage::new(
+        vec![
+            (0, (StorageSlotType::Value { value_arity: 0 }, public_key)),
+            (1, (StorageSlotType::Value { value_arity: 0 }, faucet_storage_slot_1)),
+        ],
+        BTreeMap::new(),
+        BTreeMap::new(),
+    )
     .unwrap();
 
     if total_issuance.is_some() {
@@ -298,3 +319,279 @@ fn get_faucet_account_with_max_supply_and_total_issuance(
         Felt::new(1),
     )
 }
+
+// BASIC NON-FUNGIBLE FAUCET
+// ================================================================================================
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn test_non_fungible_faucet_contract_distribute() {
+    use miden_lib::faucets::create_basic_non_fungible_faucet;
+    use miden_objects::assets::NonFungibleAsset;
+    use miden_tx::BasicAuthenticator;
+    use std::rc::Rc;
+
+    let key_pair: KeyPair = KeyPair::new().unwrap();
+    let auth_scheme = AuthScheme::RpoFalcon512 {
+        pub_key: key_pair.public_key(),
+    };
+
+    let init_seed: [u8; 32] = [
+        90, 110, 209, 94, 84, 105, 250, 242, 223, 203, 216, 124, 22, 159, 14, 132, 215, 85, 183,
+        204, 149, 90, 166, 68, 100, 73, 106, 168, 125, 237, 138, 16,
+    ];
+    let token_symbol = TokenSymbol::try_from("NFT").unwrap();
+
+    let (faucet_account, faucet_seed) =
+        create_basic_non_fungible_faucet(init_seed, token_symbol.clone(), Some(1), auth_scheme)
+            .unwrap();
+    assert!(faucet_account.is_faucet());
+    assert_eq!(
+        faucet_account.storage().get_item(1),
+        [Felt::new(1), token_symbol.into(), ZERO, ZERO].into()
+    );
+
+    let mut data_store =
+        MockDataStore::with_existing(Some(faucet_account.clone()), Some(vec![]), None);
+    data_store.account_seed = Some(faucet_seed);
+    let mut executor = TransactionExecutor::new(data_store.clone())
+        .with_authenticator(Rc::new(BasicAuthenticator::new([key_pair])));
+    executor.load_account(faucet_account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num().as_int() as u32;
+
+    let recipient = [Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)];
+    let note_type = NoteType::OffChain;
+    let tag = NoteTag::from(4);
+    let data_hash_1: Word = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+    let data_hash_2: Word = [Felt::new(9), Felt::new(10), Felt::new(11), Felt::new(12)];
+
+    let distribute_code = |data_hash: &Word| {
+        format!(
+            "
+                push.{recipient}
+                push.{note_type}
+                push.{tag}
+                push.{data_hash}
+                call.faucet::distribute
+                dropw dropw drop drop
+            ",
+            recipient = prepare_word(&recipient),
+            note_type = note_type,
+            data_hash = prepare_word(data_hash),
+        )
+    };
+    let tx_script_code = |distributions: String| {
+        ProgramAst::parse(&format!(
+            "
+            use.miden::faucets::basic_non_fungible->faucet
+            use.miden::auth::basic->auth_tx
+
+            begin
+                {distributions}
+                call.auth_tx::auth_tx_rpo_falcon512
+            end
+            "
+        ))
+        .unwrap()
+    };
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let tx_script = executor
+        .compile_tx_script(tx_script_code(distribute_code(&data_hash_1)), vec![], vec![])
+        .unwrap();
+    let transaction_result = executor
+        .execute_transaction(faucet_account.id(), block_ref, &[], Some(tx_script))
+        .unwrap();
+
+    let non_fungible_asset: Asset =
+        NonFungibleAsset::from_parts(faucet_account.id(), data_hash_1).unwrap().into();
+    let expected_note = NoteStub::new(
+        recipient.into(),
+        NoteVault::new(&[non_fungible_asset]).unwrap(),
+        NoteMetadata::new(faucet_account.id(), note_type, tag, Felt::new(1)),
+    )
+    .unwrap();
+
+    let created_note = transaction_result.created_notes().notes()[0].clone();
+    assert!(created_note.recipient() == expected_note.recipient());
+    assert!(created_note.vault() == expected_note.vault());
+    assert!(created_note.metadata() == expected_note.metadata());
+
+    // the number of outstanding assets is updated in slot 2
+    assert!(transaction_result
+        .account_delta()
+        .storage()
+        .updated_items
+        .contains(&(2, [ONE, ZERO, ZERO, ZERO])));
+
+    // CONSTRUCT AND EXECUTE TX (Failure - max supply exceeded)
+    // --------------------------------------------------------------------------------------------
+    let distributions = distribute_code(&data_hash_1) + &distribute_code(&data_hash_2);
+    let tx_script = executor
+        .compile_tx_script(tx_script_code(distributions), vec![], vec![])
+        .unwrap();
+    let transaction_result =
+        executor.execute_transaction(faucet_account.id(), block_ref, &[], Some(tx_script));
+
+    // error code of the max supply check in the basic non-fungible faucet contract
+    const ERR_NON_FUNGIBLE_FAUCET_MAX_SUPPLY_EXCEEDED: u32 = 262144;
+    assert_eq!(
+        get_failed_assertion_code(transaction_result),
+        Some(ERR_NON_FUNGIBLE_FAUCET_MAX_SUPPLY_EXCEEDED)
+    );
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn test_non_fungible_faucet_contract_burn() {
+    use miden_lib::faucets::create_basic_non_fungible_faucet;
+    use miden_objects::{
+        assets::NonFungibleAsset,
+        notes::{Note, NoteScript},
+    };
+    use miden_tx::BasicAuthenticator;
+    use mock::constants::ACCOUNT_ID_SENDER;
+    use std::rc::Rc;
+
+    let key_pair: KeyPair = KeyPair::new().unwrap();
+    let auth_scheme = AuthScheme::RpoFalcon512 {
+        pub_key: key_pair.public_key(),
+    };
+    let token_symbol = TokenSymbol::try_from("NFT").unwrap();
+
+    let (faucet_account, faucet_seed) =
+        create_basic_non_fungible_faucet([7; 32], token_symbol, Some(10), auth_scheme).unwrap();
+
+    let data_hash: Word = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+    let non_fungible_asset: Asset =
+        NonFungibleAsset::from_parts(faucet_account.id(), data_hash).unwrap().into();
+    let asset_word: Word = non_fungible_asset.into();
+
+    // MINT THE ASSET
+    // --------------------------------------------------------------------------------------------
+    let mut data_store =
+        MockDataStore::with_existing(Some(faucet_account.clone()), Some(vec![]), None);
+    data_store.account_seed = Some(faucet_seed);
+    let mut executor = TransactionExecutor::new(data_store.clone())
+        .with_authenticator(Rc::new(BasicAuthenticator::new([key_pair])));
+    executor.load_account(faucet_account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num().as_int() as u32;
+
+    let tx_script = ProgramAst::parse(&format!(
+        "
+        use.miden::faucets::basic_non_fungible->faucet
+        use.miden::auth::basic->auth_tx
+
+        begin
+            push.{recipient}
+            push.{note_type}
+            push.{tag}
+            push.{data_hash}
+            call.faucet::distribute
+            dropw dropw drop drop
+            call.auth_tx::auth_tx_rpo_falcon512
+        end
+        ",
+        recipient = prepare_word(&[ZERO, ONE, Felt::new(2), Felt::new(3)]),
+        note_type = NoteType::OffChain,
+        tag = NoteTag::from(4),
+        data_hash = prepare_word(&data_hash),
+    ))
+    .unwrap();
+    let tx_script = executor.compile_tx_script(tx_script, vec![], vec![]).unwrap();
+    let transaction_result = executor
+        .execute_transaction(faucet_account.id(), block_ref, &[], Some(tx_script))
+        .unwrap();
+
+    // the asset is recorded in the map of issued assets, and one asset is outstanding
+    let mut minted_storage = faucet_account.storage().clone();
+    minted_storage
+        .set_map_item(FAUCET_STORAGE_DATA_SLOT, asset_word, asset_word)
+        .unwrap();
+    minted_storage.set_item(2, [ONE, ZERO, ZERO, ZERO]);
+
+    let storage_delta = transaction_result.account_delta().storage();
+    assert!(storage_delta.updated_items.contains(&(2, [ONE, ZERO, ZERO, ZERO])));
+    assert!(storage_delta
+        .updated_items
+        .contains(&(FAUCET_STORAGE_DATA_SLOT, *minted_storage.get_item(FAUCET_STORAGE_DATA_SLOT))));
+
+    // BURN THE ASSET
+    // --------------------------------------------------------------------------------------------
+    let minted_faucet_account = Account::new(
+        faucet_account.id(),
+        faucet_account.vault().clone(),
+        minted_storage,
+        faucet_account.code().clone(),
+        ONE,
+    );
+
+    let note_script = ProgramAst::parse(
+        "
+        use.miden::faucets::basic_non_fungible->faucet
+        use.miden::sat::note
+
+        # burn the asset
+        begin
+            dropw
+            exec.note::get_assets drop
+            mem_loadw
+            call.faucet::burn
+        end
+        ",
+    )
+    .unwrap();
+    let (note_script, _) = NoteScript::new(note_script, &assembler()).unwrap();
+    let note = Note::new(
+        note_script,
+        &[],
+        &[non_fungible_asset],
+        [ONE, Felt::new(2), Felt::new(3), Felt::new(4)],
+        AccountId::try_from(ACCOUNT_ID_SENDER).unwrap(),
+        NoteType::Public,
+        NoteTag::from(1),
+    )
+    .unwrap();
+
+    let data_store =
+        MockDataStore::with_existing(Some(minted_faucet_account.clone()), Some(vec![note]), None);
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(minted_faucet_account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num().as_int() as u32;
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
+
+    let transaction_result = executor
+        .execute_transaction(minted_faucet_account.id(), block_ref, &note_origins, None)
+        .unwrap();
+
+    // the asset is removed from the map of issued assets, and no assets are outstanding
+    let storage_delta = transaction_result.account_delta().storage();
+    assert_eq!(transaction_result.account_delta().nonce(), Some(Felt::new(2)));
+    assert!(storage_delta.cleared_items.contains(&2));
+    assert!(storage_delta.updated_items.contains(&(
+        FAUCET_STORAGE_DATA_SLOT,
+        *faucet_account.storage().get_item(FAUCET_STORAGE_DATA_SLOT)
+    )));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn test_non_fungible_faucet_creation_fails_with_zero_max_supply() {
+    use miden_lib::faucets::create_basic_non_fungible_faucet;
+    use miden_objects::AccountError;
+
+    let pub_key: PublicKey = KeyPair::new().unwrap().public_key();
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key };
+    let token_symbol = TokenSymbol::try_from("NFT").unwrap();
+
+    let result = create_basic_non_fungible_faucet([0; 32], token_symbol, Some(0), auth_scheme);
+    assert!(matches!(result, Err(AccountError::NonFungibleFaucetInvalidMetadata(_))));
+}
@@ -1,22 +1,42 @@
-use miden_lib::notes::{create_note, Script};
+use miden_lib::{
+    assembler::assembler,
+    notes::{
+        create_note, fill_partial_swap_note, htlc_hashlock, partial_swap_fill_key,
+        swap_payback_serial_num, Script,
+    },
+};
 use miden_objects::{
     accounts::{Account, AccountId, AccountVault},
     assembly::ProgramAst,
     assets::{Asset, FungibleAsset},
+    notes::{Note, NoteExecution, NoteScript, NoteTag, NoteType},
     utils::collections::Vec,
-    Felt, StarkField,
+    Felt, NoteError, StarkField, ONE,
 };
-use miden_tx::TransactionExecutor;
+use miden_tx::{TransactionExecutor, TransactionExecutorError};
 use mock::constants::{
-    ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
-    ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
+    non_fungible_asset, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+    ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+    ACCOUNT_ID_SENDER,
 };
 
 mod common;
 use common::{
-    get_account_with_default_account_code, get_new_key_pair_with_advice_map, MockDataStore,
+    get_account_with_default_account_code, get_failed_assertion_code,
+    get_new_key_pair_with_advice_map, MockDataStore,
 };
 
+// CONSTANTS
+// ================================================================================================
+
+// Error codes of the PSWAP script.
+const ERR_PSWAP_ASSET_NOT_FUNGIBLE: u32 = 196609;
+const ERR_PSWAP_FILL_AMOUNT_NOT_MULTIPLE_OF_RATIO: u32 = 196614;
+
+// Error codes of the HTLC script.
+const ERR_HTLC_PREIMAGE_MISMATCH: u32 = 196864;
+const ERR_HTLC_TIMEOUT_NOT_REACHED: u32 = 196867;
+
 // P2ID TESTS
 // ===============================================================================================
 // We test the Pay to ID script. So we create a note that can only be consumed by the target
@@ -44,6 +64,7 @@ fn test_p2id_script() {
         p2id_script,
         vec![fungible_asset],
         sender_account_id,
+        NoteType::OffChain,
         None,
         [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)],
     )
@@ -58,8 +79,11 @@ fn test_p2id_script() {
     executor.load_account(target_account_id).unwrap();
 
     let block_ref = data_store.block_header.block_num().as_int() as u32;
-    let note_origins =
-        data_store.notes.iter().map(|note| note.origin().clone()).collect::<Vec<_>>();
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
 
     let tx_script_code = ProgramAst::parse(
         format!(
@@ -126,7 +150,7 @@ fn test_p2id_script() {
     let note_origins = data_store_malicious_account
         .notes
         .iter()
-        .map(|note| note.origin().clone())
+        .map(|note| (note.origin().clone(), None))
         .collect::<Vec<_>>();
 
     // Execute the transaction and get the witness
@@ -169,6 +193,7 @@ fn test_p2id_script_multiple_assets() {
         p2id_script,
         vec![fungible_asset_1, fungible_asset_2],
         sender_account_id,
+        NoteType::OffChain,
         None,
         [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)],
     )
@@ -183,8 +208,11 @@ fn test_p2id_script_multiple_assets() {
     executor.load_account(target_account_id).unwrap();
 
     let block_ref = data_store.block_header.block_num().as_int() as u32;
-    let note_origins =
-        data_store.notes.iter().map(|note| note.origin().clone()).collect::<Vec<_>>();
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
 
     let tx_script_code = ProgramAst::parse(
         format!(
@@ -251,7 +279,7 @@ fn test_p2id_script_multiple_assets() {
     let note_origins = data_store_malicious_account
         .notes
         .iter()
-        .map(|note| note.origin().clone())
+        .map(|note| (note.origin().clone(), None))
         .collect::<Vec<_>>();
 
     // Execute the transaction and get the witness
@@ -316,6 +344,7 @@ fn test_p2idr_script() {
         p2idr_script_in_time,
         vec![fungible_asset],
         sender_account_id,
+        NoteType::OffChain,
         None,
         [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)],
     )
@@ -329,6 +358,7 @@ fn test_p2idr_script() {
         p2idr_script_reclaimable,
         vec![fungible_asset],
         sender_account_id,
+        NoteType::OffChain,
         None,
         [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)],
     )
@@ -352,8 +382,11 @@ fn test_p2idr_script() {
     executor_1.load_account(target_account_id).unwrap();
 
     let block_ref_1 = data_store_1.block_header.block_num().as_int() as u32;
-    let note_origins =
-        data_store_1.notes.iter().map(|note| note.origin().clone()).collect::<Vec<_>>();
+    let note_origins = data_store_1
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
 
     let tx_script_code = ProgramAst::parse(
         format!(
@@ -414,8 +447,11 @@ fn test_p2idr_script() {
         .unwrap();
 
     let block_ref_2 = data_store_2.block_header.block_num().as_int() as u32;
-    let note_origins_2 =
-        data_store_2.notes.iter().map(|note| note.origin().clone()).collect::<Vec<_>>();
+    let note_origins_2 = data_store_2
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
 
     // Execute the transaction and get the witness
     let transaction_result_2 = executor_2.execute_transaction(
@@ -447,8 +483,11 @@ fn test_p2idr_script() {
         .unwrap();
 
     let block_ref_3 = data_store_3.block_header.block_num().as_int() as u32;
-    let note_origins_3 =
-        data_store_3.notes.iter().map(|note| note.origin().clone()).collect::<Vec<_>>();
+    let note_origins_3 = data_store_3
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
 
     // Execute the transaction and get the witness
     let transaction_result_3 = executor_3.execute_transaction(
@@ -473,8 +512,11 @@ fn test_p2idr_script() {
     executor_4.load_account(target_account_id).unwrap();
 
     let block_ref_4 = data_store_4.block_header.block_num().as_int() as u32;
-    let note_origins_4 =
-        data_store_4.notes.iter().map(|note| note.origin().clone()).collect::<Vec<_>>();
+    let note_origins_4 = data_store_4
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
 
     // Execute the transaction and get the witness
     let transaction_result_4 = executor_4
@@ -513,8 +555,11 @@ fn test_p2idr_script() {
     executor_5.load_account(sender_account_id).unwrap();
 
     let block_ref_5 = data_store_5.block_header.block_num().as_int() as u32;
-    let note_origins =
-        data_store_5.notes.iter().map(|note| note.origin().clone()).collect::<Vec<_>>();
+    let note_origins = data_store_5
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
 
     // Execute the transaction and get the witness
     let transaction_result_5 = executor_5
@@ -547,8 +592,11 @@ fn test_p2idr_script() {
     executor_6.load_account(malicious_account_id).unwrap();
 
     let block_ref_6 = data_store_6.block_header.block_num().as_int() as u32;
-    let note_origins_6 =
-        data_store_6.notes.iter().map(|note| note.origin().clone()).collect::<Vec<_>>();
+    let note_origins_6 = data_store_6
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
 
     // Execute the transaction and get the witness
     let transaction_result_6 = executor_6.execute_transaction(
@@ -562,3 +610,734 @@ fn test_p2idr_script() {
     // Sixth transaction should not work (malicious account can never consume), we expect an error
     assert!(transaction_result_6.is_err())
 }
+
+// SWAP TESTS
+// ===============================================================================================
+// We test the SWAP script, which offers the assets of the note to whoever consumes it, on
+// condition that the consuming account pays the requested asset back to the sender via a P2ID
+// note created in the same transaction.
+#[test]
+fn test_swap_script() {
+    // Create assets
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let offered_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+    let requested_asset: Asset = FungibleAsset::new(faucet_id_2, 50).unwrap().into();
+
+    // Create sender and target account; the target account holds the requested asset
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let (target_pub_key, target_keypair_felt) = get_new_key_pair_with_advice_map();
+    let target_account = get_account_with_default_account_code(
+        target_account_id,
+        target_pub_key,
+        Some(requested_asset),
+    );
+
+    // Create the note
+    let payback_tag = NoteTag::from_account_id(sender_account_id, NoteExecution::Local);
+    let serial_num = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let swap_script = Script::SWAP {
+        requested_asset,
+        payback_tag,
+    };
+    let note = create_note(
+        swap_script,
+        vec![offered_asset],
+        sender_account_id,
+        NoteType::OffChain,
+        None,
+        serial_num,
+    )
+    .unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store =
+        MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note.clone()]), None);
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num().as_int() as u32;
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
+
+    let tx_script_code = ProgramAst::parse(
+        "
+        use.miden::auth::basic->auth_tx
+
+        begin
+            call.auth_tx::auth_tx_rpo_falcon512
+        end
+        ",
+    )
+    .unwrap();
+    let tx_script_target = executor
+        .compile_tx_script(
+            tx_script_code.clone(),
+            vec![(target_pub_key, target_keypair_felt.clone())],
+            vec![],
+        )
+        .unwrap();
+
+    // Execute the transaction and get the witness
+    let transaction_result = executor
+        .execute_transaction(target_account_id, block_ref, &note_origins, Some(tx_script_target))
+        .unwrap();
+
+    // the target account received the offered asset and paid the requested asset
+    let target_account_after: Account = Account::new(
+        target_account.id(),
+        AccountVault::new(&vec![offered_asset]).unwrap(),
+        target_account.storage().clone(),
+        target_account.code().clone(),
+        Felt::new(2),
+    );
+    assert!(transaction_result.final_account_hash() == target_account_after.hash());
+
+    // the payback note is a P2ID note addressed to the sender of the SWAP note
+    let expected_payback_note = create_note(
+        Script::P2ID {
+            target: sender_account_id,
+        },
+        vec![requested_asset],
+        target_account_id,
+        NoteType::OffChain,
+        Some(payback_tag),
+        swap_payback_serial_num(serial_num),
+    )
+    .unwrap();
+
+    let created_notes = transaction_result.created_notes().notes();
+    assert_eq!(created_notes.len(), 1);
+    assert_eq!(*created_notes[0].recipient(), expected_payback_note.recipient());
+    assert_eq!(created_notes[0].vault(), expected_payback_note.vault());
+    assert_eq!(created_notes[0].metadata(), expected_payback_note.metadata());
+
+    // CONSTRUCT AND EXECUTE TX (Failure)
+    // --------------------------------------------------------------------------------------------
+    // An account which does not hold the requested asset tries to consume the note, we expect an
+    // error
+
+    let poor_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN + 1).unwrap();
+    let (poor_pub_key, poor_keypair_felt) = get_new_key_pair_with_advice_map();
+    let poor_account = get_account_with_default_account_code(poor_account_id, poor_pub_key, None);
+
+    let data_store_poor_account =
+        MockDataStore::with_existing(Some(poor_account), Some(vec![note]), None);
+    let mut executor_2 = TransactionExecutor::new(data_store_poor_account.clone());
+    executor_2.load_account(poor_account_id).unwrap();
+    let tx_script_poor = executor_2
+        .compile_tx_script(tx_script_code, vec![(poor_pub_key, poor_keypair_felt)], vec![])
+        .unwrap();
+
+    let block_ref = data_store_poor_account.block_header.block_num().as_int() as u32;
+    let note_origins = data_store_poor_account
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
+
+    let transaction_result_2 = executor_2.execute_transaction(
+        poor_account_id,
+        block_ref,
+        &note_origins,
+        Some(tx_script_poor),
+    );
+    assert!(transaction_result_2.is_err());
+}
+
+// PSWAP TESTS
+// ===============================================================================================
+// We test the partial SWAP script, which lets the consuming account take a part of the offered
+// asset at a fixed price ratio, and creates a new PSWAP note containing the remainder.
+#[test]
+fn test_partial_swap_script() {
+    // Create assets; 10 units of the offered asset are exchanged for 3 units of the requested one
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let offered_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+    let target_balance: Asset = FungibleAsset::new(faucet_id_2, 50).unwrap().into();
+
+    // Create sender and target account; the target account holds the requested asset
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let (target_pub_key, target_keypair_felt) = get_new_key_pair_with_advice_map();
+    let target_account = get_account_with_default_account_code(
+        target_account_id,
+        target_pub_key,
+        Some(target_balance),
+    );
+
+    // Create the note
+    let pswap_script = Script::PSWAP {
+        requested_faucet_id: faucet_id_2,
+        offered_ratio: 10,
+        requested_ratio: 3,
+    };
+    let note = create_note(
+        pswap_script,
+        vec![offered_asset],
+        sender_account_id,
+        NoteType::Public,
+        Some(NoteTag::from(7)),
+        [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)],
+    )
+    .unwrap();
+
+    let data_store =
+        MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note.clone()]), None);
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num().as_int() as u32;
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
+
+    let tx_script_code = ProgramAst::parse(
+        "
+        use.miden::auth::basic->auth_tx
+
+        begin
+            call.auth_tx::auth_tx_rpo_falcon512
+        end
+        ",
+    )
+    .unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    // the target account takes 40 units of the offered asset, paying 12 units of the requested one
+    let fill_amount = 40;
+    let tx_script = executor
+        .compile_tx_script(
+            tx_script_code.clone(),
+            vec![
+                (target_pub_key, target_keypair_felt.clone()),
+                (partial_swap_fill_key(&note), vec![Felt::new(fill_amount)]),
+            ],
+            vec![],
+        )
+        .unwrap();
+
+    let transaction_result = executor
+        .execute_transaction(target_account_id, block_ref, &note_origins, Some(tx_script))
+        .unwrap();
+
+    let target_account_after: Account = Account::new(
+        target_account.id(),
+        AccountVault::new(&vec![
+            FungibleAsset::new(faucet_id, 40).unwrap().into(),
+            FungibleAsset::new(faucet_id_2, 38).unwrap().into(),
+        ])
+        .unwrap(),
+        target_account.storage().clone(),
+        target_account.code().clone(),
+        Felt::new(2),
+    );
+    assert!(transaction_result.final_account_hash() == target_account_after.hash());
+
+    // the created notes are the payback note and the remainder note
+    let fill =
+        fill_partial_swap_note(&note, sender_account_id, fill_amount, target_account_id).unwrap();
+    let remainder_note = fill.remainder_note.unwrap();
+    assert_eq!(
+        remainder_note.vault().iter().copied().collect::<Vec<_>>(),
+        vec![FungibleAsset::new(faucet_id, 60).unwrap().into()]
+    );
+
+    let created_notes = transaction_result.created_notes().notes();
+    assert_eq!(created_notes.len(), 2);
+    for (created_note, expected_note) in
+        created_notes.iter().zip([fill.payback_note, remainder_note.clone()])
+    {
+        assert_eq!(*created_note.recipient(), expected_note.recipient());
+        assert_eq!(created_note.vault(), expected_note.vault());
+        assert_eq!(created_note.metadata(), expected_note.metadata());
+    }
+
+    // CONSTRUCT AND EXECUTE TX (Failure)
+    // --------------------------------------------------------------------------------------------
+    // the fill amount must be a multiple of the offered ratio
+    let tx_script = executor
+        .compile_tx_script(
+            tx_script_code.clone(),
+            vec![
+                (target_pub_key, target_keypair_felt.clone()),
+                (partial_swap_fill_key(&note), vec![Felt::new(45)]),
+            ],
+            vec![],
+        )
+        .unwrap();
+
+    let transaction_result =
+        executor.execute_transaction(target_account_id, block_ref, &note_origins, Some(tx_script));
+    assert_eq!(
+        get_failed_assertion_code(transaction_result),
+        Some(ERR_PSWAP_FILL_AMOUNT_NOT_MULTIPLE_OF_RATIO)
+    );
+    assert!(fill_partial_swap_note(&note, sender_account_id, 45, target_account_id).is_err());
+
+    // the payback must be addressed to the sender of the original note
+    assert!(
+        fill_partial_swap_note(&note, target_account_id, fill_amount, target_account_id).is_err()
+    );
+
+    // the offered asset must be fungible; such a note cannot be created via create_note(), and
+    // thus the script is expected to reject it on its own
+    let non_fungible_note = Note::new(
+        note.script().clone(),
+        note.inputs().inputs(),
+        &[non_fungible_asset(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN)],
+        note.serial_num(),
+        sender_account_id,
+        NoteType::Public,
+        NoteTag::from(7),
+    )
+    .unwrap();
+
+    let data_store = MockDataStore::with_existing(
+        Some(target_account.clone()),
+        Some(vec![non_fungible_note.clone()]),
+        None,
+    );
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num().as_int() as u32;
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
+
+    let tx_script = executor
+        .compile_tx_script(
+            tx_script_code.clone(),
+            vec![
+                (target_pub_key, target_keypair_felt.clone()),
+                (partial_swap_fill_key(&non_fungible_note), vec![Felt::new(fill_amount)]),
+            ],
+            vec![],
+        )
+        .unwrap();
+
+    let transaction_result =
+        executor.execute_transaction(target_account_id, block_ref, &note_origins, Some(tx_script));
+    assert_eq!(
+        get_failed_assertion_code(transaction_result),
+        Some(ERR_PSWAP_ASSET_NOT_FUNGIBLE)
+    );
+
+    // CONSTRUCT AND EXECUTE TX (Success - remainder note)
+    // --------------------------------------------------------------------------------------------
+    // the target account takes the remaining 60 units of the offered asset, paying 18 units of the
+    // requested one to the sender of the original note
+    let data_store = MockDataStore::with_existing(
+        Some(target_account_after.clone()),
+        Some(vec![remainder_note.clone()]),
+        None,
+    );
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num().as_int() as u32;
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
+
+    let remaining_amount = 60;
+    let tx_script = executor
+        .compile_tx_script(
+            tx_script_code,
+            vec![
+                (target_pub_key, target_keypair_felt),
+                (partial_swap_fill_key(&remainder_note), vec![Felt::new(remaining_amount)]),
+            ],
+            vec![],
+        )
+        .unwrap();
+
+    let transaction_result = executor
+        .execute_transaction(target_account_id, block_ref, &note_origins, Some(tx_script))
+        .unwrap();
+
+    let target_account_final: Account = Account::new(
+        target_account.id(),
+        AccountVault::new(&vec![
+            FungibleAsset::new(faucet_id, 100).unwrap().into(),
+            FungibleAsset::new(faucet_id_2, 20).unwrap().into(),
+        ])
+        .unwrap(),
+        target_account.storage().clone(),
+        target_account.code().clone(),
+        Felt::new(3),
+    );
+    assert!(transaction_result.final_account_hash() == target_account_final.hash());
+
+    // the remainder note is filled completely, and thus only the payback note is created
+    let fill = fill_partial_swap_note(
+        &remainder_note,
+        sender_account_id,
+        remaining_amount,
+        target_account_id,
+    )
+    .unwrap();
+    assert!(fill.remainder_note.is_none());
+
+    let payback_note = create_note(
+        Script::P2ID {
+            target: sender_account_id,
+        },
+        vec![FungibleAsset::new(faucet_id_2, 18).unwrap().into()],
+        target_account_id,
+        NoteType::OffChain,
+        Some(NoteTag::from(7)),
+        fill.payback_note.serial_num(),
+    )
+    .unwrap();
+    assert_eq!(fill.payback_note.recipient(), payback_note.recipient());
+
+    let created_notes = transaction_result.created_notes().notes();
+    assert_eq!(created_notes.len(), 1);
+    assert_eq!(*created_notes[0].recipient(), payback_note.recipient());
+    assert_eq!(created_notes[0].vault(), payback_note.vault());
+    assert_eq!(created_notes[0].metadata(), payback_note.metadata());
+}
+
+// HTLC TESTS
+// ===============================================================================================
+// We test the hashed timelock contract script. The target account can consume the note by revealing
+// the preimage of the hashlock, and the sender can reclaim the note after the timeout.
+#[test]
+fn test_htlc_script() {
+    // Create assets
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    // Create sender and target account
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let (sender_pub_key, sender_keypair_felt) = get_new_key_pair_with_advice_map();
+    let sender_account =
+        get_account_with_default_account_code(sender_account_id, sender_pub_key.clone(), None);
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let (target_pub_key, target_keypair_felt) = get_new_key_pair_with_advice_map();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key.clone(), None);
+
+    // --------------------------------------------------------------------------------------------
+    // Create notes (Note: Current block height is 4)
+    let preimage = [Felt::new(11), Felt::new(12), Felt::new(13), Felt::new(14)];
+    let hashlock = htlc_hashlock(preimage);
+
+    let note_in_time = create_note(
+        Script::HTLC {
+            target: target_account_id,
+            hashlock,
+            timeout: 5,
+        },
+        vec![fungible_asset],
+        sender_account_id,
+        NoteType::OffChain,
+        None,
+        [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)],
+    )
+    .unwrap();
+
+    let note_expired = create_note(
+        Script::HTLC {
+            target: target_account_id,
+            hashlock,
+            timeout: 3,
+        },
+        vec![fungible_asset],
+        sender_account_id,
+        NoteType::OffChain,
+        None,
+        [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)],
+    )
+    .unwrap();
+
+    let tx_script_code = ProgramAst::parse(
+        "
+        use.miden::auth::basic->auth_tx
+
+        begin
+            call.auth_tx::auth_tx_rpo_falcon512
+        end
+        ",
+    )
+    .unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Target Account Execution Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(
+        Some(target_account.clone()),
+        Some(vec![note_in_time.clone()]),
+        None,
+    );
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num().as_int() as u32;
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
+
+    let tx_script = executor
+        .compile_tx_script(
+            tx_script_code.clone(),
+            vec![
+                (target_pub_key, target_keypair_felt.clone()),
+                (hashlock.into(), preimage.to_vec()),
+            ],
+            vec![],
+        )
+        .unwrap();
+
+    let transaction_result = executor
+        .execute_transaction(target_account_id, block_ref, &note_origins, Some(tx_script))
+        .unwrap();
+
+    let target_account_after: Account = Account::new(
+        target_account_id,
+        AccountVault::new(&vec![fungible_asset]).unwrap(),
+        target_account.storage().clone(),
+        target_account.code().clone(),
+        Felt::new(2),
+    );
+    assert!(transaction_result.final_account_hash() == target_account_after.hash());
+
+    // CONSTRUCT AND EXECUTE TX (Target Account Wrong Preimage Failure)
+    // --------------------------------------------------------------------------------------------
+    let wrong_preimage = vec![Felt::new(11), Felt::new(12), Felt::new(13), Felt::new(15)];
+    let tx_script = executor
+        .compile_tx_script(
+            tx_script_code.clone(),
+            vec![(target_pub_key, target_keypair_felt), (hashlock.into(), wrong_preimage)],
+            vec![],
+        )
+        .unwrap();
+
+    let transaction_result =
+        executor.execute_transaction(target_account_id, block_ref, &note_origins, Some(tx_script));
+    assert_eq!(get_failed_assertion_code(transaction_result), Some(ERR_HTLC_PREIMAGE_MISMATCH));
+
+    // CONSTRUCT AND EXECUTE TX (Sender Account Before Timeout Failure)
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(
+        Some(sender_account.clone()),
+        Some(vec![note_in_time.clone()]),
+        None,
+    );
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(sender_account_id).unwrap();
+
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
+
+    let tx_script_sender = executor
+        .compile_tx_script(
+            tx_script_code.clone(),
+            vec![(sender_pub_key, sender_keypair_felt.clone())],
+            vec![],
+        )
+        .unwrap();
+
+    let transaction_result = executor.execute_transaction(
+        sender_account_id,
+        block_ref,
+        &note_origins,
+        Some(tx_script_sender),
+    );
+    assert_eq!(
+        get_failed_assertion_code(transaction_result),
+        Some(ERR_HTLC_TIMEOUT_NOT_REACHED)
+    );
+
+    // CONSTRUCT AND EXECUTE TX (Sender Account After Timeout Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(
+        Some(sender_account.clone()),
+        Some(vec![note_expired.clone()]),
+        None,
+    );
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(sender_account_id).unwrap();
+
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
+
+    let tx_script_sender = executor
+        .compile_tx_script(tx_script_code, vec![(sender_pub_key, sender_keypair_felt)], vec![])
+        .unwrap();
+
+    let transaction_result = executor
+        .execute_transaction(sender_account_id, block_ref, &note_origins, Some(tx_script_sender))
+        .unwrap();
+
+    let sender_account_after: Account = Account::new(
+        sender_account_id,
+        AccountVault::new(&vec![fungible_asset]).unwrap(),
+        sender_account.storage().clone(),
+        sender_account.code().clone(),
+        Felt::new(2),
+    );
+    assert!(transaction_result.final_account_hash() == sender_account_after.hash());
+}
+
+// NOTE ARGS TESTS
+// ===============================================================================================
+// We test that the note args provided when consuming a note can be read by the note script.
+#[test]
+fn test_note_args() {
+    // Create assets
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    // Create sender and target account
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let (target_pub_key, target_keypair_felt) = get_new_key_pair_with_advice_map();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key.clone(), None);
+
+    // Create a note which can only be consumed with the note args [1, 2, 3, 4]
+    const ERR_NOTE_ARGS_MISMATCH: u32 = 1;
+    let note_script_ast = ProgramAst::parse(&format!(
+        "
+        use.miden::sat::note
+        use.miden::wallets::basic->wallet
+
+        begin
+            dropw
+
+            # make sure the note args match the expected ones
+            exec.note::get_args push.1.2.3.4 assert_eqw.err={ERR_NOTE_ARGS_MISMATCH}
+
+            # add the note asset to the account
+            push.0 exec.note::get_assets drop padw movup.4 mem_loadw
+            call.wallet::receive_asset dropw
+        end
+        "
+    ))
+    .unwrap();
+    let (note_script, _) = NoteScript::new(note_script_ast, &assembler()).unwrap();
+    let note = Note::new(
+        note_script,
+        &[],
+        &[fungible_asset],
+        [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)],
+        sender_account_id,
+        NoteType::OffChain,
+        NoteTag::default(),
+    )
+    .unwrap();
+
+    let data_store =
+        MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note]), None);
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num().as_int() as u32;
+
+    let tx_script_code = ProgramAst::parse(
+        "
+        use.miden::auth::basic->auth_tx
+
+        begin
+            call.auth_tx::auth_tx_rpo_falcon512
+        end
+        ",
+    )
+    .unwrap();
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(target_pub_key, target_keypair_felt)], vec![])
+        .unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let note_args = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let notes = data_store
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), Some(note_args)))
+        .collect::<Vec<_>>();
+
+    let transaction_result = executor
+        .execute_transaction(target_account_id, block_ref, &notes, Some(tx_script.clone()))
+        .unwrap();
+
+    let target_account_after: Account = Account::new(
+        target_account_id,
+        AccountVault::new(&vec![fungible_asset]).unwrap(),
+        target_account.storage().clone(),
+        target_account.code().clone(),
+        Felt::new(2),
+    );
+    assert!(transaction_result.final_account_hash() == target_account_after.hash());
+
+    // CONSTRUCT AND EXECUTE TX (Failure)
+    // --------------------------------------------------------------------------------------------
+    // without note args, the note args are set to an empty word
+    let notes = data_store
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
+
+    let transaction_result =
+        executor.execute_transaction(target_account_id, block_ref, &notes, Some(tx_script.clone()));
+    assert_eq!(get_failed_assertion_code(transaction_result), Some(ERR_NOTE_ARGS_MISMATCH));
+
+    // CONSTRUCT AND EXECUTE TX (Failure)
+    // --------------------------------------------------------------------------------------------
+    // the note args must be provided for a note which is consumed by the transaction
+    let notes = data_store
+        .notes
+        .iter()
+        .map(|note| {
+            let mut origin = note.origin().clone();
+            origin.block_num += ONE;
+            (origin, Some(note_args))
+        })
+        .collect::<Vec<_>>();
+
+    let transaction_result =
+        executor.execute_transaction(target_account_id, block_ref, &notes, Some(tx_script));
+    assert!(matches!(
+        transaction_result,
+        Err(TransactionExecutorError::InvalidNoteArgs(NoteError::NoteArgsOriginNotFound(_)))
+    ));
+}
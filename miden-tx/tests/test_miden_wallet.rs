@@ -4,6 +4,8 @@ use miden_objects::{
     assembly::ProgramAst,
     assets::{Asset, FungibleAsset},
     crypto::dsa::rpo_falcon512::{KeyPair, PublicKey},
+    notes::NoteType,
+    utils::collections::BTreeMap,
     Felt, StarkField, Word, ONE, ZERO,
 };
 use miden_tx::TransactionExecutor;
@@ -65,8 +67,11 @@ fn test_receive_asset_via_wallet() {
     executor.load_account(target_account.id()).unwrap();
 
     let block_ref = data_store.block_header.block_num().as_int() as u32;
-    let note_origins =
-        data_store.notes.iter().map(|note| note.origin().clone()).collect::<Vec<_>>();
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
 
     let tx_script_code = ProgramAst::parse(
         "
@@ -93,9 +98,12 @@ fn test_receive_asset_via_wallet() {
     assert_eq!(transaction_result.account_delta().nonce(), Some(Felt::new(2)));
 
     // clone account info
-    let account_storage =
-        AccountStorage::new(vec![(0, (StorageSlotType::Value { value_arity: 0 }, target_pub_key))])
-            .unwrap();
+    let account_storage = AccountStorage::new(
+        vec![(0, (StorageSlotType::Value { value_arity: 0 }, target_pub_key))],
+        BTreeMap::new(),
+        BTreeMap::new(),
+    )
+    .unwrap();
     let account_code = target_account.code().clone();
     // vault delta
     let target_account_after: Account = Account::new(
@@ -133,8 +141,11 @@ fn test_send_asset_via_wallet() {
     executor.load_account(sender_account.id()).unwrap();
 
     let block_ref = data_store.block_header.block_num().as_int() as u32;
-    let note_origins =
-        data_store.notes.iter().map(|note| note.origin().clone()).collect::<Vec<_>>();
+    let note_origins = data_store
+        .notes
+        .iter()
+        .map(|note| (note.origin().clone(), None))
+        .collect::<Vec<_>>();
 
     let recipient = [ZERO, ONE, Felt::new(2), Felt::new(3)];
     let tag = Felt::new(4);
@@ -147,14 +158,16 @@ fn test_send_asset_via_wallet() {
 
         begin
             push.{recipient}
+            push.{note_type}
             push.{tag}
             push.{asset}
             call.wallet::send_asset drop
-            dropw dropw
+            dropw dropw drop
             call.auth_tx::auth_tx_rpo_falcon512
         end
         ",
             recipient = prepare_word(&recipient),
+            note_type = NoteType::OffChain,
             tag = tag,
             asset = prepare_word(&fungible_asset_1.try_into().unwrap())
         )
@@ -171,9 +184,12 @@ fn test_send_asset_via_wallet() {
         .unwrap();
 
     // clones account info
-    let sender_account_storage =
-        AccountStorage::new(vec![(0, (StorageSlotType::Value { value_arity: 0 }, sender_pub_key))])
-            .unwrap();
+    let sender_account_storage = AccountStorage::new(
+        vec![(0, (StorageSlotType::Value { value_arity: 0 }, sender_pub_key))],
+        BTreeMap::new(),
+        BTreeMap::new(),
+    )
+    .unwrap();
     let sender_account_code = sender_account.code().clone();
 
     // vault delta
@@ -219,3 +235,299 @@ fn test_wallet_creation() {
     let pub_key_word: Word = pub_key.into();
     assert!(wallet.storage().get_item(0).as_elements() == pub_key_word);
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn test_wallet_deployment() {
+    use miden_objects::{
+        accounts::{AccountCodeDelta, AccountType},
+        crypto::utils::Serializable,
+    };
+
+    let key_pair: KeyPair = KeyPair::new().unwrap();
+    let pub_key: PublicKey = key_pair.public_key();
+    let key_pair_felts: Vec<Felt> =
+        key_pair.to_bytes().iter().map(|a| Felt::new(*a as u64)).collect::<Vec<Felt>>();
+    let auth_scheme: AuthScheme = AuthScheme::RpoFalcon512 { pub_key };
+
+    let init_seed: [u8; 32] = [
+        95, 113, 209, 94, 84, 105, 250, 242, 223, 203, 216, 124, 22, 159, 14, 132, 215, 85, 183,
+        204, 149, 90, 166, 68, 100, 73, 106, 168, 125, 237, 138, 16,
+    ];
+
+    let (wallet, wallet_seed) =
+        create_basic_wallet(init_seed, auth_scheme, AccountType::RegularAccountUpdatableCode)
+            .unwrap();
+    assert!(wallet.is_new());
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let mut data_store = MockDataStore::with_existing(Some(wallet.clone()), Some(vec![]), None);
+    data_store.account_seed = Some(wallet_seed);
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(wallet.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num().as_int() as u32;
+
+    let tx_script_code = ProgramAst::parse(
+        "
+        use.miden::auth::basic->auth_tx
+
+        begin
+            call.auth_tx::auth_tx_rpo_falcon512
+        end
+        ",
+    )
+    .unwrap();
+    let pub_key_word: Word = pub_key.into();
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(pub_key_word, key_pair_felts)], vec![])
+        .unwrap();
+
+    let transaction_result = executor
+        .execute_transaction(wallet.id(), block_ref, &[], Some(tx_script))
+        .unwrap();
+
+    // the delta must contain the full initial state of the wallet
+    let account_delta = transaction_result.account_delta();
+    assert_eq!(account_delta.nonce(), Some(ONE));
+    let slot_types_commitment =
+        *wallet.storage().get_item(AccountStorage::SLOT_TYPES_COMMITMENT_INDEX);
+    assert_eq!(
+        account_delta.storage().updated_items,
+        vec![
+            (0, pub_key_word),
+            (AccountStorage::SLOT_TYPES_COMMITMENT_INDEX, slot_types_commitment)
+        ]
+    );
+    assert!(account_delta.storage().cleared_items.is_empty());
+    assert!(account_delta.vault().is_empty());
+    assert_eq!(account_delta.code(), Some(&AccountCodeDelta::from(wallet.code().clone())));
+
+    // APPLY THE DELTA TO AN EMPTY ACCOUNT
+    // --------------------------------------------------------------------------------------------
+    let mut deployed_wallet = Account::new(
+        wallet.id(),
+        AccountVault::default(),
+        AccountStorage::new(vec![], BTreeMap::new(), BTreeMap::new()).unwrap(),
+        wallet.code().clone(),
+        ZERO,
+    );
+    deployed_wallet
+        .apply_delta(account_delta, transaction_result.final_account_hash())
+        .unwrap();
+
+    assert_eq!(deployed_wallet.nonce(), ONE);
+    assert_eq!(deployed_wallet.storage().get_item(0).as_elements(), pub_key_word);
+    assert_eq!(deployed_wallet.storage().root(), wallet.storage().root());
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn test_wallet_authenticator() {
+    use miden_objects::accounts::AccountType;
+    use miden_tx::BasicAuthenticator;
+    use std::rc::Rc;
+
+    let key_pair: KeyPair = KeyPair::new().unwrap();
+    let auth_scheme = AuthScheme::RpoFalcon512 {
+        pub_key: key_pair.public_key(),
+    };
+
+    let (wallet, wallet_seed) =
+        create_basic_wallet([7; 32], auth_scheme, AccountType::RegularAccountUpdatableCode)
+            .unwrap();
+
+    let mut data_store = MockDataStore::with_existing(Some(wallet.clone()), Some(vec![]), None);
+    data_store.account_seed = Some(wallet_seed);
+    let block_ref = data_store.block_header.block_num().as_int() as u32;
+
+    let tx_script_code = ProgramAst::parse(
+        "
+        use.miden::auth::basic->auth_tx
+
+        begin
+            call.auth_tx::auth_tx_rpo_falcon512
+        end
+        ",
+    )
+    .unwrap();
+
+    // the signature is generated by the authenticator during execution, and thus the secret key
+    // does not need to be provided via the advice map
+    let authenticator = BasicAuthenticator::new([key_pair]);
+    let mut executor =
+        TransactionExecutor::new(data_store.clone()).with_authenticator(Rc::new(authenticator));
+    executor.load_account(wallet.id()).unwrap();
+
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let transaction_result = executor
+        .execute_transaction(wallet.id(), block_ref, &[], Some(tx_script))
+        .unwrap();
+    assert_eq!(transaction_result.account_delta().nonce(), Some(ONE));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn test_wallet_authenticator_unknown_key() {
+    use miden_objects::accounts::AccountType;
+    use miden_tx::{BasicAuthenticator, TransactionExecutorError};
+    use std::rc::Rc;
+    use vm_processor::ExecutionError;
+
+    let key_pair: KeyPair = KeyPair::new().unwrap();
+    let auth_scheme = AuthScheme::RpoFalcon512 {
+        pub_key: key_pair.public_key(),
+    };
+
+    let (wallet, wallet_seed) =
+        create_basic_wallet([7; 32], auth_scheme, AccountType::RegularAccountUpdatableCode)
+            .unwrap();
+
+    let mut data_store = MockDataStore::with_existing(Some(wallet.clone()), Some(vec![]), None);
+    data_store.account_seed = Some(wallet_seed);
+    let block_ref = data_store.block_header.block_num().as_int() as u32;
+
+    let tx_script_code = ProgramAst::parse(
+        "
+        use.miden::auth::basic->auth_tx
+
+        begin
+            call.auth_tx::auth_tx_rpo_falcon512
+        end
+        ",
+    )
+    .unwrap();
+
+    // the authenticator does not manage the key of the wallet
+    let authenticator = BasicAuthenticator::new([KeyPair::new().unwrap()]);
+    let mut executor =
+        TransactionExecutor::new(data_store.clone()).with_authenticator(Rc::new(authenticator));
+    executor.load_account(wallet.id()).unwrap();
+
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let result = executor.execute_transaction(wallet.id(), block_ref, &[], Some(tx_script));
+
+    // the transaction fails with the error raised by the authenticator rather than with a failed
+    // signature verification
+    match result {
+        Err(TransactionExecutorError::ExecuteTransactionProgramFailed(
+            ExecutionError::EventError(msg),
+            None,
+        )) => assert!(msg.contains("UnknownKey")),
+        _ => panic!("expected the transaction to fail with an unknown key error"),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn test_multisig_wallet() {
+    use miden_objects::accounts::AccountType;
+    use miden_tx::BasicAuthenticator;
+    use std::rc::Rc;
+
+    let key_pair_1: KeyPair = KeyPair::new().unwrap();
+    let key_pair_2: KeyPair = KeyPair::new().unwrap();
+    let key_pair_3: KeyPair = KeyPair::new().unwrap();
+    let pub_keys = vec![key_pair_1.public_key(), key_pair_2.public_key(), key_pair_3.public_key()];
+    let auth_scheme = AuthScheme::RpoFalcon512Multisig {
+        threshold: 2,
+        pub_keys: pub_keys.clone(),
+    };
+
+    let init_seed: [u8; 32] = [
+        95, 113, 209, 94, 84, 105, 250, 242, 223, 203, 216, 124, 22, 159, 14, 132, 215, 85, 183,
+        204, 149, 90, 166, 68, 100, 73, 106, 168, 125, 237, 138, 16,
+    ];
+
+    let (wallet, wallet_seed) =
+        create_basic_wallet(init_seed, auth_scheme, AccountType::RegularAccountUpdatableCode)
+            .unwrap();
+
+    // slot 0 holds the threshold followed by the public keys
+    let mut expected_words = vec![[Felt::new(2), ZERO, ZERO, ZERO]];
+    expected_words.extend(pub_keys.iter().map(|&pub_key| Word::from(pub_key)));
+    assert_eq!(
+        wallet.storage().get_item(0),
+        AccountStorage::compute_words_commitment(&expected_words)
+    );
+
+    // only the first and the third key sign the transaction
+    let authenticator = BasicAuthenticator::new([key_pair_1, key_pair_3]);
+    let mut data_store = MockDataStore::with_existing(Some(wallet.clone()), Some(vec![]), None);
+    data_store.account_seed = Some(wallet_seed);
+    let mut executor =
+        TransactionExecutor::new(data_store.clone()).with_authenticator(Rc::new(authenticator));
+    executor.load_account(wallet.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num().as_int() as u32;
+
+    // the signer indexes are provided to the authentication procedure via the advice stack
+    let signers_key: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let tx_script_code = ProgramAst::parse(
+        format!(
+            "
+        use.miden::auth::basic->auth_tx
+
+        begin
+            push.{signers_key} adv.push_mapval dropw
+            call.auth_tx::auth_tx_rpo_falcon512_multisig
+        end
+        ",
+            signers_key = prepare_word(&signers_key)
+        )
+        .as_str(),
+    )
+    .unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let signers = vec![Felt::new(2), Felt::new(1), Felt::new(3)];
+    let tx_script = executor
+        .compile_tx_script(tx_script_code.clone(), vec![(signers_key, signers)], vec![])
+        .unwrap();
+
+    let transaction_result = executor
+        .execute_transaction(wallet.id(), block_ref, &[], Some(tx_script))
+        .unwrap();
+    assert_eq!(transaction_result.account_delta().nonce(), Some(ONE));
+
+    // CONSTRUCT AND EXECUTE TX (Failure - threshold not reached)
+    // --------------------------------------------------------------------------------------------
+    let signers = vec![Felt::new(1), Felt::new(1)];
+    let tx_script = executor
+        .compile_tx_script(tx_script_code.clone(), vec![(signers_key, signers)], vec![])
+        .unwrap();
+    assert!(executor
+        .execute_transaction(wallet.id(), block_ref, &[], Some(tx_script))
+        .is_err());
+
+    // CONSTRUCT AND EXECUTE TX (Failure - the same key is counted twice)
+    // --------------------------------------------------------------------------------------------
+    let signers = vec![Felt::new(2), Felt::new(1), Felt::new(1)];
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(signers_key, signers)], vec![])
+        .unwrap();
+    assert!(executor
+        .execute_transaction(wallet.id(), block_ref, &[], Some(tx_script))
+        .is_err());
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn test_multisig_wallet_invalid_threshold() {
+    use miden_objects::{accounts::AccountType, AccountError};
+
+    let pub_keys: Vec<PublicKey> = (0..2).map(|_| KeyPair::new().unwrap().public_key()).collect();
+
+    for threshold in [0, 3] {
+        let auth_scheme = AuthScheme::RpoFalcon512Multisig {
+            threshold,
+            pub_keys: pub_keys.clone(),
+        };
+        let result =
+            create_basic_wallet([0; 32], auth_scheme, AccountType::RegularAccountUpdatableCode);
+        assert!(matches!(result, Err(AccountError::InvalidAuthScheme(_))));
+    }
+}
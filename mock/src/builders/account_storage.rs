@@ -1,6 +1,6 @@
 use miden_objects::{
     accounts::{AccountStorage, SlotItem},
-    utils::collections::Vec,
+    utils::collections::{BTreeMap, Vec},
 };
 
 #[derive(Default, Debug, Clone)]
@@ -28,6 +28,6 @@ impl AccountStorageBuilder {
     }
 
     pub fn build(&self) -> AccountStorage {
-        AccountStorage::new(self.items.clone()).unwrap()
+        AccountStorage::new(self.items.clone(), BTreeMap::new(), BTreeMap::new()).unwrap()
     }
 }
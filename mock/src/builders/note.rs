@@ -3,7 +3,7 @@ use miden_objects::{
     accounts::AccountId,
     assembly::ProgramAst,
     assets::Asset,
-    notes::{Note, NoteInclusionProof, NoteInputs, NoteScript},
+    notes::{Note, NoteInclusionProof, NoteInputs, NoteScript, NoteTag, NoteType},
     utils::{
         collections::Vec,
         string::{String, ToString},
@@ -24,7 +24,8 @@ pub struct NoteBuilder {
     inputs: Vec<Felt>,
     assets: Vec<Asset>,
     serial_num: Word,
-    tag: Felt,
+    note_type: NoteType,
+    tag: NoteTag,
     code: String,
     proof: Option<NoteInclusionProof>,
 }
@@ -43,7 +44,8 @@ impl NoteBuilder {
             inputs: vec![],
             assets: vec![],
             serial_num,
-            tag: Felt::default(),
+            note_type: NoteType::Public,
+            tag: NoteTag::default(),
             code: DEFAULT_NOTE_CODE.to_string(),
             proof: None,
         }
@@ -60,7 +62,12 @@ impl NoteBuilder {
         self
     }
 
-    pub fn tag(mut self, tag: Felt) -> Self {
+    pub fn note_type(mut self, note_type: NoteType) -> Self {
+        self.note_type = note_type;
+        self
+    }
+
+    pub fn tag(mut self, tag: NoteTag) -> Self {
         self.tag = tag;
         self
     }
@@ -79,6 +86,14 @@ impl NoteBuilder {
         let assembler = assembler();
         let note_ast = ProgramAst::parse(&self.code).unwrap();
         let (note_script, _) = NoteScript::new(note_ast, &assembler)?;
-        Note::new(note_script, &self.inputs, &self.assets, self.serial_num, self.sender, self.tag)
+        Note::new(
+            note_script,
+            &self.inputs,
+            &self.assets,
+            self.serial_num,
+            self.sender,
+            self.note_type,
+            self.tag,
+        )
     }
 }
@@ -1,11 +1,13 @@
 use super::mock::account::{mock_account, mock_fungible_faucet, mock_non_fungible_faucet};
 pub use super::mock::account::{
     ACCOUNT_PROCEDURE_INCR_NONCE_PROC_IDX, ACCOUNT_PROCEDURE_SET_CODE_PROC_IDX,
-    ACCOUNT_PROCEDURE_SET_ITEM_PROC_IDX,
+    ACCOUNT_PROCEDURE_SET_ITEM_PROC_IDX, ACCOUNT_PROCEDURE_SET_ITEM_WORDS_PROC_IDX,
 };
 use miden_lib::assembler::assembler;
 use miden_objects::{
-    accounts::{AccountId, AccountType, SlotItem, StorageSlotType},
+    accounts::{
+        AccountId, AccountStorage, AccountType, SlotItem, StorageArray, StorageMap, StorageSlotType,
+    },
     assets::{Asset, NonFungibleAsset, NonFungibleAssetDetails},
     Felt, FieldElement, Word, ZERO,
 };
@@ -60,6 +62,82 @@ pub fn storage_item_1() -> SlotItem {
     (STORAGE_INDEX_1, (StorageSlotType::Value { value_arity: 0 }, STORAGE_VALUE_1))
 }
 
+pub const STORAGE_INDEX_2: u8 = 40;
+pub const STORAGE_MAP_KEY_0: Word =
+    [Felt::new(101), Felt::new(102), Felt::new(103), Felt::new(104)];
+pub const STORAGE_MAP_VALUE_0: Word =
+    [Felt::new(1001), Felt::new(1002), Felt::new(1003), Felt::new(1004)];
+pub const STORAGE_MAP_KEY_1: Word =
+    [Felt::new(105), Felt::new(106), Felt::new(107), Felt::new(108)];
+pub const STORAGE_MAP_VALUE_1: Word =
+    [Felt::new(1005), Felt::new(1006), Felt::new(1007), Felt::new(1008)];
+
+pub fn storage_map_2() -> StorageMap {
+    StorageMap::with_entries([
+        (STORAGE_MAP_KEY_0.into(), STORAGE_MAP_VALUE_0),
+        (STORAGE_MAP_KEY_1.into(), STORAGE_MAP_VALUE_1),
+    ])
+    .unwrap()
+}
+
+pub fn storage_item_2() -> SlotItem {
+    (
+        STORAGE_INDEX_2,
+        (StorageSlotType::Map { value_arity: 0 }, *storage_map_2().root()),
+    )
+}
+
+pub const STORAGE_INDEX_3: u8 = 50;
+pub const STORAGE_ARRAY_DEPTH: u8 = 8;
+pub const STORAGE_ARRAY_INDEX_0: u64 = 3;
+pub const STORAGE_ARRAY_VALUE_0: Word =
+    [Felt::new(2001), Felt::new(2002), Felt::new(2003), Felt::new(2004)];
+pub const STORAGE_ARRAY_INDEX_1: u64 = 200;
+pub const STORAGE_ARRAY_VALUE_1: Word =
+    [Felt::new(2005), Felt::new(2006), Felt::new(2007), Felt::new(2008)];
+
+pub fn storage_array_3() -> StorageArray {
+    StorageArray::with_items(
+        STORAGE_ARRAY_DEPTH,
+        [
+            (STORAGE_ARRAY_INDEX_0, STORAGE_ARRAY_VALUE_0),
+            (STORAGE_ARRAY_INDEX_1, STORAGE_ARRAY_VALUE_1),
+        ],
+    )
+    .unwrap()
+}
+
+pub fn storage_item_3() -> SlotItem {
+    (
+        STORAGE_INDEX_3,
+        (
+            StorageSlotType::Array {
+                depth: STORAGE_ARRAY_DEPTH,
+                value_arity: 0,
+            },
+            *storage_array_3().root(),
+        ),
+    )
+}
+
+pub const STORAGE_INDEX_4: u8 = 60;
+
+pub const STORAGE_WORDS_4: [Word; 3] = [
+    [Felt::new(3001), Felt::new(3002), Felt::new(3003), Felt::new(3004)],
+    [Felt::new(3005), Felt::new(3006), Felt::new(3007), Felt::new(3008)],
+    [Felt::new(3009), Felt::new(3010), Felt::new(3011), Felt::new(3012)],
+];
+
+pub fn storage_item_4() -> SlotItem {
+    (
+        STORAGE_INDEX_4,
+        (
+            StorageSlotType::Value { value_arity: 3 },
+            *AccountStorage::compute_words_commitment(&STORAGE_WORDS_4),
+        ),
+    )
+}
+
 pub const CHILD_ROOT_PARENT_LEAF_INDEX: u8 = 10;
 pub const CHILD_SMT_DEPTH: u8 = 64;
 pub const CHILD_STORAGE_INDEX_0: u64 = 40;
@@ -128,7 +206,6 @@ pub fn generate_account_seed(account_seed_type: AccountSeedType) -> (AccountId,
                 ZERO,
                 false,
                 &assembler,
-                &mut AdviceInputs::default(),
             ),
             AccountType::NonFungibleFaucet,
         ),
@@ -138,7 +215,6 @@ pub fn generate_account_seed(account_seed_type: AccountSeedType) -> (AccountId,
                 ZERO,
                 true,
                 &assembler,
-                &mut AdviceInputs::default(),
             ),
             AccountType::NonFungibleFaucet,
         ),
@@ -2,7 +2,7 @@ use miden_lib::{assembler::assembler, memory};
 use miden_objects::{
     accounts::Account,
     notes::{Note, NoteVault, RecordedNote},
-    transaction::{PreparedTransaction, TransactionScript},
+    transaction::{ConsumedNotes, PreparedTransaction, TransactionScript},
     BlockHeader, ChainMmr, Felt, StarkField,
 };
 use std::{fs::File, io::Read, path::PathBuf};
@@ -112,7 +112,7 @@ pub fn prepare_transaction(
         account_seed,
         block_header,
         chain,
-        notes,
+        ConsumedNotes::new(notes),
         tx_script,
         program,
         auxiliary_data,
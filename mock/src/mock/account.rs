@@ -1,18 +1,23 @@
 use crate::constants::{
-    generate_account_seed, non_fungible_asset, non_fungible_asset_2, storage_item_0,
-    storage_item_1, AccountSeedType, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
-    ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
-    ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
-    CHILD_ROOT_PARENT_LEAF_INDEX, CHILD_SMT_DEPTH, CHILD_STORAGE_INDEX_0, CHILD_STORAGE_VALUE_0,
-    FUNGIBLE_ASSET_AMOUNT, FUNGIBLE_FAUCET_INITIAL_BALANCE,
+    generate_account_seed, non_fungible_asset, non_fungible_asset_2, storage_array_3,
+    storage_item_0, storage_item_1, storage_item_2, storage_item_3, storage_item_4, storage_map_2,
+    AccountSeedType, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+    ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+    ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, CHILD_ROOT_PARENT_LEAF_INDEX,
+    CHILD_SMT_DEPTH, CHILD_STORAGE_INDEX_0, CHILD_STORAGE_VALUE_0, FUNGIBLE_ASSET_AMOUNT,
+    FUNGIBLE_FAUCET_INITIAL_BALANCE, STORAGE_INDEX_2, STORAGE_INDEX_3, STORAGE_INDEX_4,
+    STORAGE_WORDS_4,
 };
 use miden_lib::memory::FAUCET_STORAGE_DATA_SLOT;
 use miden_objects::{
-    accounts::{Account, AccountCode, AccountId, AccountStorage, AccountVault, StorageSlotType},
+    accounts::{
+        Account, AccountCode, AccountId, AccountStorage, AccountVault, StorageMap, StorageSlotType,
+    },
     assembly::{Assembler, ModuleAst},
     assets::{Asset, FungibleAsset},
-    crypto::merkle::{SimpleSmt, TieredSmt},
-    Felt, FieldElement, Word, ZERO,
+    crypto::merkle::SimpleSmt,
+    utils::collections::BTreeMap,
+    Digest, Felt, FieldElement, Word, ZERO,
 };
 use vm_processor::AdviceInputs;
 
@@ -46,24 +51,48 @@ pub fn mock_account_storage(auxiliary_data: &mut AdviceInputs) -> AccountStorage
     auxiliary_data.extend_merkle_store(child_smt.inner_nodes());
 
     // create account storage
-    AccountStorage::new(vec![
-        storage_item_0(),
-        storage_item_1(),
-        (
-            CHILD_ROOT_PARENT_LEAF_INDEX,
-            (StorageSlotType::Value { value_arity: 0 }, *child_smt.root()),
-        ),
-    ])
-    .unwrap()
+    let mut storage = AccountStorage::new(
+        vec![
+            storage_item_0(),
+            storage_item_1(),
+            storage_item_2(),
+            storage_item_3(),
+            storage_item_4(),
+            (
+                CHILD_ROOT_PARENT_LEAF_INDEX,
+                (StorageSlotType::Value { value_arity: 0 }, *child_smt.root()),
+            ),
+        ],
+        BTreeMap::from([(STORAGE_INDEX_2, storage_map_2())]),
+        BTreeMap::from([(STORAGE_INDEX_3, storage_array_3())]),
+    )
+    .unwrap();
+    storage.set_item_words(STORAGE_INDEX_4, STORAGE_WORDS_4.to_vec()).unwrap();
+    storage
 }
 
 // Constants that define the indexes of the account procedures of interest
 pub const ACCOUNT_PROCEDURE_INCR_NONCE_PROC_IDX: usize = 2;
 pub const ACCOUNT_PROCEDURE_SET_ITEM_PROC_IDX: usize = 3;
 pub const ACCOUNT_PROCEDURE_SET_CODE_PROC_IDX: usize = 4;
+pub const ACCOUNT_PROCEDURE_SET_ITEM_WORDS_PROC_IDX: usize = 8;
+
+/// Source code of the `create_note` procedure exposed by the mock account.
+const MOCK_CREATE_NOTE_PROC: &str = "\
+            export.create_note
+                # apply padding
+                repeat.9
+                    push.0 movdn.10
+                end
+
+                # create note
+                exec.tx::create_note
+                # => [ptr, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+            end";
 
 pub fn mock_account_code(assembler: &Assembler) -> AccountCode {
-    let account_code = "\
+    let account_code = format!(
+        "\
             use.miden::sat::account
             use.miden::sat::tx
             use.miden::wallets::basic->wallet
@@ -101,16 +130,7 @@ pub fn mock_account_code(assembler: &Assembler) -> AccountCode {
             end
 
             # acct proc 5
-            export.create_note
-                # apply padding
-                repeat.8
-                    push.0 movdn.9
-                end
-
-                # create note
-                exec.tx::create_note
-                # => [ptr, 0, 0, 0, 0, 0, 0, 0, 0]
-            end
+            {MOCK_CREATE_NOTE_PROC}
 
             # acct proc 6
             export.account_procedure_1
@@ -123,11 +143,34 @@ pub fn mock_account_code(assembler: &Assembler) -> AccountCode {
                 push.2.1
                 sub
             end
-            ";
-    let account_module_ast = ModuleAst::parse(account_code).unwrap();
+
+            # acct proc 8
+            export.set_item_words.3
+                # store the words of the value in local memory
+                movdn.12 loc_storew.0 dropw loc_storew.1 dropw loc_storew.2 dropw
+                # => [index]
+
+                locaddr.0 swap exec.account::set_item_words
+                # => [R', OLD_COMMITMENT, 0, 0, 0, 0, 0, 0, 0]
+
+                repeat.7
+                    movup.8 drop
+                end
+                # => [R', OLD_COMMITMENT]
+            end
+            "
+    );
+    let account_module_ast = ModuleAst::parse(&account_code).unwrap();
     AccountCode::new(account_module_ast, assembler).unwrap()
 }
 
+/// Returns the MAST root of the `create_note` procedure exposed by the mock account.
+pub fn mock_account_create_note_root(assembler: &Assembler) -> Digest {
+    let module_ast =
+        ModuleAst::parse(&format!("use.miden::sat::tx\n{MOCK_CREATE_NOTE_PROC}")).unwrap();
+    AccountCode::new(module_ast, assembler).unwrap().procedures()[0]
+}
+
 pub fn mock_new_account(assembler: &Assembler, auxiliary_data: &mut AdviceInputs) -> Account {
     let (acct_id, _account_seed) =
         generate_account_seed(AccountSeedType::RegularAccountUpdatableCodeOnChain);
@@ -172,10 +215,14 @@ pub fn mock_fungible_faucet(
     } else {
         Felt::new(FUNGIBLE_FAUCET_INITIAL_BALANCE)
     };
-    let account_storage = AccountStorage::new(vec![(
-        FAUCET_STORAGE_DATA_SLOT,
-        (StorageSlotType::Value { value_arity: 0 }, [ZERO, ZERO, ZERO, initial_balance]),
-    )])
+    let account_storage = AccountStorage::new(
+        vec![(
+            FAUCET_STORAGE_DATA_SLOT,
+            (StorageSlotType::Value { value_arity: 0 }, [ZERO, ZERO, ZERO, initial_balance]),
+        )],
+        BTreeMap::new(),
+        BTreeMap::new(),
+    )
     .unwrap();
     let account_id = AccountId::try_from(account_id).unwrap();
     let account_code = mock_account_code(assembler);
@@ -187,7 +234,6 @@ pub fn mock_non_fungible_faucet(
     nonce: Felt,
     empty_reserved_slot: bool,
     assembler: &Assembler,
-    auxiliary_data: &mut AdviceInputs,
 ) -> Account {
     let entires = match empty_reserved_slot {
         true => vec![],
@@ -198,15 +244,16 @@ pub fn mock_non_fungible_faucet(
     };
 
     // construct nft tree
-    let nft_tree = TieredSmt::with_entries(entires).unwrap();
-
-    // add nft tree data to auxiliary data inputs
-    auxiliary_data.extend_merkle_store(nft_tree.inner_nodes());
+    let nft_tree = StorageMap::with_entries(entires).unwrap();
 
-    let account_storage = AccountStorage::new(vec![(
-        FAUCET_STORAGE_DATA_SLOT,
-        (StorageSlotType::Map { value_arity: 0 }, *nft_tree.root()),
-    )])
+    let account_storage = AccountStorage::new(
+        vec![(
+            FAUCET_STORAGE_DATA_SLOT,
+            (StorageSlotType::Map { value_arity: 0 }, *nft_tree.root()),
+        )],
+        BTreeMap::from([(FAUCET_STORAGE_DATA_SLOT, nft_tree)]),
+        BTreeMap::new(),
+    )
     .unwrap();
     let account_id = AccountId::try_from(account_id).unwrap();
     let account_code = mock_account_code(assembler);
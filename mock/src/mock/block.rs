@@ -1,7 +1,4 @@
-use miden_objects::{
-    accounts::Account, crypto::merkle::SimpleSmt, utils::collections::Vec, BlockHeader, Digest,
-    Felt, StarkField, ZERO,
-};
+use miden_objects::{accounts::Account, block::AccountTree, BlockHeader, Digest, Felt, ZERO};
 use miden_test_utils::rand;
 
 pub fn mock_block_header(
@@ -10,19 +7,8 @@ pub fn mock_block_header(
     note_root: Option<Digest>,
     accts: &[Account],
 ) -> BlockHeader {
-    let acct_db = SimpleSmt::with_leaves(
-        64,
-        accts
-            .iter()
-            .flat_map(|acct| {
-                if acct.is_new() {
-                    None
-                } else {
-                    let felt_id: Felt = acct.id().into();
-                    Some((felt_id.as_int(), *acct.hash()))
-                }
-            })
-            .collect::<Vec<_>>(),
+    let acct_db = AccountTree::with_entries(
+        accts.iter().filter(|acct| !acct.is_new()).map(|acct| (acct.id(), acct.hash())),
     )
     .expect("failed to create account db");
 
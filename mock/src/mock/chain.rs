@@ -10,10 +10,10 @@ use core::fmt;
 use miden_objects::{
     accounts::{Account, AccountId, AccountType, SlotItem},
     assets::Asset,
-    crypto::merkle::{NodeIndex, SimpleSmt, TieredSmt},
-    notes::{Note, NoteInclusionProof, RecordedNote, NOTE_LEAF_DEPTH, NOTE_TREE_DEPTH},
+    block::{AccountTree, BlockNoteTree, NullifierTree},
+    notes::{Note, RecordedNote},
     utils::collections::Vec,
-    BlockHeader, ChainMmr, Digest, Felt, FieldElement, StarkField, Word,
+    BlockHeader, ChainMmr, Digest, Felt, FieldElement, Word,
 };
 use rand::{Rng, SeedableRng};
 
@@ -57,7 +57,7 @@ impl<R: Rng> Objects<R> {
         &mut self,
         pending: &mut Objects<R>,
         header: BlockHeader,
-        notes: &SimpleSmt,
+        notes: &BlockNoteTree,
     ) {
         self.accounts.append(&mut pending.accounts);
         self.fungible_faucets.append(&mut pending.fungible_faucets);
@@ -68,49 +68,31 @@ impl<R: Rng> Objects<R> {
         pending.nullifiers.clear(); // nullifiers are saved in the nullifier TSTM
     }
 
-    /// Creates a [SimpleSmt] tree from the `notes`.
+    /// Creates a [BlockNoteTree] from the `notes`.
     ///
     /// The root of the tree is a commitment to all notes created in the block. The commitment
     /// is not for all fields of the [Note] struct, but only for note metadata + core fields of
     /// a note (i.e., vault, inputs, script, and serial number).
-    pub fn build_notes_tree(&self) -> SimpleSmt {
-        let mut entries = Vec::with_capacity(self.notes.len() * 2);
-
-        entries.extend(self.notes.iter().enumerate().map(|(index, note)| {
-            let tree_index = (index * 2) as u64;
-            (tree_index, note.hash().into())
-        }));
-        entries.extend(self.notes.iter().enumerate().map(|(index, note)| {
-            let tree_index = (index * 2 + 1) as u64;
-            (tree_index, note.metadata().into())
-        }));
-
-        SimpleSmt::with_leaves(NOTE_LEAF_DEPTH, entries).unwrap()
+    ///
+    /// All notes are placed in the first batch of the block.
+    pub fn build_notes_tree(&self) -> BlockNoteTree {
+        let entries = self.notes.iter().enumerate().map(|(index, note)| (0, index, note.into()));
+
+        BlockNoteTree::with_entries(entries).expect("too many notes in the block")
     }
 
-    /// Given the [BlockHeader] and its notedb's [SimpleSmt], set all the [Note]'s proof.
+    /// Given the [BlockHeader] and its [BlockNoteTree], set all the [Note]'s proof.
     ///
     /// Update the [Note]'s proof once the [BlockHeader] has been created.
-    fn finalize_notes(&mut self, header: BlockHeader, notes: &SimpleSmt) -> Vec<RecordedNote> {
+    fn finalize_notes(&mut self, header: BlockHeader, notes: &BlockNoteTree) -> Vec<RecordedNote> {
         self.notes
             .drain(..)
             .enumerate()
             .map(|(index, note)| {
-                let auth_index =
-                    NodeIndex::new(NOTE_TREE_DEPTH, index as u64).expect("index bigger than 2**20");
-                let note_path =
-                    notes.get_path(auth_index).expect("auth_index outside of SimpleSmt range");
-                RecordedNote::new(
-                    note.clone(),
-                    NoteInclusionProof::new(
-                        header.block_num(),
-                        header.sub_hash(),
-                        header.note_root(),
-                        index as u64,
-                        note_path,
-                    )
-                    .expect("Invalid data provided to proof constructor"),
-                )
+                let proof = notes
+                    .get_inclusion_proof(&header, 0, index)
+                    .expect("Invalid data provided to proof constructor");
+                RecordedNote::new(note, proof)
             })
             .collect::<Vec<_>>()
     }
@@ -127,11 +109,10 @@ pub struct MockChain<R> {
     blocks: Vec<BlockHeader>,
 
     /// Tree containing the latest `Nullifier`'s tree.
-    nullifiers: TieredSmt,
+    nullifiers: NullifierTree,
 
     /// Tree containing the latest hash of each account.
-    // TODO: change this to a TieredSmt with 64bit keys.
-    accounts: SimpleSmt,
+    accounts: AccountTree,
 
     /// RNG used to seed builders.
     ///
@@ -195,8 +176,8 @@ impl<R: Rng + SeedableRng> MockChain<R> {
         Self {
             chain: ChainMmr::default(),
             blocks: vec![],
-            nullifiers: TieredSmt::default(),
-            accounts: SimpleSmt::new(64).expect("depth too big for SimpleSmt"),
+            nullifiers: NullifierTree::new(),
+            accounts: AccountTree::new(),
             rng,
             account_id_builder,
             objects: Objects::new(),
@@ -425,9 +406,13 @@ impl<R: Rng + SeedableRng> MockChain<R> {
             .unwrap()
     }
 
-    fn check_nullifier_unknown(&self, nullifier: Digest) {
-        assert!(self.pending_objects.nullifiers.iter().any(|e| *e == nullifier));
-        assert!(self.nullifiers.get_value(nullifier) != TieredSmt::EMPTY_VALUE)
+    fn check_nullifier_unknown(&self, nullifier: Digest) -> Result<(), MockError> {
+        if self.pending_objects.nullifiers.iter().any(|e| *e == nullifier)
+            || self.nullifiers.is_spent(nullifier.into())
+        {
+            return Err(MockError::DuplicatedNullifier);
+        }
+        Ok(())
     }
 
     // MODIFIERS
@@ -441,22 +426,19 @@ impl<R: Rng + SeedableRng> MockChain<R> {
         let block_num: Felt = block_num.into();
 
         for (account, _seed) in self.pending_objects.accounts.iter() {
-            let id: Felt = account.id().into();
-            self.accounts.update_leaf(id.as_int(), account.hash().into()).unwrap();
+            self.accounts.update_account(account.id(), account.hash());
         }
         for (account, _seed) in self.objects.accounts.iter() {
-            let id: Felt = account.id().into();
-            self.accounts.update_leaf(id.as_int(), account.hash().into()).unwrap();
+            self.accounts.update_account(account.id(), account.hash());
         }
 
         // TODO:
         // - resetting the nullifier tree once defined at the protocol level.
         // - insering only nullifier from transactions included in the batches, once the batch
         // kernel has been implemented.
-        for nullifier in self.pending_objects.nullifiers.iter() {
-            self.nullifiers
-                .insert(*nullifier, [block_num, Felt::ZERO, Felt::ZERO, Felt::ZERO]);
-        }
+        self.nullifiers
+            .insert_batch(self.pending_objects.nullifiers.iter().map(|n| (*n).into()), block_num)
+            .expect("nullifiers are checked when added to the pending block");
         let notes = self.pending_objects.build_notes_tree();
 
         let previous = self.blocks.last();
@@ -508,14 +490,14 @@ impl<R: Rng + SeedableRng> MockChain<R> {
             return Err(MockError::DuplicatedNote);
         }
 
-        self.check_nullifier_unknown(note.nullifier().inner());
+        self.check_nullifier_unknown(note.nullifier().inner())?;
         self.pending_objects.notes.push(note);
         Ok(())
     }
 
     /// Mark a [Note] as consumed by inserting its nullifier into the block.
     pub fn add_nullifier(&mut self, nullifier: Digest) -> Result<(), MockError> {
-        self.check_nullifier_unknown(nullifier);
+        self.check_nullifier_unknown(nullifier)?;
         self.pending_objects.nullifiers.push(nullifier);
         Ok(())
     }
@@ -543,7 +525,7 @@ impl<R: Rng + SeedableRng> MockChain<R> {
     }
 
     /// Get a reference to the nullifier tree.
-    pub fn nullifiers(&self) -> &TieredSmt {
+    pub fn nullifiers(&self) -> &NullifierTree {
         &self.nullifiers
     }
 
@@ -604,13 +586,8 @@ pub fn mock_chain_data(consumed_notes: Vec<Note>) -> (ChainMmr, Vec<RecordedNote
     // TODO: Consider how to better represent note authentication data.
     // we use the index for both the block number and the leaf index in the note tree
     for (index, note) in consumed_notes.iter().enumerate() {
-        let tree_index = 2 * index;
-        let smt_entries = vec![
-            (tree_index as u64, note.hash().into()),
-            ((tree_index + 1) as u64, note.metadata().into()),
-        ];
-        let smt = SimpleSmt::with_leaves(NOTE_LEAF_DEPTH, smt_entries).unwrap();
-        note_trees.push(smt);
+        let note_tree = BlockNoteTree::with_entries([(0, index, note.into())]).unwrap();
+        note_trees.push(note_tree);
     }
 
     let mut note_tree_iter = note_trees.iter();
@@ -635,18 +612,8 @@ pub fn mock_chain_data(consumed_notes: Vec<Note>) -> (ChainMmr, Vec<RecordedNote
         .enumerate()
         .map(|(index, note)| {
             let block_header = &block_chain[index];
-            let auth_index = NodeIndex::new(NOTE_TREE_DEPTH, index as u64).unwrap();
-            RecordedNote::new(
-                note,
-                NoteInclusionProof::new(
-                    block_header.block_num(),
-                    block_header.sub_hash(),
-                    block_header.note_root(),
-                    index as u64,
-                    note_trees[index].get_path(auth_index).unwrap(),
-                )
-                .unwrap(),
-            )
+            let proof = note_trees[index].get_inclusion_proof(block_header, 0, index).unwrap();
+            RecordedNote::new(note, proof)
         })
         .collect::<Vec<_>>();
 
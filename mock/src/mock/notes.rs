@@ -5,15 +5,16 @@ use crate::{
         ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_SENDER, CONSUMED_ASSET_1_AMOUNT,
         CONSUMED_ASSET_2_AMOUNT, CONSUMED_ASSET_3_AMOUNT,
     },
+    mock::account::mock_account_create_note_root,
     utils::{prepare_assets, prepare_word},
 };
 use miden_objects::{
     accounts::AccountId,
     assembly::{Assembler, ProgramAst},
     assets::{Asset, FungibleAsset},
-    notes::{Note, NoteScript},
+    notes::{Note, NoteScript, NoteTag, NoteType},
     utils::collections::Vec,
-    Felt, Word, ZERO,
+    Felt, Word,
 };
 
 pub enum AssetPreservationStatus {
@@ -56,7 +57,8 @@ pub fn mock_notes(
         &[fungible_asset_1],
         SERIAL_NUM_4,
         sender,
-        ZERO,
+        NoteType::Public,
+        NoteTag::default(),
     )
     .unwrap();
 
@@ -67,45 +69,61 @@ pub fn mock_notes(
         &[fungible_asset_2],
         SERIAL_NUM_5,
         sender,
-        ZERO,
+        NoteType::Public,
+        NoteTag::default(),
     )
     .unwrap();
 
     const SERIAL_NUM_6: Word = [Felt::new(21), Felt::new(22), Felt::new(23), Felt::new(24)];
-    let created_note_3 =
-        Note::new(note_script, &[Felt::new(2)], &[fungible_asset_3], SERIAL_NUM_6, sender, ZERO)
-            .unwrap();
+    let created_note_3 = Note::new(
+        note_script,
+        &[Felt::new(2)],
+        &[fungible_asset_3],
+        SERIAL_NUM_6,
+        sender,
+        NoteType::Public,
+        NoteTag::default(),
+    )
+    .unwrap();
 
     let created_notes = vec![created_note_1, created_note_2, created_note_3];
 
     // CONSUMED NOTES
     // --------------------------------------------------------------------------------------------
 
+    // MAST root of the `create_note` mock account procedure which is called by the note scripts
+    let create_note_root = hex::encode(mock_account_create_note_root(assembler).as_bytes());
+
     // create note 1 script
     let note_1_script_src = format!(
         "\
         begin
             # create note 0
             push.{created_note_0_recipient}
+            push.{created_note_0_note_type}
             push.{created_note_0_tag}
             push.{created_note_0_asset}
             # MAST root of the `create_note` mock account procedure
-            call.0xeba65b5e6276e2c2f8e52f8ab8ce0c039bdfba127961826ff7d125c32d6d2458
-            drop dropw dropw
+            call.0x{create_note_root}
+            drop dropw dropw drop
 
             # create note 1
             push.{created_note_1_recipient}
+            push.{created_note_1_note_type}
             push.{created_note_1_tag}
             push.{created_note_1_asset}
             # MAST root of the `create_note` mock account procedure
-            call.0xeba65b5e6276e2c2f8e52f8ab8ce0c039bdfba127961826ff7d125c32d6d2458
-            drop dropw dropw
+            call.0x{create_note_root}
+            drop dropw dropw drop
         end
     ",
+        create_note_root = create_note_root,
         created_note_0_recipient = prepare_word(&created_notes[0].recipient()),
+        created_note_0_note_type = created_notes[0].metadata().note_type(),
         created_note_0_tag = created_notes[0].metadata().tag(),
         created_note_0_asset = prepare_assets(created_notes[0].vault())[0],
         created_note_1_recipient = prepare_word(&created_notes[1].recipient()),
+        created_note_1_note_type = created_notes[1].metadata().note_type(),
         created_note_1_tag = created_notes[1].metadata().tag(),
         created_note_1_asset = prepare_assets(created_notes[1].vault())[0],
     );
@@ -118,14 +136,17 @@ pub fn mock_notes(
         begin
             # create note 2
             push.{created_note_2_recipient}
+            push.{created_note_2_note_type}
             push.{created_note_2_tag}
             push.{created_note_2_asset}
             # MAST root of the `create_note` mock account procedure
-            call.0xeba65b5e6276e2c2f8e52f8ab8ce0c039bdfba127961826ff7d125c32d6d2458
-            drop dropw dropw
+            call.0x{create_note_root}
+            drop dropw dropw drop
         end
         ",
+        create_note_root = create_note_root,
         created_note_2_recipient = prepare_word(&created_notes[2].recipient()),
+        created_note_2_note_type = created_notes[2].metadata().note_type(),
         created_note_2_tag = created_notes[2].metadata().tag(),
         created_note_2_asset = prepare_assets(created_notes[2].vault())[0],
     );
@@ -134,9 +155,16 @@ pub fn mock_notes(
 
     // Consumed Notes
     const SERIAL_NUM_1: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
-    let consumed_note_1 =
-        Note::new(note_1_script, &[Felt::new(1)], &[fungible_asset_1], SERIAL_NUM_1, sender, ZERO)
-            .unwrap();
+    let consumed_note_1 = Note::new(
+        note_1_script,
+        &[Felt::new(1)],
+        &[fungible_asset_1],
+        SERIAL_NUM_1,
+        sender,
+        NoteType::Public,
+        NoteTag::default(),
+    )
+    .unwrap();
 
     const SERIAL_NUM_2: Word = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
     let consumed_note_2 = Note::new(
@@ -145,7 +173,8 @@ pub fn mock_notes(
         &[fungible_asset_2, fungible_asset_3],
         SERIAL_NUM_2,
         sender,
-        ZERO,
+        NoteType::Public,
+        NoteTag::default(),
     )
     .unwrap();
 
@@ -159,7 +188,8 @@ pub fn mock_notes(
         &[fungible_asset_2, fungible_asset_3],
         SERIAL_NUM_3,
         sender,
-        ZERO,
+        NoteType::Public,
+        NoteTag::default(),
     )
     .unwrap();
 
@@ -173,7 +203,8 @@ pub fn mock_notes(
         &[non_fungible_asset_2(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN)],
         SERIAL_NUM_7,
         sender,
-        ZERO,
+        NoteType::Public,
+        NoteTag::default(),
     )
     .unwrap();
 
@@ -219,7 +250,8 @@ pub fn mock_notes(
         ],
         SERIAL_NUM_8,
         sender,
-        ZERO,
+        NoteType::Public,
+        NoteTag::default(),
     )
     .unwrap();
 
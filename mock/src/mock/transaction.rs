@@ -43,13 +43,7 @@ pub fn mock_inputs(
             acct_id,
             nonce,
             empty_reserved_slot,
-        } => mock_non_fungible_faucet(
-            acct_id,
-            nonce,
-            empty_reserved_slot,
-            &assembler,
-            &mut auxiliary_data,
-        ),
+        } => mock_non_fungible_faucet(acct_id, nonce, empty_reserved_slot, &assembler),
     };
 
     // mock notes
@@ -100,13 +94,7 @@ pub fn mock_inputs_with_existing(
             acct_id,
             nonce,
             empty_reserved_slot,
-        } => mock_non_fungible_faucet(
-            acct_id,
-            nonce,
-            empty_reserved_slot,
-            &assembler,
-            &mut auxiliary_data,
-        ),
+        } => mock_non_fungible_faucet(acct_id, nonce, empty_reserved_slot, &assembler),
     };
 
     let (mut consumed_notes, _created_notes) = mock_notes(&assembler, &asset_preservation);
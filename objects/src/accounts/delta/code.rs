@@ -0,0 +1,69 @@
+use super::{
+    AccountCode, AccountDeltaError, ByteReader, ByteWriter, Deserializable, DeserializationError,
+    Digest, Serializable, ToString,
+};
+
+// ACCOUNT CODE DELTA
+// ================================================================================================
+
+/// [AccountCodeDelta] stores the new code of an account whose code was updated.
+///
+/// The new code is represented as follows:
+/// - code_root: the root of the new account code.
+/// - code: the new [AccountCode] if it is known, or None if only the root of the new code is
+///   known.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountCodeDelta {
+    pub code_root: Digest,
+    pub code: Option<AccountCode>,
+}
+
+impl AccountCodeDelta {
+    /// Checks whether this code delta is valid.
+    ///
+    /// # Errors
+    /// Returns an error if the new code is provided, but its root is not equal to the new code
+    /// root.
+    pub fn validate(&self) -> Result<(), AccountDeltaError> {
+        if let Some(code) = &self.code {
+            if code.root() != self.code_root {
+                return Err(AccountDeltaError::InconsistentCodeUpdate {
+                    expected: self.code_root,
+                    actual: code.root(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl From<AccountCode> for AccountCodeDelta {
+    fn from(code: AccountCode) -> Self {
+        Self {
+            code_root: code.root(),
+            code: Some(code),
+        }
+    }
+}
+
+impl Serializable for AccountCodeDelta {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.code_root.write_into(target);
+        self.code.write_into(target);
+    }
+}
+
+impl Deserializable for AccountCodeDelta {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let code_root = Digest::read_from(source)?;
+        let code = <Option<AccountCode>>::read_from(source)?;
+
+        let delta = Self { code_root, code };
+        delta
+            .validate()
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+        Ok(delta)
+    }
+}
@@ -1,8 +1,14 @@
 use super::{
-    ByteReader, ByteWriter, Deserializable, DeserializationError, Felt, Serializable, ToString,
-    Vec, Word, ZERO,
+    AccountCode, AccountId, BTreeMap, ByteReader, ByteWriter, Deserializable, DeserializationError,
+    Digest, Felt, Serializable, StarkField, ToString, Vec, Word, ZERO,
 };
-use crate::{assets::Asset, AccountDeltaError};
+use crate::{
+    assets::{Asset, FungibleAsset},
+    AccountDeltaError,
+};
+
+mod code;
+pub use code::AccountCodeDelta;
 
 mod storage;
 pub use storage::AccountStorageDelta;
@@ -18,13 +24,14 @@ pub use vault::AccountVaultDelta;
 /// The differences are represented as follows:
 /// - storage: an [AccountStorageDelta] that contains the changes to the account storage.
 /// - vault: an [AccountVaultDelta] object that contains the changes to the account vault.
+/// - code: if the code of the account has changed, an [AccountCodeDelta] with the new code is
+///   stored here.
 /// - nonce: if the nonce of the account has changed, the new nonce is stored here.
-///
-/// TODO: add ability to trace account code updates.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct AccountDelta {
     storage: AccountStorageDelta,
     vault: AccountVaultDelta,
+    code: Option<AccountCodeDelta>,
     nonce: Option<Felt>,
 }
 
@@ -35,24 +42,29 @@ impl AccountDelta {
     ///
     /// # Errors
     /// Returns an error if:
-    /// - Storage or vault deltas are invalid.
-    /// - Storage and vault deltas are empty, and the nonce was updated.
-    /// - Storage or vault deltas are not empty, but nonce was not updated.
+    /// - Storage, vault, or code deltas are invalid.
+    /// - Storage, vault, and code deltas are empty, and the nonce was updated.
+    /// - Storage, vault, or code deltas are not empty, but nonce was not updated.
     pub fn new(
         storage: AccountStorageDelta,
         vault: AccountVaultDelta,
+        code: Option<AccountCodeDelta>,
         nonce: Option<Felt>,
     ) -> Result<Self, AccountDeltaError> {
-        // make sure storage and vault deltas are valid
+        // make sure storage, vault, and code deltas are valid
         storage.validate()?;
         vault.validate()?;
+        if let Some(code) = &code {
+            code.validate()?;
+        }
 
-        // nonce must be updated if and only if either account storage or vault were updated
-        validate_nonce(nonce, &storage, &vault)?;
+        // nonce must be updated if and only if either account storage, vault, or code were updated
+        validate_nonce(nonce, &storage, &vault, code.as_ref())?;
 
         Ok(Self {
             storage,
             vault,
+            code,
             nonce,
         })
     }
@@ -62,7 +74,7 @@ impl AccountDelta {
 
     /// Returns true if this account delta does not contain any updates.
     pub fn is_empty(&self) -> bool {
-        self.storage.is_empty() && self.vault.is_empty()
+        self.storage.is_empty() && self.vault.is_empty() && self.code.is_none()
     }
 
     /// Returns storage updates for this account delta.
@@ -75,14 +87,75 @@ impl AccountDelta {
         &self.vault
     }
 
+    /// Returns the new account code, if the code was changed.
+    pub fn code(&self) -> Option<&AccountCodeDelta> {
+        self.code.as_ref()
+    }
+
     /// Returns the new nonce, if the nonce was changes.
     pub fn nonce(&self) -> Option<Felt> {
         self.nonce
     }
 
     /// Converts this storage delta into individual delta components.
-    pub fn into_parts(self) -> (AccountStorageDelta, AccountVaultDelta, Option<Felt>) {
-        (self.storage, self.vault, self.nonce)
+    pub fn into_parts(
+        self,
+    ) -> (AccountStorageDelta, AccountVaultDelta, Option<AccountCodeDelta>, Option<Felt>) {
+        (self.storage, self.vault, self.code, self.nonce)
+    }
+
+    // COMPOSITION
+    // --------------------------------------------------------------------------------------------
+
+    /// Merges this delta with the delta of a subsequent state transition of the same account and
+    /// returns a single delta describing both transitions.
+    ///
+    /// Applying the merged delta to an account is equivalent to applying this delta followed by
+    /// the `other` delta.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Both deltas update the nonce, but the nonce of the `other` delta is not greater than the
+    ///   nonce of this delta.
+    /// - The merged storage or vault delta is invalid (e.g., the net amount of a fungible asset
+    ///   exceeds the maximum allowed amount).
+    /// - The merged delta is not empty, but the nonce was not updated by either delta.
+    ///
+    /// Note that the merged delta may be empty and still update the nonce. This is the case when
+    /// the updates of the two deltas cancel each other out.
+    pub fn merge(self, other: Self) -> Result<Self, AccountDeltaError> {
+        let nonce = match (self.nonce, other.nonce) {
+            (Some(nonce), Some(other_nonce)) if other_nonce.as_int() <= nonce.as_int() => {
+                return Err(AccountDeltaError::InconsistentNonceUpdate(
+                    "nonce of the merged delta is not greater than the current nonce".to_string(),
+                ));
+            }
+            (nonce, other_nonce) => other_nonce.or(nonce),
+        };
+
+        let storage = self.storage.merge(other.storage);
+        let vault = self.vault.merge(other.vault)?;
+        let code = other.code.or(self.code);
+
+        storage.validate()?;
+        vault.validate()?;
+        if let Some(code) = &code {
+            code.validate()?;
+        }
+
+        // updates of the merged deltas may cancel each other out, in which case the nonce is still
+        // updated; thus, the nonce is validated only if the merged delta is not empty
+        let delta = Self {
+            storage,
+            vault,
+            code,
+            nonce,
+        };
+        if !delta.is_empty() {
+            validate_nonce(delta.nonce, &delta.storage, &delta.vault, delta.code.as_ref())?;
+        }
+
+        Ok(delta)
     }
 }
 
@@ -90,6 +163,7 @@ impl Serializable for AccountDelta {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         self.storage.write_into(target);
         self.vault.write_into(target);
+        self.code.write_into(target);
         self.nonce.write_into(target);
     }
 }
@@ -98,14 +172,16 @@ impl Deserializable for AccountDelta {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let storage = AccountStorageDelta::read_from(source)?;
         let vault = AccountVaultDelta::read_from(source)?;
+        let code = <Option<AccountCodeDelta>>::read_from(source)?;
         let nonce = <Option<Felt>>::read_from(source)?;
 
-        validate_nonce(nonce, &storage, &vault)
+        validate_nonce(nonce, &storage, &vault, code.as_ref())
             .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
 
         Ok(Self {
             storage,
             vault,
+            code,
             nonce,
         })
     }
@@ -114,18 +190,19 @@ impl Deserializable for AccountDelta {
 // HELPER FUNCTIONS
 // ================================================================================================
 
-/// Checks if the nonce was updated correctly given the provided storage and vault deltas.
+/// Checks if the nonce was updated correctly given the provided storage, vault, and code deltas.
 ///
 /// # Errors
 /// Returns an error if:
-/// - Storage or vault were updated, but the nonce was either not updated or set to 0.
-/// - Storage and vault were not updated, but the nonce was updated.
+/// - Storage, vault, or code were updated, but the nonce was either not updated or set to 0.
+/// - Storage, vault, and code were not updated, but the nonce was updated.
 fn validate_nonce(
     nonce: Option<Felt>,
     storage: &AccountStorageDelta,
     vault: &AccountVaultDelta,
+    code: Option<&AccountCodeDelta>,
 ) -> Result<(), AccountDeltaError> {
-    if !storage.is_empty() || !vault.is_empty() {
+    if !storage.is_empty() || !vault.is_empty() || code.is_some() {
         match nonce {
             Some(nonce) => {
                 if nonce == ZERO {
@@ -154,8 +231,16 @@ fn validate_nonce(
 
 #[cfg(test)]
 mod tests {
-    use super::{AccountDelta, AccountStorageDelta, AccountVaultDelta};
-    use crate::{ONE, ZERO};
+    use super::{
+        AccountCodeDelta, AccountDelta, AccountStorageDelta, AccountVaultDelta, Deserializable,
+        Serializable,
+    };
+    use crate::{
+        accounts::{AccountCode, AccountId, AccountType},
+        assembly::{Assembler, ModuleAst},
+        assets::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+        Digest, Felt, ONE, ZERO,
+    };
 
     #[test]
     fn account_delta_nonce_validation() {
@@ -163,6 +248,9 @@ mod tests {
         let storage_delta = AccountStorageDelta {
             cleared_items: vec![],
             updated_items: vec![],
+            updated_words: vec![],
+            updated_maps: vec![],
+            updated_arrays: vec![],
         };
 
         let vault_delta = AccountVaultDelta {
@@ -170,17 +258,175 @@ mod tests {
             removed_assets: vec![],
         };
 
-        assert!(AccountDelta::new(storage_delta.clone(), vault_delta.clone(), None).is_ok());
-        assert!(AccountDelta::new(storage_delta.clone(), vault_delta.clone(), Some(ONE)).is_err());
+        assert!(AccountDelta::new(storage_delta.clone(), vault_delta.clone(), None, None).is_ok());
+        assert!(
+            AccountDelta::new(storage_delta.clone(), vault_delta.clone(), None, Some(ONE)).is_err()
+        );
+
+        // code-only delta
+        let code_delta = AccountCodeDelta {
+            code_root: Digest::new([ONE, ZERO, ZERO, ZERO]),
+            code: None,
+        };
+
+        assert!(AccountDelta::new(
+            storage_delta.clone(),
+            vault_delta.clone(),
+            Some(code_delta.clone()),
+            None
+        )
+        .is_err());
+        assert!(AccountDelta::new(
+            storage_delta.clone(),
+            vault_delta.clone(),
+            Some(code_delta.clone()),
+            Some(ONE)
+        )
+        .is_ok());
 
         // non-empty delta
         let storage_delta = AccountStorageDelta {
             cleared_items: vec![1],
             updated_items: vec![],
+            updated_words: vec![],
+            updated_maps: vec![],
+            updated_arrays: vec![],
         };
 
-        assert!(AccountDelta::new(storage_delta.clone(), vault_delta.clone(), None).is_err());
-        assert!(AccountDelta::new(storage_delta.clone(), vault_delta.clone(), Some(ZERO)).is_err());
-        assert!(AccountDelta::new(storage_delta.clone(), vault_delta.clone(), Some(ONE)).is_ok());
+        assert!(AccountDelta::new(storage_delta.clone(), vault_delta.clone(), None, None).is_err());
+        assert!(AccountDelta::new(storage_delta.clone(), vault_delta.clone(), None, Some(ZERO))
+            .is_err());
+        assert!(
+            AccountDelta::new(storage_delta.clone(), vault_delta.clone(), None, Some(ONE)).is_ok()
+        );
+    }
+
+    #[test]
+    fn account_delta_merge() {
+        let ffid = AccountId::new_dummy([0; 32], AccountType::FungibleFaucet);
+        let nffid = AccountId::new_dummy([0; 32], AccountType::NonFungibleFaucet);
+
+        let fungible = |amount| -> Asset { FungibleAsset::new(ffid, amount).unwrap().into() };
+        let non_fungible: Asset =
+            NonFungibleAsset::new(&NonFungibleAssetDetails::new(nffid, vec![1, 2, 3]).unwrap())
+                .unwrap()
+                .into();
+
+        let delta1 = AccountDelta::new(
+            AccountStorageDelta {
+                cleared_items: vec![1],
+                updated_items: vec![(2, [ONE, ONE, ONE, ONE]), (3, [ONE, ZERO, ZERO, ZERO])],
+                updated_words: vec![],
+                updated_maps: vec![],
+                updated_arrays: vec![],
+            },
+            AccountVaultDelta {
+                added_assets: vec![fungible(100), non_fungible],
+                removed_assets: vec![],
+            },
+            None,
+            Some(ONE),
+        )
+        .unwrap();
+
+        let code_delta = AccountCodeDelta {
+            code_root: Digest::new([ONE, ZERO, ZERO, ZERO]),
+            code: None,
+        };
+        let delta2 = AccountDelta::new(
+            AccountStorageDelta {
+                cleared_items: vec![2],
+                updated_items: vec![(1, [ONE, ONE, ZERO, ZERO]), (3, [ZERO, ONE, ZERO, ZERO])],
+                updated_words: vec![],
+                updated_maps: vec![],
+                updated_arrays: vec![],
+            },
+            AccountVaultDelta {
+                added_assets: vec![],
+                removed_assets: vec![fungible(30), non_fungible],
+            },
+            Some(code_delta.clone()),
+            Some(Felt::new(2)),
+        )
+        .unwrap();
+
+        let merged = delta1.clone().merge(delta2.clone()).unwrap();
+        assert_eq!(merged.storage().cleared_items, vec![2]);
+        assert_eq!(
+            merged.storage().updated_items,
+            vec![(3, [ZERO, ONE, ZERO, ZERO]), (1, [ONE, ONE, ZERO, ZERO])]
+        );
+        assert_eq!(merged.vault().added_assets, vec![fungible(70)]);
+        assert!(merged.vault().removed_assets.is_empty());
+        assert_eq!(merged.code(), Some(&code_delta));
+        assert_eq!(merged.nonce(), Some(Felt::new(2)));
+
+        // updates which cancel each other out still result in a nonce update
+        let delta3 = AccountDelta::new(
+            AccountStorageDelta::default(),
+            AccountVaultDelta {
+                added_assets: vec![],
+                removed_assets: vec![fungible(100), non_fungible],
+            },
+            None,
+            Some(Felt::new(2)),
+        )
+        .unwrap();
+        let delta4 = AccountDelta::new(
+            AccountStorageDelta::default(),
+            AccountVaultDelta {
+                added_assets: vec![fungible(100), non_fungible],
+                removed_assets: vec![],
+            },
+            None,
+            Some(Felt::new(3)),
+        )
+        .unwrap();
+
+        let merged = delta3.merge(delta4).unwrap();
+        assert!(merged.is_empty());
+        assert_eq!(merged.nonce(), Some(Felt::new(3)));
+
+        // nonce must increase across merged deltas
+        assert!(delta2.merge(delta1).is_err());
+    }
+
+    #[test]
+    fn account_delta_code_serialization() {
+        let module = ModuleAst::parse("export.foo push.1 drop end").unwrap();
+        let code = AccountCode::new(module, &Assembler::default()).unwrap();
+
+        // delta with the full new code
+        let delta = AccountDelta::new(
+            AccountStorageDelta::default(),
+            AccountVaultDelta::default(),
+            Some(AccountCodeDelta::from(code.clone())),
+            Some(ONE),
+        )
+        .unwrap();
+        let bytes = delta.to_bytes();
+        assert_eq!(AccountDelta::read_from_bytes(&bytes), Ok(delta));
+
+        // delta with only the root of the new code
+        let delta = AccountDelta::new(
+            AccountStorageDelta::default(),
+            AccountVaultDelta::default(),
+            Some(AccountCodeDelta {
+                code_root: code.root(),
+                code: None,
+            }),
+            Some(ONE),
+        )
+        .unwrap();
+        let bytes = delta.to_bytes();
+        assert_eq!(AccountDelta::read_from_bytes(&bytes), Ok(delta));
+
+        // the root of the new code must match the code
+        let delta = AccountCodeDelta {
+            code_root: Digest::new([ONE, ZERO, ZERO, ZERO]),
+            code: Some(code),
+        };
+        let bytes = delta.to_bytes();
+        assert!(AccountCodeDelta::read_from_bytes(&bytes).is_err());
     }
 }
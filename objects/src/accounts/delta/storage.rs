@@ -15,10 +15,24 @@ const MAX_MUTABLE_STORAGE_SLOT_IDX: u8 = 254;
 ///
 /// The differences are represented as follows:
 /// - item updates: represented by `cleared_items` and `updated_items` field.
+/// - multi-word value updates: represented by `updated_words` field. For every multi-word value
+///   in `updated_items` whose words are known, this field contains the words committed to by the
+///   new value of the slot.
+/// - map updates: represented by `updated_maps` field, which contains the (key, value) entries set
+///   in the maps stored in the slots of `updated_items`.
+/// - array updates: represented by `updated_arrays` field, which contains the (index, value) items
+///   set in the arrays stored in the slots of `updated_items`.
+///
+/// The slot types commitment (slot 255) is immutable, and thus can never be cleared. It may be
+/// included in `updated_items` only to describe the initial state of a newly created account, in
+/// which case the value must match the slot types commitment of the account.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct AccountStorageDelta {
     pub cleared_items: Vec<u8>,
     pub updated_items: Vec<(u8, Word)>,
+    pub updated_words: Vec<(u8, Vec<Word>)>,
+    pub updated_maps: Vec<(u8, Vec<(Word, Word)>)>,
+    pub updated_arrays: Vec<(u8, Vec<(u64, Word)>)>,
 }
 
 impl AccountStorageDelta {
@@ -27,11 +41,16 @@ impl AccountStorageDelta {
     /// # Errors
     /// Returns an error if:
     /// - The number of cleared or updated items is greater than 255.
-    /// - Any of cleared or updated items are at slot 255 (i.e., immutable slot).
+    /// - Any of cleared items are at slot 255 (i.e., immutable slot).
     /// - Any of the cleared or updated items is referenced more than once (e.g., updated twice).
+    /// - Words, map entries, or array items are provided for an item which is not updated, or more
+    ///   than once for the same item.
     pub fn validate(&self) -> Result<(), AccountDeltaError> {
         let num_cleared_items = self.cleared_items.len();
         let num_updated_items = self.updated_items.len();
+        let num_updated_words = self.updated_words.len();
+        let num_updated_maps = self.updated_maps.len();
+        let num_updated_arrays = self.updated_arrays.len();
 
         if num_cleared_items > u8::MAX as usize {
             return Err(AccountDeltaError::TooManyClearedStorageItems {
@@ -43,6 +62,21 @@ impl AccountStorageDelta {
                 actual: num_updated_items,
                 max: u8::MAX as usize,
             });
+        } else if num_updated_words > u8::MAX as usize {
+            return Err(AccountDeltaError::TooManyUpdatedStorageItems {
+                actual: num_updated_words,
+                max: u8::MAX as usize,
+            });
+        } else if num_updated_maps > u8::MAX as usize {
+            return Err(AccountDeltaError::TooManyUpdatedStorageItems {
+                actual: num_updated_maps,
+                max: u8::MAX as usize,
+            });
+        } else if num_updated_arrays > u8::MAX as usize {
+            return Err(AccountDeltaError::TooManyUpdatedStorageItems {
+                actual: num_updated_arrays,
+                max: u8::MAX as usize,
+            });
         }
 
         // make sure cleared items vector does not contain errors
@@ -58,10 +92,6 @@ impl AccountStorageDelta {
 
         // make sure updates items vector does not contain errors
         for (pos, (idx, _)) in self.updated_items.iter().enumerate() {
-            if *idx > MAX_MUTABLE_STORAGE_SLOT_IDX {
-                return Err(AccountDeltaError::ImmutableStorageSlot(*idx as usize));
-            }
-
             if self.cleared_items.contains(idx) {
                 return Err(AccountDeltaError::DuplicateStorageItemUpdate(*idx as usize));
             }
@@ -71,6 +101,39 @@ impl AccountStorageDelta {
             }
         }
 
+        // make sure words are provided only for updated items
+        for (pos, (idx, _)) in self.updated_words.iter().enumerate() {
+            if !self.updated_items.iter().any(|x| x.0 == *idx) {
+                return Err(AccountDeltaError::InconsistentStorageUpdate(*idx as usize));
+            }
+
+            if self.updated_words[..pos].iter().any(|x| x.0 == *idx) {
+                return Err(AccountDeltaError::DuplicateStorageItemUpdate(*idx as usize));
+            }
+        }
+
+        // make sure map entries are provided only for updated items
+        for (pos, (idx, _)) in self.updated_maps.iter().enumerate() {
+            if !self.updated_items.iter().any(|x| x.0 == *idx) {
+                return Err(AccountDeltaError::InconsistentStorageUpdate(*idx as usize));
+            }
+
+            if self.updated_maps[..pos].iter().any(|x| x.0 == *idx) {
+                return Err(AccountDeltaError::DuplicateStorageItemUpdate(*idx as usize));
+            }
+        }
+
+        // make sure array items are provided only for updated items
+        for (pos, (idx, _)) in self.updated_arrays.iter().enumerate() {
+            if !self.updated_items.iter().any(|x| x.0 == *idx) {
+                return Err(AccountDeltaError::InconsistentStorageUpdate(*idx as usize));
+            }
+
+            if self.updated_arrays[..pos].iter().any(|x| x.0 == *idx) {
+                return Err(AccountDeltaError::DuplicateStorageItemUpdate(*idx as usize));
+            }
+        }
+
         Ok(())
     }
 
@@ -78,6 +141,65 @@ impl AccountStorageDelta {
     pub fn is_empty(&self) -> bool {
         self.cleared_items.is_empty() && self.updated_items.is_empty()
     }
+
+    /// Merges this storage delta with a subsequent storage delta.
+    ///
+    /// Items cleared or updated by the `other` delta override the updates made to the same items
+    /// by this delta. This includes the words of multi-word values. Map entries and array items
+    /// set by both deltas are combined, with the values set by the `other` delta taking
+    /// precedence.
+    pub fn merge(mut self, other: Self) -> Self {
+        for idx in other.cleared_items {
+            self.updated_items.retain(|(updated_idx, _)| *updated_idx != idx);
+            self.updated_words.retain(|(updated_idx, _)| *updated_idx != idx);
+            self.updated_maps.retain(|(updated_idx, _)| *updated_idx != idx);
+            self.updated_arrays.retain(|(updated_idx, _)| *updated_idx != idx);
+            if !self.cleared_items.contains(&idx) {
+                self.cleared_items.push(idx);
+            }
+        }
+
+        for (idx, value) in other.updated_items {
+            self.cleared_items.retain(|cleared_idx| *cleared_idx != idx);
+            self.updated_words.retain(|(updated_idx, _)| *updated_idx != idx);
+            match self.updated_items.iter_mut().find(|(updated_idx, _)| *updated_idx == idx) {
+                Some(item) => item.1 = value,
+                None => self.updated_items.push((idx, value)),
+            }
+        }
+
+        self.updated_words.extend(other.updated_words);
+
+        for (idx, entries) in other.updated_maps {
+            match self.updated_maps.iter_mut().find(|(updated_idx, _)| *updated_idx == idx) {
+                Some((_, current)) => {
+                    for (key, value) in entries {
+                        match current.iter_mut().find(|(current_key, _)| *current_key == key) {
+                            Some(entry) => entry.1 = value,
+                            None => current.push((key, value)),
+                        }
+                    }
+                }
+                None => self.updated_maps.push((idx, entries)),
+            }
+        }
+
+        for (idx, items) in other.updated_arrays {
+            match self.updated_arrays.iter_mut().find(|(updated_idx, _)| *updated_idx == idx) {
+                Some((_, current)) => {
+                    for (item_idx, value) in items {
+                        match current.iter_mut().find(|(current_idx, _)| *current_idx == item_idx) {
+                            Some(item) => item.1 = value,
+                            None => current.push((item_idx, value)),
+                        }
+                    }
+                }
+                None => self.updated_arrays.push((idx, items)),
+            }
+        }
+
+        self
+    }
 }
 
 impl Serializable for AccountStorageDelta {
@@ -94,6 +216,37 @@ impl Serializable for AccountStorageDelta {
             idx.write_into(target);
             value.write_into(target);
         }
+
+        assert!(self.updated_words.len() <= u8::MAX as usize, "too many updated storage values");
+        target.write_u8(self.updated_words.len() as u8);
+        for (idx, words) in self.updated_words.iter() {
+            assert!(words.len() <= u8::MAX as usize, "too many words in storage value");
+            idx.write_into(target);
+            target.write_u8(words.len() as u8);
+            words.write_into(target);
+        }
+
+        assert!(self.updated_maps.len() <= u8::MAX as usize, "too many updated storage maps");
+        target.write_u8(self.updated_maps.len() as u8);
+        for (idx, entries) in self.updated_maps.iter() {
+            idx.write_into(target);
+            target.write_u32(entries.len() as u32);
+            for (key, value) in entries.iter() {
+                key.write_into(target);
+                value.write_into(target);
+            }
+        }
+
+        assert!(self.updated_arrays.len() <= u8::MAX as usize, "too many updated storage arrays");
+        target.write_u8(self.updated_arrays.len() as u8);
+        for (idx, items) in self.updated_arrays.iter() {
+            idx.write_into(target);
+            target.write_u32(items.len() as u32);
+            for (item_idx, value) in items.iter() {
+                target.write_u64(*item_idx);
+                value.write_into(target);
+            }
+        }
     }
 }
 
@@ -129,13 +282,6 @@ impl Deserializable for AccountStorageDelta {
             let idx = source.read_u8()?;
             let value = Word::read_from(source)?;
 
-            // make sure index is valid
-            if idx > MAX_MUTABLE_STORAGE_SLOT_IDX {
-                return Err(DeserializationError::InvalidValue(
-                    "immutable storage item updated".to_string(),
-                ));
-            }
-
             // make sure the same item hasn't been updated before
             if updated_items.iter().any(|x| x.0 == idx) {
                 return Err(DeserializationError::InvalidValue(
@@ -153,9 +299,98 @@ impl Deserializable for AccountStorageDelta {
             updated_items.push((idx, value));
         }
 
+        // deserialize and validate words of updated multi-word values
+        let num_updated_words = source.read_u8()? as usize;
+        let mut updated_words: Vec<(u8, Vec<Word>)> = Vec::with_capacity(num_updated_words);
+        for _ in 0..num_updated_words {
+            let idx = source.read_u8()?;
+            let num_words = source.read_u8()? as usize;
+            let words = Word::read_batch_from(source, num_words)?;
+
+            // make sure the words belong to an updated storage item
+            if !updated_items.iter().any(|x| x.0 == idx) {
+                return Err(DeserializationError::InvalidValue(
+                    "words provided for storage item which was not updated".to_string(),
+                ));
+            }
+
+            // make sure the words of the same item haven't been provided before
+            if updated_words.iter().any(|x| x.0 == idx) {
+                return Err(DeserializationError::InvalidValue(
+                    "words of storage item provided more than once".to_string(),
+                ));
+            }
+
+            updated_words.push((idx, words));
+        }
+
+        // deserialize and validate entries of updated maps
+        let num_updated_maps = source.read_u8()? as usize;
+        let mut updated_maps: Vec<(u8, Vec<(Word, Word)>)> = Vec::with_capacity(num_updated_maps);
+        for _ in 0..num_updated_maps {
+            let idx = source.read_u8()?;
+            let num_entries = source.read_u32()? as usize;
+            let mut entries = Vec::with_capacity(num_entries);
+            for _ in 0..num_entries {
+                let key = Word::read_from(source)?;
+                let value = Word::read_from(source)?;
+                entries.push((key, value));
+            }
+
+            // make sure the entries belong to an updated storage item
+            if !updated_items.iter().any(|x| x.0 == idx) {
+                return Err(DeserializationError::InvalidValue(
+                    "map entries provided for storage item which was not updated".to_string(),
+                ));
+            }
+
+            // make sure the entries of the same map haven't been provided before
+            if updated_maps.iter().any(|x| x.0 == idx) {
+                return Err(DeserializationError::InvalidValue(
+                    "map entries of storage item provided more than once".to_string(),
+                ));
+            }
+
+            updated_maps.push((idx, entries));
+        }
+
+        // deserialize and validate items of updated arrays
+        let num_updated_arrays = source.read_u8()? as usize;
+        let mut updated_arrays: Vec<(u8, Vec<(u64, Word)>)> =
+            Vec::with_capacity(num_updated_arrays);
+        for _ in 0..num_updated_arrays {
+            let idx = source.read_u8()?;
+            let num_items = source.read_u32()? as usize;
+            let mut items = Vec::with_capacity(num_items);
+            for _ in 0..num_items {
+                let item_idx = source.read_u64()?;
+                let value = Word::read_from(source)?;
+                items.push((item_idx, value));
+            }
+
+            // make sure the items belong to an updated storage item
+            if !updated_items.iter().any(|x| x.0 == idx) {
+                return Err(DeserializationError::InvalidValue(
+                    "array items provided for storage item which was not updated".to_string(),
+                ));
+            }
+
+            // make sure the items of the same array haven't been provided before
+            if updated_arrays.iter().any(|x| x.0 == idx) {
+                return Err(DeserializationError::InvalidValue(
+                    "array items of storage item provided more than once".to_string(),
+                ));
+            }
+
+            updated_arrays.push((idx, items));
+        }
+
         Ok(Self {
             cleared_items,
             updated_items,
+            updated_words,
+            updated_maps,
+            updated_arrays,
         })
     }
 }
@@ -173,6 +408,9 @@ mod tests {
         let delta = AccountStorageDelta {
             cleared_items: vec![1, 2, 3],
             updated_items: vec![(4, [ONE, ONE, ONE, ONE]), (5, [ONE, ONE, ONE, ZERO])],
+            updated_words: vec![],
+            updated_maps: vec![],
+            updated_arrays: vec![],
         };
         assert!(delta.validate().is_ok());
 
@@ -183,6 +421,9 @@ mod tests {
         let delta = AccountStorageDelta {
             cleared_items: vec![1, 2, 255],
             updated_items: vec![],
+            updated_words: vec![],
+            updated_maps: vec![],
+            updated_arrays: vec![],
         };
         assert!(delta.validate().is_err());
 
@@ -193,21 +434,27 @@ mod tests {
         let delta = AccountStorageDelta {
             cleared_items: vec![1, 2, 1],
             updated_items: vec![],
+            updated_words: vec![],
+            updated_maps: vec![],
+            updated_arrays: vec![],
         };
         assert!(delta.validate().is_err());
 
         let bytes = delta.to_bytes();
         assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
 
-        // invalid index in updated items
+        // slot types commitment in updated items (i.e., initial state of a new account)
         let delta = AccountStorageDelta {
             cleared_items: vec![],
             updated_items: vec![(4, [ONE, ONE, ONE, ONE]), (255, [ONE, ONE, ONE, ZERO])],
+            updated_words: vec![],
+            updated_maps: vec![],
+            updated_arrays: vec![],
         };
-        assert!(delta.validate().is_err());
+        assert!(delta.validate().is_ok());
 
         let bytes = delta.to_bytes();
-        assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
+        assert_eq!(AccountStorageDelta::read_from_bytes(&bytes), Ok(delta));
 
         // duplicate in updated items
         let delta = AccountStorageDelta {
@@ -217,6 +464,9 @@ mod tests {
                 (5, [ONE, ONE, ONE, ZERO]),
                 (4, [ONE, ONE, ZERO, ZERO]),
             ],
+            updated_words: vec![],
+            updated_maps: vec![],
+            updated_arrays: vec![],
         };
         assert!(delta.validate().is_err());
 
@@ -227,10 +477,125 @@ mod tests {
         let delta = AccountStorageDelta {
             cleared_items: vec![1, 2, 3],
             updated_items: vec![(2, [ONE, ONE, ONE, ONE]), (5, [ONE, ONE, ONE, ZERO])],
+            updated_words: vec![],
+            updated_maps: vec![],
+            updated_arrays: vec![],
+        };
+        assert!(delta.validate().is_err());
+
+        let bytes = delta.to_bytes();
+        assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
+
+        // words of updated multi-word values
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![(4, [ONE, ONE, ONE, ONE])],
+            updated_words: vec![(4, vec![[ONE, ZERO, ZERO, ZERO], [ZERO, ONE, ZERO, ZERO]])],
+            updated_maps: vec![],
+            updated_arrays: vec![],
+        };
+        assert!(delta.validate().is_ok());
+
+        let bytes = delta.to_bytes();
+        assert_eq!(AccountStorageDelta::read_from_bytes(&bytes), Ok(delta));
+
+        // words for an item which was not updated
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![(4, [ONE, ONE, ONE, ONE])],
+            updated_words: vec![(5, vec![[ONE, ZERO, ZERO, ZERO]])],
+            updated_maps: vec![],
+            updated_arrays: vec![],
+        };
+        assert!(delta.validate().is_err());
+
+        let bytes = delta.to_bytes();
+        assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
+
+        // duplicate in updated words
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![(4, [ONE, ONE, ONE, ONE])],
+            updated_words: vec![(4, vec![[ONE, ZERO, ZERO, ZERO]]), (4, vec![[ONE; 4]])],
+            updated_maps: vec![],
+            updated_arrays: vec![],
         };
         assert!(delta.validate().is_err());
 
         let bytes = delta.to_bytes();
         assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
+
+        // map entries and array items set in updated items
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![(4, [ONE, ONE, ONE, ONE]), (5, [ONE, ONE, ONE, ZERO])],
+            updated_words: vec![],
+            updated_maps: vec![(4, vec![([ONE, ZERO, ZERO, ZERO], [ONE; 4])])],
+            updated_arrays: vec![(5, vec![(3, [ONE; 4]), (7, [ZERO, ONE, ZERO, ZERO])])],
+        };
+        assert!(delta.validate().is_ok());
+
+        let bytes = delta.to_bytes();
+        assert_eq!(AccountStorageDelta::read_from_bytes(&bytes), Ok(delta));
+
+        // map entries for an item which was not updated
+        let delta = AccountStorageDelta {
+            cleared_items: vec![5],
+            updated_items: vec![(4, [ONE, ONE, ONE, ONE])],
+            updated_words: vec![],
+            updated_maps: vec![(5, vec![([ONE, ZERO, ZERO, ZERO], [ONE; 4])])],
+            updated_arrays: vec![],
+        };
+        assert!(delta.validate().is_err());
+
+        let bytes = delta.to_bytes();
+        assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
+
+        // duplicate in updated arrays
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![(4, [ONE, ONE, ONE, ONE])],
+            updated_words: vec![],
+            updated_maps: vec![],
+            updated_arrays: vec![(4, vec![(3, [ONE; 4])]), (4, vec![(7, [ONE; 4])])],
+        };
+        assert!(delta.validate().is_err());
+
+        let bytes = delta.to_bytes();
+        assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn account_storage_delta_merge_maps_and_arrays() {
+        let delta1 = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![(4, [ONE, ONE, ONE, ONE]), (5, [ONE, ONE, ONE, ZERO])],
+            updated_words: vec![],
+            updated_maps: vec![(4, vec![([ONE, ZERO, ZERO, ZERO], [ONE; 4])])],
+            updated_arrays: vec![(5, vec![(3, [ONE; 4])])],
+        };
+        let delta2 = AccountStorageDelta {
+            cleared_items: vec![5],
+            updated_items: vec![(4, [ZERO, ONE, ONE, ONE])],
+            updated_words: vec![],
+            updated_maps: vec![(
+                4,
+                vec![([ONE, ZERO, ZERO, ZERO], [ZERO; 4]), ([ZERO, ONE, ZERO, ZERO], [ONE; 4])],
+            )],
+            updated_arrays: vec![],
+        };
+
+        let merged = delta1.merge(delta2);
+        assert!(merged.validate().is_ok());
+        assert_eq!(merged.cleared_items, vec![5]);
+        assert_eq!(merged.updated_items, vec![(4, [ZERO, ONE, ONE, ONE])]);
+        assert_eq!(
+            merged.updated_maps,
+            vec![(
+                4,
+                vec![([ONE, ZERO, ZERO, ZERO], [ZERO; 4]), ([ZERO, ONE, ZERO, ZERO], [ONE; 4])]
+            )]
+        );
+        assert!(merged.updated_arrays.is_empty());
     }
 }
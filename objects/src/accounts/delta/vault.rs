@@ -1,6 +1,6 @@
 use super::{
-    AccountDeltaError, Asset, ByteReader, ByteWriter, Deserializable, DeserializationError,
-    Serializable, ToString, Vec,
+    AccountDeltaError, AccountId, Asset, BTreeMap, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, FungibleAsset, Serializable, ToString, Vec,
 };
 
 // ACCOUNT VAULT DELTA
@@ -64,6 +64,70 @@ impl AccountVaultDelta {
     pub fn is_empty(&self) -> bool {
         self.added_assets.is_empty() && self.removed_assets.is_empty()
     }
+
+    /// Merges this vault delta with a subsequent vault delta.
+    ///
+    /// Amounts of fungible assets issued by the same faucet are netted out, and non-fungible
+    /// assets which were added by one delta and removed by the other delta are dropped.
+    ///
+    /// # Errors
+    /// Returns an error if the net amount of any fungible asset exceeds the maximum allowed
+    /// amount.
+    pub fn merge(self, other: Self) -> Result<Self, AccountDeltaError> {
+        let mut fungible_amounts: BTreeMap<AccountId, i128> = BTreeMap::new();
+        let mut added_assets = Vec::new();
+        let mut removed_assets: Vec<Asset> = Vec::new();
+
+        let added = self.added_assets.into_iter().chain(other.added_assets).map(|a| (a, true));
+        let removed =
+            self.removed_assets.into_iter().chain(other.removed_assets).map(|a| (a, false));
+
+        for (asset, is_added) in added.chain(removed) {
+            match asset {
+                Asset::Fungible(asset) => {
+                    let balance = fungible_amounts.entry(asset.faucet_id()).or_default();
+                    if is_added {
+                        *balance += asset.amount() as i128;
+                    } else {
+                        *balance -= asset.amount() as i128;
+                    }
+                }
+                Asset::NonFungible(_) => {
+                    let (same_side, opposite_side) = if is_added {
+                        (&mut added_assets, &mut removed_assets)
+                    } else {
+                        (&mut removed_assets, &mut added_assets)
+                    };
+                    match opposite_side.iter().position(|a| a.is_same(&asset)) {
+                        Some(pos) => {
+                            opposite_side.remove(pos);
+                        }
+                        None => same_side.push(asset),
+                    }
+                }
+            }
+        }
+
+        for (faucet_id, balance) in fungible_amounts.into_iter().filter(|(_, b)| *b != 0) {
+            let amount = u64::try_from(balance.unsigned_abs()).unwrap_or(u64::MAX);
+            let asset: Asset = FungibleAsset::new(faucet_id, amount)
+                .map_err(AccountDeltaError::InvalidFungibleAssetAmount)?
+                .into();
+
+            if balance > 0 {
+                added_assets.push(asset);
+            } else {
+                removed_assets.push(asset);
+            }
+        }
+
+        let delta = Self {
+            added_assets,
+            removed_assets,
+        };
+        delta.validate()?;
+        Ok(delta)
+    }
 }
 
 impl Serializable for AccountVaultDelta {
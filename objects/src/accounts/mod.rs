@@ -18,13 +18,13 @@ mod code;
 pub use code::AccountCode;
 
 pub mod delta;
-pub use delta::{AccountDelta, AccountStorageDelta, AccountVaultDelta};
+pub use delta::{AccountCodeDelta, AccountDelta, AccountStorageDelta, AccountVaultDelta};
 
 mod seed;
 pub use seed::get_account_seed;
 
 mod storage;
-pub use storage::{AccountStorage, SlotItem, StorageSlotType};
+pub use storage::{AccountStorage, SlotItem, StorageArray, StorageMap, StorageSlotType};
 
 mod stub;
 pub use stub::AccountStub;
@@ -179,6 +179,64 @@ impl Account {
     pub fn is_new(&self) -> bool {
         self.nonce == ZERO
     }
+
+    // DATA MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Applies the provided delta to this account and checks that the hash of the resulting
+    /// account is equal to the specified final hash.
+    ///
+    /// The account is updated only if the delta is applied successfully; otherwise, the account
+    /// is left unchanged.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The storage or vault delta cannot be applied to the account.
+    /// - The code of the account was updated, but the delta contains only the root of the new
+    ///   code.
+    /// - The new nonce is not greater than the current nonce of the account.
+    /// - The hash of the resulting account is not equal to the specified final hash.
+    pub fn apply_delta(
+        &mut self,
+        delta: &AccountDelta,
+        final_hash: Digest,
+    ) -> Result<(), AccountError> {
+        let mut account = self.clone();
+
+        account.storage.apply_delta(delta.storage())?;
+        account.vault.apply_delta(delta.vault())?;
+
+        if let Some(code_delta) = delta.code() {
+            match &code_delta.code {
+                Some(code) => account.code = code.clone(),
+                None if code_delta.code_root != account.code.root() => {
+                    return Err(AccountError::AccountCodeNotAvailable(code_delta.code_root))
+                }
+                None => (),
+            }
+        }
+
+        if let Some(nonce) = delta.nonce() {
+            if nonce.as_int() <= account.nonce.as_int() {
+                return Err(AccountError::NonceMustBeMonotonicallyIncreasing(
+                    nonce.as_int(),
+                    account.nonce.as_int(),
+                ));
+            }
+            account.nonce = nonce;
+        }
+
+        let actual = account.hash();
+        if actual != final_hash {
+            return Err(AccountError::FinalAccountHashMismatch {
+                expected: final_hash,
+                actual,
+            });
+        }
+
+        *self = account;
+        Ok(())
+    }
 }
 
 impl ToAdviceInputs for Account {
@@ -195,10 +253,14 @@ impl ToAdviceInputs for Account {
     ///
     /// Pushes the following items into the Merkle store:
     ///  - The Merkle nodes associated with the storage slots tree.
+    ///  - The Merkle nodes associated with the storage maps.
+    ///  - The Merkle nodes associated with the storage arrays.
     ///  - The Merkle nodes associated with the account code procedures tree.
     ///
     /// Pushes the following items into the advice map:
     /// - The storage types commitment -> storage slot types vector
+    /// - The storage map leaf nodes -> (key, value) of the leaf
+    /// - The multi-word storage value commitments -> words of the value
     /// - The account code procedure root -> procedure leaf index
     fn to_advice_inputs<T: AdviceInputsBuilder>(&self, target: &mut T) {
         // push core items onto the stack
@@ -207,14 +269,8 @@ impl ToAdviceInputs for Account {
         target.push_onto_stack(&*self.storage.root());
         target.push_onto_stack(self.code.root().as_elements());
 
-        // extend the merkle store with the storage items
-        target.add_merkle_nodes(self.storage.slots().inner_nodes());
-
-        // extend advice map with storage types commitment -> storage types
-        target.insert_into_map(
-            *self.storage().slot_types_commitment(),
-            self.storage.slot_types().iter().map(Felt::from).collect(),
-        );
+        // extend the advice provider with [AccountStorage] inputs
+        self.storage.to_advice_inputs(target);
 
         // extend the merkle store with account code tree
         target.add_merkle_nodes(self.code.procedure_tree().inner_nodes());
@@ -320,3 +376,82 @@ pub fn hash_account(
     elements[12..].copy_from_slice(&*code_root);
     Hasher::hash_elements(&elements)
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Account, AccountCode, AccountDelta, AccountId, AccountStorage, AccountStorageDelta,
+        AccountType, AccountVault, AccountVaultDelta, Assembler, Asset, BTreeMap, Felt,
+        FungibleAsset, ModuleAst, StorageSlotType, ZERO,
+    };
+    use crate::ONE;
+
+    fn build_account(assets: &[Asset], nonce: Felt, items: [(u8, [Felt; 4]); 2]) -> Account {
+        let id = AccountId::new_dummy([0; 32], AccountType::RegularAccountUpdatableCode);
+
+        let module = ModuleAst::parse("export.foo\n push.1 push.2 mul\nend").unwrap();
+        let code = AccountCode::new(module, &Assembler::default()).unwrap();
+
+        let vault = AccountVault::new(assets).unwrap();
+
+        let slot_type = StorageSlotType::Value { value_arity: 0 };
+        let storage = AccountStorage::new(
+            items.into_iter().map(|(idx, value)| (idx, (slot_type, value))).collect(),
+            BTreeMap::new(),
+            BTreeMap::new(),
+        )
+        .unwrap();
+
+        Account::new(id, vault, storage, code, nonce)
+    }
+
+    #[test]
+    fn account_apply_delta() {
+        let faucet_id = AccountId::new_dummy([1; 32], AccountType::FungibleFaucet);
+        let asset = |amount| -> Asset { FungibleAsset::new(faucet_id, amount).unwrap().into() };
+
+        let mut account = build_account(
+            &[asset(100)],
+            ONE,
+            [(1, [ONE, ONE, ONE, ONE]), (2, [ONE, ZERO, ZERO, ZERO])],
+        );
+        let final_account = build_account(
+            &[asset(70)],
+            Felt::new(2),
+            [(1, [ZERO, ZERO, ZERO, ZERO]), (2, [ZERO, ONE, ZERO, ZERO])],
+        );
+
+        let delta = AccountDelta::new(
+            AccountStorageDelta {
+                cleared_items: vec![1],
+                updated_items: vec![(2, [ZERO, ONE, ZERO, ZERO])],
+                updated_words: vec![],
+                updated_maps: vec![],
+                updated_arrays: vec![],
+            },
+            AccountVaultDelta {
+                added_assets: vec![],
+                removed_assets: vec![asset(30)],
+            },
+            None,
+            Some(Felt::new(2)),
+        )
+        .unwrap();
+
+        // applying the delta with a wrong final hash leaves the account unchanged
+        let initial_account = account.clone();
+        assert!(account.apply_delta(&delta, initial_account.hash()).is_err());
+        assert_eq!(account, initial_account);
+
+        account.apply_delta(&delta, final_account.hash()).unwrap();
+        assert_eq!(account.hash(), final_account.hash());
+        assert_eq!(account.vault().get_balance(faucet_id), Ok(70));
+        assert_eq!(account.nonce(), Felt::new(2));
+
+        // the nonce cannot be decreased
+        assert!(account.apply_delta(&delta, final_account.hash()).is_err());
+    }
+}
@@ -0,0 +1,169 @@
+use super::{
+    slot::{MAX_ARRAY_DEPTH, MIN_ARRAY_DEPTH},
+    AccountError, AdviceInputsBuilder, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, Digest, Serializable, ToAdviceInputs, ToString, Vec, Word,
+};
+use crate::crypto::merkle::{NodeIndex, SimpleSmt};
+
+// STORAGE ARRAY
+// ================================================================================================
+
+/// A sparse array stored in a single account storage slot.
+///
+/// A storage array of depth `n` can hold up to 2^n words addressed by their index. The array is
+/// backed by a simple Sparse Merkle tree of depth `n`, and the root of this tree is the value
+/// stored in the storage slot the array is associated with. Thus, any item of the array can be
+/// authenticated against the account storage root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageArray {
+    items: SimpleSmt,
+}
+
+impl StorageArray {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Value of an item which is not present in the array.
+    pub const EMPTY_VALUE: Word = SimpleSmt::EMPTY_VALUE;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new empty storage array of the specified depth.
+    ///
+    /// # Errors
+    /// Returns an error if the depth is smaller than 2 or greater than 64.
+    pub fn new(depth: u8) -> Result<Self, AccountError> {
+        Self::with_items(depth, [])
+    }
+
+    /// Returns a new storage array of the specified depth instantiated with the provided
+    /// (index, value) items.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The depth is smaller than 2 or greater than 64.
+    /// - Any of the indexes is greater than or equal to 2^depth.
+    /// - The provided items contain duplicate indexes.
+    pub fn with_items<I>(depth: u8, items: I) -> Result<Self, AccountError>
+    where
+        I: IntoIterator<Item = (u64, Word)>,
+    {
+        if !(MIN_ARRAY_DEPTH..=MAX_ARRAY_DEPTH).contains(&depth) {
+            return Err(AccountError::StorageArrayInvalidDepth(depth));
+        }
+
+        let items = items
+            .into_iter()
+            .map(|(idx, value)| {
+                validate_index(depth, idx)?;
+                Ok((idx, value))
+            })
+            .collect::<Result<Vec<_>, AccountError>>()?;
+
+        Ok(Self {
+            items: SimpleSmt::with_leaves(depth, items)
+                .map_err(AccountError::DuplicateStorageArrayItems)?,
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the depth of this array.
+    pub fn depth(&self) -> u8 {
+        self.items.depth()
+    }
+
+    /// Returns the root of this array.
+    pub fn root(&self) -> Digest {
+        self.items.root()
+    }
+
+    /// Returns the item stored at the specified index.
+    ///
+    /// If no item was set at the specified index, [StorageArray::EMPTY_VALUE] is returned.
+    ///
+    /// # Errors
+    /// Returns an error if the index is greater than or equal to 2^depth.
+    pub fn get(&self, index: u64) -> Result<Word, AccountError> {
+        validate_index(self.depth(), index)?;
+        let item_index = NodeIndex::new(self.depth(), index).expect("index is within array bounds");
+        let item = self.items.get_node(item_index).expect("index is within array bounds");
+        Ok(item.into())
+    }
+
+    /// Returns an iterator over the (index, value) items of this array which are not empty.
+    pub fn items(&self) -> impl Iterator<Item = (u64, &Word)> {
+        self.items.leaves().filter(|(_, value)| **value != Self::EMPTY_VALUE)
+    }
+
+    // PUBLIC MODIFIERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the item at the specified index and returns the value which was previously stored at
+    /// this index.
+    ///
+    /// # Errors
+    /// Returns an error if the index is greater than or equal to 2^depth.
+    pub fn set(&mut self, index: u64, value: Word) -> Result<Word, AccountError> {
+        validate_index(self.depth(), index)?;
+        Ok(self.items.update_leaf(index, value).expect("index is within array bounds"))
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for StorageArray {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.depth());
+
+        let items = self.items().collect::<Vec<_>>();
+
+        assert!(items.len() <= u32::MAX as usize, "too many items in the storage array");
+        target.write_u32(items.len() as u32);
+
+        for (idx, value) in items {
+            target.write_u64(idx);
+            value.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for StorageArray {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let depth = source.read_u8()?;
+        let num_items = source.read_u32()? as usize;
+        let mut items = Vec::with_capacity(num_items);
+        for _ in 0..num_items {
+            let idx = source.read_u64()?;
+            let value = Word::read_from(source)?;
+            items.push((idx, value));
+        }
+
+        Self::with_items(depth, items)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+// ADVICE INPUTS INJECTION
+// ================================================================================================
+
+impl ToAdviceInputs for StorageArray {
+    fn to_advice_inputs<T: AdviceInputsBuilder>(&self, target: &mut T) {
+        // extend the merkle store with the array data
+        target.add_merkle_nodes(self.items.inner_nodes());
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Checks that the index can be addressed in an array of the specified depth.
+fn validate_index(depth: u8, index: u64) -> Result<(), AccountError> {
+    if depth < 64 && index >> depth != 0 {
+        return Err(AccountError::StorageArrayIndexOutOfBounds { index, depth });
+    }
+
+    Ok(())
+}
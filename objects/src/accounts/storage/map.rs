@@ -0,0 +1,124 @@
+use super::{
+    AccountError, AdviceInputsBuilder, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, Digest, Serializable, ToAdviceInputs, ToString, Vec, Word,
+};
+use crate::crypto::merkle::TieredSmt;
+
+// STORAGE MAP
+// ================================================================================================
+
+/// A key-value map stored in a single account storage slot.
+///
+/// Both keys and values of the map are words. The map is backed by a Tiered Sparse Merkle tree,
+/// and the root of this tree is the value stored in the storage slot the map is associated with.
+/// Thus, any entry of the map can be authenticated against the account storage root.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StorageMap {
+    map: TieredSmt,
+}
+
+impl StorageMap {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Value of an entry which is not present in the map.
+    pub const EMPTY_VALUE: Word = TieredSmt::EMPTY_VALUE;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new empty storage map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new storage map instantiated with the provided entries.
+    ///
+    /// # Errors
+    /// Returns an error if the provided entries contain duplicate keys.
+    pub fn with_entries<I>(entries: I) -> Result<Self, AccountError>
+    where
+        I: IntoIterator<Item = (Digest, Word)>,
+    {
+        Ok(Self {
+            map: TieredSmt::with_entries(entries).map_err(AccountError::DuplicateStorageMapKeys)?,
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of this map.
+    pub fn root(&self) -> Digest {
+        self.map.root()
+    }
+
+    /// Returns the value associated with the specified key.
+    ///
+    /// If the key is not present in the map, [StorageMap::EMPTY_VALUE] is returned.
+    pub fn get_value(&self, key: Digest) -> Word {
+        self.map.get_value(key)
+    }
+
+    /// Returns an iterator over the (key, value) entries of this map.
+    pub fn entries(&self) -> impl Iterator<Item = &(Digest, Word)> {
+        self.map.iter()
+    }
+
+    // PUBLIC MODIFIERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Inserts the specified value under the specified key and returns the value which was
+    /// previously associated with this key.
+    ///
+    /// Inserting [StorageMap::EMPTY_VALUE] removes the entry from the map.
+    pub fn insert(&mut self, key: Digest, value: Word) -> Word {
+        self.map.insert(key, value)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for StorageMap {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        let entries = self.entries().collect::<Vec<_>>();
+
+        assert!(entries.len() <= u32::MAX as usize, "too many entries in the storage map");
+        target.write_u32(entries.len() as u32);
+
+        for (key, value) in entries {
+            key.write_into(target);
+            value.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for StorageMap {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_entries = source.read_u32()? as usize;
+        let mut entries = Vec::with_capacity(num_entries);
+        for _ in 0..num_entries {
+            let key = Digest::read_from(source)?;
+            let value = Word::read_from(source)?;
+            entries.push((key, value));
+        }
+
+        Self::with_entries(entries)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+// ADVICE INPUTS INJECTION
+// ================================================================================================
+
+impl ToAdviceInputs for StorageMap {
+    fn to_advice_inputs<T: AdviceInputsBuilder>(&self, target: &mut T) {
+        // extend the merkle store with the map data
+        target.add_merkle_nodes(self.map.inner_nodes());
+
+        // populate advice map with tiered merkle tree leaf nodes
+        self.map.upper_leaves().for_each(|(node, key, value)| {
+            target.insert_into_map(*node, (*key).into_iter().chain(value).collect());
+        })
+    }
+}
@@ -1,8 +1,18 @@
 use super::{
-    AccountError, BTreeMap, ByteReader, ByteWriter, Deserializable, DeserializationError, Digest,
-    Felt, Hasher, Serializable, String, ToString, Vec, Word,
+    AccountError, AccountStorageDelta, AdviceInputsBuilder, BTreeMap, ByteReader, ByteWriter,
+    Deserializable, DeserializationError, Digest, Felt, Hasher, Serializable, String,
+    ToAdviceInputs, ToString, Vec, Word,
 };
-use crate::crypto::merkle::{NodeIndex, SimpleSmt};
+use crate::{
+    crypto::merkle::{NodeIndex, SimpleSmt},
+    utils::collections::btree_map::Entry,
+};
+
+mod array;
+pub use array::StorageArray;
+
+mod map;
+pub use map::StorageMap;
 
 mod slot;
 pub use slot::StorageSlotType;
@@ -33,10 +43,20 @@ pub type StorageSlot = (StorageSlotType, Word);
 ///
 /// Storage slots are stored in a simple Sparse Merkle tree of depth 8. Slot 255 is always reserved
 /// and contains information about slot types of all other slots.
+///
+/// For Map slots, the value of the slot is the root of a [StorageMap], and for Array slots, the
+/// value of the slot is the root of a [StorageArray]. Both are kept alongside the slots tree.
+///
+/// For Value slots with arity n > 0, the value of the slot is a commitment to a sequence of n
+/// words (see [AccountStorage::compute_words_commitment()]). The words of such values are also
+/// kept alongside the slots tree whenever they are known.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AccountStorage {
     slots: SimpleSmt,
     types: Vec<StorageSlotType>,
+    maps: BTreeMap<u8, StorageMap>,
+    arrays: BTreeMap<u8, StorageArray>,
+    words: BTreeMap<u8, Vec<Word>>,
 }
 
 impl AccountStorage {
@@ -51,8 +71,33 @@ impl AccountStorage {
 
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
-    /// Returns a new instance of account storage initialized with the provided items.
-    pub fn new(items: Vec<SlotItem>) -> Result<AccountStorage, AccountError> {
+    /// Returns a new instance of account storage initialized with the provided items, maps and
+    /// arrays.
+    ///
+    /// Every slot of Map type must have a corresponding entry in `maps` whose root is equal to the
+    /// value of the slot. Similarly, every slot of Array type must have a corresponding entry in
+    /// `arrays` of the same depth and with the root equal to the value of the slot. The only
+    /// exception are slots set to the root of an empty map or array, for which an empty
+    /// [StorageMap] or [StorageArray] is created.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the items is at the reserved slot 255.
+    /// - The provided items contain duplicate slot indexes.
+    /// - A map is provided for a slot which is not of Map type.
+    /// - An array is provided for a slot which is not of Array type.
+    /// - A slot of Map type has no map associated with it, or the root of the associated map does
+    ///   not match the value of the slot.
+    /// - A slot of Array type has no array associated with it, or the depth or the root of the
+    ///   associated array does not match the type or the value of the slot.
+    ///
+    /// Words of multi-word values can be provided via [AccountStorage::set_item_words()] once the
+    /// storage has been created.
+    pub fn new(
+        items: Vec<SlotItem>,
+        mut maps: BTreeMap<u8, StorageMap>,
+        mut arrays: BTreeMap<u8, StorageArray>,
+    ) -> Result<AccountStorage, AccountError> {
         // initialize slot types vector
         let mut types = vec![StorageSlotType::default(); 256];
 
@@ -74,6 +119,45 @@ impl AccountStorage {
             })
             .collect::<Result<Vec<_>, AccountError>>()?;
 
+        // make sure all provided maps are associated with map slots
+        if let Some((&idx, _)) = maps.iter().find(|(&idx, _)| !types[idx as usize].is_map()) {
+            return Err(AccountError::StorageSlotNotMap(idx));
+        }
+
+        // make sure all provided arrays are associated with array slots
+        if let Some((&idx, _)) = arrays.iter().find(|(&idx, _)| !types[idx as usize].is_array()) {
+            return Err(AccountError::StorageSlotNotArray(idx));
+        }
+
+        // make sure all map and array slots have consistent data structures associated with them
+        for (idx, slot_value) in entires.iter() {
+            let idx = *idx as u8;
+            match types[idx as usize] {
+                StorageSlotType::Map { .. } => {
+                    let map = maps.entry(idx).or_default();
+                    if *map.root() != *slot_value {
+                        return Err(AccountError::StorageMapRootMismatch(idx));
+                    }
+                }
+                StorageSlotType::Array { depth, .. } => {
+                    let array = match arrays.entry(idx) {
+                        Entry::Occupied(entry) => entry.into_mut(),
+                        Entry::Vacant(entry) => entry.insert(StorageArray::new(depth)?),
+                    };
+                    if array.depth() != depth {
+                        return Err(AccountError::StorageArrayDepthMismatch {
+                            expected: depth,
+                            actual: array.depth(),
+                        });
+                    }
+                    if *array.root() != *slot_value {
+                        return Err(AccountError::StorageArrayRootMismatch(idx));
+                    }
+                }
+                StorageSlotType::Value { .. } => (),
+            }
+        }
+
         // add slot types commitment entry
         entires.push((
             Self::SLOT_TYPES_COMMITMENT_INDEX as u64,
@@ -84,7 +168,13 @@ impl AccountStorage {
         let slots = SimpleSmt::with_leaves(Self::STORAGE_TREE_DEPTH, entires)
             .map_err(AccountError::DuplicateStorageItems)?;
 
-        Ok(Self { slots, types })
+        Ok(Self {
+            slots,
+            types,
+            maps,
+            arrays,
+            words: BTreeMap::new(),
+        })
     }
 
     // PUBLIC ACCESSORS
@@ -104,6 +194,64 @@ impl AccountStorage {
         self.slots.get_node(item_index).expect("index is u8 - index within range")
     }
 
+    /// Returns the value associated with the specified key in the map stored at the specified
+    /// index.
+    ///
+    /// If the key is not present in the map, [StorageMap::EMPTY_VALUE] is returned.
+    ///
+    /// # Errors
+    /// Returns an error if the slot at the specified index is not of Map type.
+    pub fn get_map_item(&self, index: u8, key: Word) -> Result<Word, AccountError> {
+        self.maps
+            .get(&index)
+            .map(|map| map.get_value(key.into()))
+            .ok_or(AccountError::StorageSlotNotMap(index))
+    }
+
+    /// Returns a reference to the map stored at the specified index, or None if the slot at the
+    /// specified index is not of Map type.
+    pub fn get_map(&self, index: u8) -> Option<&StorageMap> {
+        self.maps.get(&index)
+    }
+
+    /// Returns a reference to the maps of this storage keyed by the index of their slot.
+    pub fn maps(&self) -> &BTreeMap<u8, StorageMap> {
+        &self.maps
+    }
+
+    /// Returns the item at the specified position of the array stored at the specified index.
+    ///
+    /// If no item was set at the specified position, [StorageArray::EMPTY_VALUE] is returned.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The slot at the specified index is not of Array type.
+    /// - The item index is greater than or equal to the capacity of the array.
+    pub fn get_array_item(&self, index: u8, item_index: u64) -> Result<Word, AccountError> {
+        self.arrays
+            .get(&index)
+            .ok_or(AccountError::StorageSlotNotArray(index))?
+            .get(item_index)
+    }
+
+    /// Returns a reference to the array stored at the specified index, or None if the slot at the
+    /// specified index is not of Array type.
+    pub fn get_array(&self, index: u8) -> Option<&StorageArray> {
+        self.arrays.get(&index)
+    }
+
+    /// Returns a reference to the arrays of this storage keyed by the index of their slot.
+    pub fn arrays(&self) -> &BTreeMap<u8, StorageArray> {
+        &self.arrays
+    }
+
+    /// Returns the words of the multi-word value stored at the specified index, or None if the
+    /// slot at the specified index is not a Value slot with arity greater than 0 or the words of
+    /// the value are not known.
+    pub fn get_item_words(&self, index: u8) -> Option<&[Word]> {
+        self.words.get(&index).map(|words| words.as_slice())
+    }
+
     /// Returns a reference to the sparse Merkle tree that backs the storage slots.
     pub fn slots(&self) -> &SimpleSmt {
         &self.slots
@@ -127,11 +275,221 @@ impl AccountStorage {
     // PUBLIC MODIFIERS
     // --------------------------------------------------------------------------------------------
     /// Sets an item from the storage at the specified index.
+    ///
+    /// If the slot contains a multi-word value, the words of the value which were previously set
+    /// via [AccountStorage::set_item_words()] are discarded.
     pub fn set_item(&mut self, index: u8, value: Word) -> Word {
+        self.words.remove(&index);
         self.slots
             .update_leaf(index as u64, value)
             .expect("index is u8 - index within range")
     }
+
+    /// Sets the value associated with the specified key in the map stored at the specified index
+    /// and updates the slot with the new root of the map.
+    ///
+    /// Returns the value which was previously associated with the key.
+    ///
+    /// # Errors
+    /// Returns an error if the slot at the specified index is not of Map type.
+    pub fn set_map_item(
+        &mut self,
+        index: u8,
+        key: Word,
+        value: Word,
+    ) -> Result<Word, AccountError> {
+        let map = self.maps.get_mut(&index).ok_or(AccountError::StorageSlotNotMap(index))?;
+        let old_value = map.insert(key.into(), value);
+        let new_root = map.root();
+        self.set_item(index, *new_root);
+
+        Ok(old_value)
+    }
+
+    /// Sets the item at the specified position of the array stored at the specified index and
+    /// updates the slot with the new root of the array.
+    ///
+    /// Returns the item which was previously stored at the specified position.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The slot at the specified index is not of Array type.
+    /// - The item index is greater than or equal to the capacity of the array.
+    pub fn set_array_item(
+        &mut self,
+        index: u8,
+        item_index: u64,
+        value: Word,
+    ) -> Result<Word, AccountError> {
+        let array = self.arrays.get_mut(&index).ok_or(AccountError::StorageSlotNotArray(index))?;
+        let old_value = array.set(item_index, value)?;
+        let new_root = array.root();
+        self.set_item(index, *new_root);
+
+        Ok(old_value)
+    }
+
+    /// Sets the words of the multi-word value stored at the specified index and updates the slot
+    /// with the commitment to these words.
+    ///
+    /// Returns the value which was previously stored in the slot.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The slot at the specified index is not a Value slot with arity greater than 0.
+    /// - The number of words is not equal to the arity of the slot.
+    pub fn set_item_words(&mut self, index: u8, words: Vec<Word>) -> Result<Word, AccountError> {
+        let value_arity = match self.types[index as usize] {
+            StorageSlotType::Value { value_arity }
+                if value_arity > 0 && index != Self::SLOT_TYPES_COMMITMENT_INDEX =>
+            {
+                value_arity
+            }
+            _ => return Err(AccountError::StorageSlotNotMultiWordValue(index)),
+        };
+
+        if words.len() != value_arity as usize {
+            return Err(AccountError::InvalidStorageItemWordsCount {
+                expected: value_arity,
+                actual: words.len(),
+            });
+        }
+
+        let commitment = Self::compute_words_commitment(&words);
+        let old_value = self.set_item(index, *commitment);
+        self.words.insert(index, words);
+
+        Ok(old_value)
+    }
+
+    /// Applies the provided storage delta to this storage.
+    ///
+    /// The map entries and the array items set by the delta are applied to the maps and arrays
+    /// of the corresponding slots. Then, cleared items are set to an empty word, and updated items
+    /// are set to their new values. The words of multi-word values provided by the delta are
+    /// stored alongside the updated items.
+    ///
+    /// If an error is returned, the storage may be left partially updated.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Map entries or array items are provided for a slot which is not of Map or Array type
+    ///   respectively, or an array item index is out of bounds.
+    /// - A Map or an Array slot is cleared or updated to a value which is not equal to the root of
+    ///   the map or the array stored in this slot after the entries or items of the delta have
+    ///   been applied.
+    /// - The words provided for a multi-word value do not match the new value of the slot, or the
+    ///   slot is not a Value slot with arity greater than 0.
+    /// - The slot types commitment is updated to a value different from its current value.
+    pub fn apply_delta(&mut self, delta: &AccountStorageDelta) -> Result<(), AccountError> {
+        // make sure the words of multi-word values match the new values of their slots
+        for (idx, words) in delta.updated_words.iter() {
+            let value = delta.updated_items.iter().find(|x| x.0 == *idx).map(|x| x.1);
+            if value != Some(*Self::compute_words_commitment(words)) {
+                return Err(AccountError::StorageItemWordsMismatch(*idx));
+            }
+        }
+
+        // replay the entries set in storage maps
+        for (idx, entries) in delta.updated_maps.iter() {
+            let map = self.maps.get_mut(idx).ok_or(AccountError::StorageSlotNotMap(*idx))?;
+            for (key, value) in entries.iter() {
+                map.insert((*key).into(), *value);
+            }
+        }
+
+        // replay the items set in storage arrays
+        for (idx, items) in delta.updated_arrays.iter() {
+            let array = self.arrays.get_mut(idx).ok_or(AccountError::StorageSlotNotArray(*idx))?;
+            for (item_idx, value) in items.iter() {
+                array.set(*item_idx, *value)?;
+            }
+        }
+
+        let cleared_items = delta.cleared_items.iter().map(|&idx| (idx, Word::default()));
+        let updated_items = delta.updated_items.iter().copied();
+
+        for (idx, value) in cleared_items.chain(updated_items) {
+            // the slot types commitment is immutable and can be included into the delta only to
+            // describe the initial state of the account
+            if idx == Self::SLOT_TYPES_COMMITMENT_INDEX && value != *self.get_item(idx) {
+                return Err(AccountError::StorageSlotIsReserved(idx));
+            }
+
+            match self.types[idx as usize] {
+                StorageSlotType::Value { .. } => (),
+                StorageSlotType::Map { .. } => {
+                    let map = self.maps.get(&idx).ok_or(AccountError::StorageSlotNotMap(idx))?;
+                    if *map.root() != value {
+                        return Err(AccountError::StorageMapRootMismatch(idx));
+                    }
+                }
+                StorageSlotType::Array { .. } => {
+                    let array =
+                        self.arrays.get(&idx).ok_or(AccountError::StorageSlotNotArray(idx))?;
+                    if *array.root() != value {
+                        return Err(AccountError::StorageArrayRootMismatch(idx));
+                    }
+                }
+            }
+
+            self.set_item(idx, value);
+        }
+
+        for (idx, words) in delta.updated_words.iter() {
+            self.set_item_words(*idx, words.clone())?;
+        }
+
+        Ok(())
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a commitment to the provided words of a multi-word value.
+    ///
+    /// The commitment is computed as a sequential hash of the words. If the number of words is
+    /// odd, the words are padded with an empty word.
+    pub fn compute_words_commitment(words: &[Word]) -> Digest {
+        Hasher::hash_elements(&words_to_padded_elements(words))
+    }
+}
+
+// ADVICE INPUTS INJECTION
+// ================================================================================================
+
+impl ToAdviceInputs for AccountStorage {
+    /// Pushes the following items into the advice inputs target:
+    /// - The Merkle nodes associated with the storage slots tree.
+    /// - The storage types commitment -> storage slot types vector.
+    /// - The Merkle nodes and leaves of all storage maps.
+    /// - The Merkle nodes of all storage arrays.
+    /// - The multi-word value commitments -> words of the values.
+    fn to_advice_inputs<T: AdviceInputsBuilder>(&self, target: &mut T) {
+        // extend the merkle store with the storage items
+        target.add_merkle_nodes(self.slots.inner_nodes());
+
+        // extend advice map with storage types commitment -> storage types
+        target.insert_into_map(
+            *self.slot_types_commitment(),
+            self.types.iter().map(Felt::from).collect(),
+        );
+
+        // extend the advice provider with the data of all storage maps
+        for map in self.maps.values() {
+            map.to_advice_inputs(target);
+        }
+
+        // extend the merkle store with the data of all storage arrays
+        for array in self.arrays.values() {
+            array.to_advice_inputs(target);
+        }
+
+        // extend advice map with multi-word value commitments -> words
+        for (&idx, words) in self.words.iter() {
+            target.insert_into_map(*self.get_item(idx), words.iter().flatten().copied().collect());
+        }
+    }
 }
 
 // SERIALIZATION
@@ -171,6 +529,40 @@ impl Serializable for AccountStorage {
             target.write_u8(idx as u8);
             target.write(value);
         }
+
+        // serialize storage maps; we skip empty maps as they can be re-created from slot types
+        let filled_maps = self
+            .maps
+            .iter()
+            .filter(|(_, map)| map.root() != StorageMap::new().root())
+            .collect::<Vec<_>>();
+
+        target.write_u8(filled_maps.len() as u8);
+        for (&idx, map) in filled_maps {
+            target.write_u8(idx);
+            map.write_into(target);
+        }
+
+        // serialize storage arrays; we skip empty arrays as they can be re-created from slot types
+        let filled_arrays = self
+            .arrays
+            .iter()
+            .filter(|(_, array)| array.items().next().is_some())
+            .collect::<Vec<_>>();
+
+        target.write_u8(filled_arrays.len() as u8);
+        for (&idx, array) in filled_arrays {
+            target.write_u8(idx);
+            array.write_into(target);
+        }
+
+        // serialize words of multi-word values
+        target.write_u8(self.words.len() as u8);
+        for (&idx, words) in self.words.iter() {
+            target.write_u8(idx);
+            target.write_u8(words.len() as u8);
+            words.write_into(target);
+        }
     }
 }
 
@@ -196,52 +588,388 @@ impl Deserializable for AccountStorage {
             items.push((idx, (slot_type, slot_value)));
         }
 
-        Self::new(items).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+        // read storage maps
+        let mut maps = BTreeMap::new();
+        let num_maps = source.read_u8()?;
+        for _ in 0..num_maps {
+            let idx = source.read_u8()?;
+            let map = StorageMap::read_from(source)?;
+            maps.insert(idx, map);
+        }
+
+        // read storage arrays
+        let mut arrays = BTreeMap::new();
+        let num_arrays = source.read_u8()?;
+        for _ in 0..num_arrays {
+            let idx = source.read_u8()?;
+            let array = StorageArray::read_from(source)?;
+            arrays.insert(idx, array);
+        }
+
+        let mut storage = Self::new(items, maps, arrays)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+        // read words of multi-word values and make sure they match the commitments in the slots
+        let num_values = source.read_u8()?;
+        for _ in 0..num_values {
+            let idx = source.read_u8()?;
+            let num_words = source.read_u8()? as usize;
+            let words = Word::read_batch_from(source, num_words)?;
+
+            let commitment = storage.get_item(idx);
+            storage
+                .set_item_words(idx, words)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+            if storage.get_item(idx) != commitment {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "words of the value at slot {idx} do not match the slot commitment"
+                )));
+            }
+        }
+
+        Ok(storage)
     }
 }
 
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the elements of the provided words padded with an empty word if the number of words is
+/// odd. This ensures the number of elements is a multiple of 8 - the size of the hasher rate.
+fn words_to_padded_elements(words: &[Word]) -> Vec<Felt> {
+    let mut elements = words.iter().flatten().copied().collect::<Vec<_>>();
+    if words.len() % 2 == 1 {
+        elements.extend_from_slice(&Word::default());
+    }
+    elements
+}
+
 // TESTS
 // ================================================================================================
 
 #[cfg(test)]
 mod tests {
-    use super::{AccountStorage, Deserializable, Serializable, StorageSlotType};
-    use crate::{ONE, ZERO};
+    use super::{
+        AccountStorage, AccountStorageDelta, BTreeMap, Deserializable, Hasher, Serializable,
+        StorageArray, StorageMap, StorageSlotType,
+    };
+    use crate::{Felt, ONE, ZERO};
 
     #[test]
     fn account_storage_serialization() {
         // empty storage
-        let storage = AccountStorage::new(Vec::new()).unwrap();
+        let storage = AccountStorage::new(Vec::new(), BTreeMap::new(), BTreeMap::new()).unwrap();
         let bytes = storage.to_bytes();
         assert_eq!(storage, AccountStorage::read_from_bytes(&bytes).unwrap());
 
         // storage with values for default types
-        let storage = AccountStorage::new(vec![
-            (0, (StorageSlotType::default(), [ONE, ONE, ONE, ONE])),
-            (2, (StorageSlotType::default(), [ONE, ONE, ONE, ZERO])),
-        ])
+        let storage = AccountStorage::new(
+            vec![
+                (0, (StorageSlotType::default(), [ONE, ONE, ONE, ONE])),
+                (2, (StorageSlotType::default(), [ONE, ONE, ONE, ZERO])),
+            ],
+            BTreeMap::new(),
+            BTreeMap::new(),
+        )
         .unwrap();
         let bytes = storage.to_bytes();
         assert_eq!(storage, AccountStorage::read_from_bytes(&bytes).unwrap());
 
         // storage with a mix of types
-        let storage = AccountStorage::new(vec![
-            (0, (StorageSlotType::Value { value_arity: 1 }, [ONE, ONE, ONE, ONE])),
-            (1, (StorageSlotType::Value { value_arity: 0 }, [ONE, ONE, ONE, ZERO])),
-            (2, (StorageSlotType::Map { value_arity: 2 }, [ONE, ONE, ZERO, ZERO])),
-            (
-                3,
+        let map = StorageMap::with_entries([
+            ([ONE, ONE, ZERO, ZERO].into(), [ONE, ZERO, ONE, ZERO]),
+            ([ONE, ONE, ONE, ZERO].into(), [ZERO, ZERO, ONE, ONE]),
+        ])
+        .unwrap();
+        let array = StorageArray::with_items(
+            4,
+            [(3, [ONE, ZERO, ZERO, ZERO]), (15, [ZERO, ONE, ONE, ZERO])],
+        )
+        .unwrap();
+        let storage = AccountStorage::new(
+            vec![
+                (0, (StorageSlotType::Value { value_arity: 1 }, [ONE, ONE, ONE, ONE])),
+                (1, (StorageSlotType::Value { value_arity: 0 }, [ONE, ONE, ONE, ZERO])),
+                (2, (StorageSlotType::Map { value_arity: 0 }, *map.root())),
                 (
-                    StorageSlotType::Array {
-                        depth: 4,
-                        value_arity: 3,
-                    },
-                    [ONE, ZERO, ZERO, ZERO],
+                    3,
+                    (
+                        StorageSlotType::Array {
+                            depth: 4,
+                            value_arity: 3,
+                        },
+                        *array.root(),
+                    ),
                 ),
-            ),
-        ])
+                (4, (StorageSlotType::Map { value_arity: 0 }, *StorageMap::new().root())),
+                (
+                    5,
+                    (
+                        StorageSlotType::Array {
+                            depth: 8,
+                            value_arity: 0,
+                        },
+                        *StorageArray::new(8).unwrap().root(),
+                    ),
+                ),
+            ],
+            BTreeMap::from([(2, map)]),
+            BTreeMap::from([(3, array)]),
+        )
         .unwrap();
         let bytes = storage.to_bytes();
         assert_eq!(storage, AccountStorage::read_from_bytes(&bytes).unwrap());
+
+        // storage with words of a multi-word value
+        let mut storage = storage;
+        storage.set_item_words(0, vec![[ONE, ZERO, ONE, ZERO]]).unwrap();
+        let bytes = storage.to_bytes();
+        assert_eq!(storage, AccountStorage::read_from_bytes(&bytes).unwrap());
+    }
+
+    #[test]
+    fn account_storage_maps() {
+        let key = [ONE, ONE, ZERO, ZERO];
+        let value = [Felt::new(3), Felt::new(4), Felt::new(5), Felt::new(6)];
+        let map = StorageMap::with_entries([(key.into(), value)]).unwrap();
+
+        // map root must match the slot value
+        assert!(AccountStorage::new(
+            vec![(0, (StorageSlotType::Map { value_arity: 0 }, [ONE, ONE, ZERO, ZERO]))],
+            BTreeMap::from([(0, map.clone())]),
+            BTreeMap::new(),
+        )
+        .is_err());
+
+        // maps can be provided only for map slots
+        assert!(AccountStorage::new(
+            vec![(0, (StorageSlotType::Value { value_arity: 0 }, *map.root()))],
+            BTreeMap::from([(0, map.clone())]),
+            BTreeMap::new(),
+        )
+        .is_err());
+
+        let mut storage = AccountStorage::new(
+            vec![
+                (0, (StorageSlotType::Map { value_arity: 0 }, *map.root())),
+                (1, (StorageSlotType::Value { value_arity: 0 }, [ONE, ONE, ONE, ONE])),
+            ],
+            BTreeMap::from([(0, map)]),
+            BTreeMap::new(),
+        )
+        .unwrap();
+
+        assert_eq!(storage.get_map_item(0, key).unwrap(), value);
+        assert_eq!(
+            storage.get_map_item(0, [ZERO, ONE, ZERO, ONE]).unwrap(),
+            StorageMap::EMPTY_VALUE
+        );
+        assert!(storage.get_map_item(1, key).is_err());
+
+        // update an existing entry and make sure the slot value tracks the map root
+        let new_value = [Felt::new(7), Felt::new(8), Felt::new(9), Felt::new(10)];
+        let init_root = storage.root();
+        assert_eq!(storage.set_map_item(0, key, new_value).unwrap(), value);
+        assert_eq!(storage.get_map_item(0, key).unwrap(), new_value);
+        assert_eq!(storage.get_item(0), storage.get_map(0).unwrap().root());
+        assert_ne!(storage.root(), init_root);
+        assert!(storage.set_map_item(1, key, new_value).is_err());
+    }
+
+    #[test]
+    fn account_storage_arrays() {
+        let value = [Felt::new(3), Felt::new(4), Felt::new(5), Felt::new(6)];
+        let array = StorageArray::with_items(4, [(5, value)]).unwrap();
+        let array_type = StorageSlotType::Array {
+            depth: 4,
+            value_arity: 0,
+        };
+
+        // items must be within the array capacity
+        assert!(StorageArray::with_items(4, [(16, value)]).is_err());
+
+        // array depth must match the depth of the slot type
+        assert!(AccountStorage::new(
+            vec![(
+                0,
+                (
+                    StorageSlotType::Array {
+                        depth: 5,
+                        value_arity: 0
+                    },
+                    *array.root()
+                )
+            )],
+            BTreeMap::new(),
+            BTreeMap::from([(0, array.clone())]),
+        )
+        .is_err());
+
+        // array root must match the slot value
+        assert!(AccountStorage::new(
+            vec![(0, (array_type, [ONE, ONE, ZERO, ZERO]))],
+            BTreeMap::new(),
+            BTreeMap::from([(0, array.clone())]),
+        )
+        .is_err());
+
+        // arrays can be provided only for array slots
+        assert!(AccountStorage::new(
+            vec![(0, (StorageSlotType::Map { value_arity: 0 }, *array.root()))],
+            BTreeMap::new(),
+            BTreeMap::from([(0, array.clone())]),
+        )
+        .is_err());
+
+        let mut storage = AccountStorage::new(
+            vec![
+                (0, (array_type, *array.root())),
+                (1, (StorageSlotType::Value { value_arity: 0 }, [ONE, ONE, ONE, ONE])),
+            ],
+            BTreeMap::new(),
+            BTreeMap::from([(0, array)]),
+        )
+        .unwrap();
+
+        assert_eq!(storage.get_array_item(0, 5).unwrap(), value);
+        assert_eq!(storage.get_array_item(0, 6).unwrap(), StorageArray::EMPTY_VALUE);
+        assert!(storage.get_array_item(0, 16).is_err());
+        assert!(storage.get_array_item(1, 5).is_err());
+
+        // update an existing item and make sure the slot value tracks the array root
+        let new_value = [Felt::new(7), Felt::new(8), Felt::new(9), Felt::new(10)];
+        let init_root = storage.root();
+        assert_eq!(storage.set_array_item(0, 5, new_value).unwrap(), value);
+        assert_eq!(storage.get_array_item(0, 5).unwrap(), new_value);
+        assert_eq!(storage.get_item(0), storage.get_array(0).unwrap().root());
+        assert_ne!(storage.root(), init_root);
+        assert!(storage.set_array_item(0, 16, new_value).is_err());
+        assert!(storage.set_array_item(1, 5, new_value).is_err());
+    }
+
+    #[test]
+    fn account_storage_item_words() {
+        let words = vec![[ONE, ONE, ZERO, ZERO], [ZERO, ONE, ZERO, ONE], [Felt::new(3); 4]];
+        let commitment = AccountStorage::compute_words_commitment(&words);
+
+        let mut storage = AccountStorage::new(
+            vec![
+                (0, (StorageSlotType::Value { value_arity: 3 }, [ONE, ONE, ONE, ONE])),
+                (1, (StorageSlotType::Value { value_arity: 0 }, [ONE, ONE, ONE, ONE])),
+            ],
+            BTreeMap::new(),
+            BTreeMap::new(),
+        )
+        .unwrap();
+
+        // words of a value are not known until they are set
+        assert!(storage.get_item_words(0).is_none());
+
+        // words can be set only for value slots with arity greater than 0
+        assert!(storage.set_item_words(1, words.clone()).is_err());
+        assert!(storage
+            .set_item_words(AccountStorage::SLOT_TYPES_COMMITMENT_INDEX, words.clone())
+            .is_err());
+
+        // the number of words must match the arity of the slot
+        assert!(storage.set_item_words(0, words[..2].to_vec()).is_err());
+
+        assert_eq!(storage.set_item_words(0, words.clone()).unwrap(), [ONE, ONE, ONE, ONE]);
+        assert_eq!(storage.get_item_words(0).unwrap(), words.as_slice());
+        assert_eq!(storage.get_item(0), commitment);
+
+        // an odd number of words is padded with an empty word
+        let mut elements = words.iter().flatten().copied().collect::<Vec<_>>();
+        elements.extend_from_slice(&[ZERO; 4]);
+        assert_eq!(commitment, Hasher::hash_elements(&elements));
+    }
+
+    #[test]
+    fn account_storage_apply_delta_maps_and_arrays() {
+        let key = [ONE, ONE, ZERO, ZERO];
+        let value = [Felt::new(3), Felt::new(4), Felt::new(5), Felt::new(6)];
+        let map = StorageMap::with_entries([(key.into(), value)]).unwrap();
+        let array = StorageArray::with_items(4, [(5, value)]).unwrap();
+        let array_type = StorageSlotType::Array {
+            depth: 4,
+            value_arity: 0,
+        };
+
+        let storage = AccountStorage::new(
+            vec![
+                (0, (StorageSlotType::Map { value_arity: 0 }, *map.root())),
+                (1, (array_type, *array.root())),
+            ],
+            BTreeMap::from([(0, map)]),
+            BTreeMap::from([(1, array)]),
+        )
+        .unwrap();
+
+        // compute the expected state of the storage
+        let new_key = [ZERO, ONE, ZERO, ONE];
+        let new_value = [Felt::new(7), Felt::new(8), Felt::new(9), Felt::new(10)];
+        let mut expected = storage.clone();
+        expected.set_map_item(0, key, new_value).unwrap();
+        expected.set_map_item(0, new_key, value).unwrap();
+        expected.set_array_item(1, 9, new_value).unwrap();
+
+        // the map entries and the array items of the delta are replayed on the storage
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![(0, *expected.get_item(0)), (1, *expected.get_item(1))],
+            updated_words: vec![],
+            updated_maps: vec![(0, vec![(key, new_value), (new_key, value)])],
+            updated_arrays: vec![(1, vec![(9, new_value)])],
+        };
+        let mut updated = storage.clone();
+        updated.apply_delta(&delta).unwrap();
+        assert_eq!(updated, expected);
+        assert_eq!(updated.get_map_item(0, new_key).unwrap(), value);
+        assert_eq!(updated.get_array_item(1, 9).unwrap(), new_value);
+
+        // the roots must match the values of the updated items
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![(0, *expected.get_item(0))],
+            updated_words: vec![],
+            updated_maps: vec![(0, vec![(key, new_value)])],
+            updated_arrays: vec![],
+        };
+        assert!(storage.clone().apply_delta(&delta).is_err());
+
+        // map entries can be provided only for map slots
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![(1, *expected.get_item(1))],
+            updated_words: vec![],
+            updated_maps: vec![(1, vec![(key, new_value)])],
+            updated_arrays: vec![],
+        };
+        assert!(storage.clone().apply_delta(&delta).is_err());
+
+        // the slot types commitment can be included only with its current value
+        let slot_types_commitment = *storage.get_item(AccountStorage::SLOT_TYPES_COMMITMENT_INDEX);
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![(
+                AccountStorage::SLOT_TYPES_COMMITMENT_INDEX,
+                slot_types_commitment,
+            )],
+            updated_words: vec![],
+            updated_maps: vec![],
+            updated_arrays: vec![],
+        };
+        let mut updated = storage.clone();
+        updated.apply_delta(&delta).unwrap();
+        assert_eq!(updated, storage);
+
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![(AccountStorage::SLOT_TYPES_COMMITMENT_INDEX, [ONE; 4])],
+            updated_words: vec![],
+            updated_maps: vec![],
+            updated_arrays: vec![],
+        };
+        assert!(storage.clone().apply_delta(&delta).is_err());
     }
 }
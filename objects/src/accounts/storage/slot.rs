@@ -5,8 +5,8 @@ use super::{Felt, String, ToString};
 
 const MAX_VALUE_ARITY: u8 = u8::MAX - 1;
 
-const MIN_ARRAY_DEPTH: u8 = 2;
-const MAX_ARRAY_DEPTH: u8 = 64;
+pub(super) const MIN_ARRAY_DEPTH: u8 = 2;
+pub(super) const MAX_ARRAY_DEPTH: u8 = 64;
 
 const DEFAULT_SLOT_TYPE: StorageSlotType = StorageSlotType::Value { value_arity: 0 };
 
@@ -43,6 +43,16 @@ impl StorageSlotType {
         }
     }
 
+    /// Returns true if this storage slot type is a map type.
+    pub fn is_map(&self) -> bool {
+        matches!(self, StorageSlotType::Map { .. })
+    }
+
+    /// Returns true if this storage slot type is an array type.
+    pub fn is_array(&self) -> bool {
+        matches!(self, StorageSlotType::Array { .. })
+    }
+
     /// Returns true if this storage slot type is a value type with arity 0.
     pub fn is_default(&self) -> bool {
         match self {
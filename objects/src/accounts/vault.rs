@@ -1,7 +1,7 @@
 use super::{
-    AccountError, AccountId, AccountType, AdviceInputsBuilder, Asset, ByteReader, ByteWriter,
-    Deserializable, DeserializationError, Digest, FungibleAsset, NonFungibleAsset, Serializable,
-    TieredSmt, ToAdviceInputs, ToString, Vec, ZERO,
+    AccountError, AccountId, AccountType, AccountVaultDelta, AdviceInputsBuilder, Asset,
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, FungibleAsset,
+    NonFungibleAsset, Serializable, TieredSmt, ToAdviceInputs, ToString, Vec, ZERO,
 };
 
 // ACCOUNT VAULT
@@ -202,6 +202,28 @@ impl AccountVault {
         // return the asset that was removed.
         Ok(asset)
     }
+
+    // APPLY DELTA
+    // --------------------------------------------------------------------------------------------
+    /// Applies the provided vault delta to this vault.
+    ///
+    /// Removed assets are removed from the vault first, and then added assets are added to it.
+    /// For fungible assets, this means that the amounts are subtracted from or added to the
+    /// balances of the respective faucets.
+    ///
+    /// # Errors
+    /// Returns an error if any of the assets cannot be removed from or added to the vault.
+    pub fn apply_delta(&mut self, delta: &AccountVaultDelta) -> Result<(), AccountError> {
+        for &asset in delta.removed_assets.iter() {
+            self.remove_asset(asset)?;
+        }
+
+        for &asset in delta.added_assets.iter() {
+            self.add_asset(asset)?;
+        }
+
+        Ok(())
+    }
 }
 
 // SERIALIZATION
@@ -0,0 +1,361 @@
+use super::{Digest, Vec};
+use crate::{
+    accounts::{Account, AccountId},
+    crypto::merkle::{MerklePath, NodeIndex, SimpleSmt},
+    transaction::ProvenTransaction,
+    utils::collections::BTreeMap,
+    BlockError, Word, EMPTY_WORD,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The depth of the account tree. Each account ID is used directly as the index of its leaf.
+pub const ACCOUNT_TREE_DEPTH: u8 = 64;
+
+// ACCOUNT TREE
+// ================================================================================================
+
+/// A sparse Merkle tree committing to the current state of all accounts on chain.
+///
+/// Each leaf of the tree is indexed by an account ID and contains the hash of the current state
+/// of the account. Leaves of accounts which are not in the tree are empty. The root of this tree is
+/// the `account_root` of the block header.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct AccountTree(SimpleSmt);
+
+impl AccountTree {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty [AccountTree].
+    pub fn new() -> Self {
+        Self(SimpleSmt::new(ACCOUNT_TREE_DEPTH).expect("account tree depth is valid"))
+    }
+
+    /// Returns a new [AccountTree] instantiated with the provided account IDs and account hashes.
+    ///
+    /// # Errors
+    /// Returns an error if the same account ID is provided more than once.
+    pub fn with_entries(
+        entries: impl IntoIterator<Item = (AccountId, Digest)>,
+    ) -> Result<Self, BlockError> {
+        let mut accounts = BTreeMap::new();
+        for (account_id, account_hash) in entries {
+            if accounts.insert(u64::from(account_id), Word::from(account_hash)).is_some() {
+                return Err(BlockError::DuplicateAccountId(account_id));
+            }
+        }
+
+        let tree =
+            SimpleSmt::with_leaves(ACCOUNT_TREE_DEPTH, accounts.into_iter().collect::<Vec<_>>())
+                .expect("account IDs are unique and within the bounds of the tree");
+
+        Ok(Self(tree))
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of the tree, i.e., the account root of the block.
+    pub fn root(&self) -> Digest {
+        self.0.root()
+    }
+
+    /// Returns the hash of the current state of the specified account, or None if the account is
+    /// not in the tree.
+    pub fn get_account_hash(&self, account_id: AccountId) -> Option<Digest> {
+        let account_hash =
+            self.0.get_node(leaf_index(account_id)).expect("index is within tree bounds");
+        (Word::from(account_hash) != EMPTY_WORD).then_some(account_hash)
+    }
+
+    /// Returns a proof of the current state of the specified account.
+    ///
+    /// If the account is not in the tree, the account hash of the returned proof is
+    /// [EMPTY_WORD], i.e., the proof attests that the account is not in the tree.
+    pub fn prove(&self, account_id: AccountId) -> AccountProof {
+        let account_hash = self.get_account_hash(account_id).unwrap_or_default();
+        let path = self.0.get_path(leaf_index(account_id)).expect("index is within tree bounds");
+
+        AccountProof {
+            account_id,
+            account_hash,
+            path,
+        }
+    }
+
+    // MODIFIERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the hash of the current state of the specified account, and returns the previous hash
+    /// of the account, or None if the account was not in the tree.
+    pub fn update_account(
+        &mut self,
+        account_id: AccountId,
+        account_hash: Digest,
+    ) -> Option<Digest> {
+        let old_value = self
+            .0
+            .update_leaf(account_id.into(), account_hash.into())
+            .expect("index is within tree bounds");
+        (old_value != EMPTY_WORD).then(|| old_value.into())
+    }
+
+    /// Updates the state of the account modified by the provided transaction.
+    ///
+    /// If the transaction creates the account, the initial state of the new account must be
+    /// provided via `new_account`, as the initial account hash of such a transaction commits to
+    /// this state rather than to a state recorded in the tree.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The account is in the tree, and its current hash is different from the initial account
+    ///   hash of the transaction.
+    /// - The account is in the tree, and the initial state of a new account is provided.
+    /// - The account is not in the tree, and the initial state of a new account with the ID of the
+    ///   transaction account is not provided.
+    /// - The hash of the provided new account is different from the initial account hash of the
+    ///   transaction.
+    pub fn apply_transaction(
+        &mut self,
+        transaction: &ProvenTransaction,
+        new_account: Option<&Account>,
+    ) -> Result<(), BlockError> {
+        self.apply_account_update(
+            transaction.account_id(),
+            transaction.initial_account_hash(),
+            transaction.final_account_hash(),
+            new_account,
+        )
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Updates the state of the specified account from the provided initial hash to the provided
+    /// final hash, following the rules described in [AccountTree::apply_transaction()].
+    fn apply_account_update(
+        &mut self,
+        account_id: AccountId,
+        initial_account_hash: Digest,
+        final_account_hash: Digest,
+        new_account: Option<&Account>,
+    ) -> Result<(), BlockError> {
+        let expected_hash = match (self.get_account_hash(account_id), new_account) {
+            (Some(account_hash), None) => account_hash,
+            (Some(_), Some(_)) => return Err(BlockError::DuplicateAccountId(account_id)),
+            (None, Some(account)) if account.id() == account_id && account.is_new() => {
+                account.hash()
+            }
+            (None, _) => return Err(BlockError::InvalidNewAccountState(account_id)),
+        };
+
+        if expected_hash != initial_account_hash {
+            return Err(BlockError::InconsistentAccountHash {
+                account_id,
+                expected: expected_hash,
+                actual: initial_account_hash,
+            });
+        }
+
+        self.update_account(account_id, final_account_hash);
+        Ok(())
+    }
+}
+
+impl Default for AccountTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ACCOUNT PROOF
+// ================================================================================================
+
+/// A proof of the state of an account in an [AccountTree].
+///
+/// This can be used to check an off-chain account state commitment against the account root of a
+/// block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountProof {
+    account_id: AccountId,
+    account_hash: Digest,
+    path: MerklePath,
+}
+
+impl AccountProof {
+    /// Returns the ID of the account this proof was created for.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the hash of the account state attested by this proof, or [EMPTY_WORD] if the proof
+    /// attests that the account is not in the tree.
+    pub fn account_hash(&self) -> Digest {
+        self.account_hash
+    }
+
+    /// Returns the Merkle path from the leaf of the account to the root of the tree.
+    pub fn path(&self) -> &MerklePath {
+        &self.path
+    }
+
+    /// Returns true if this proof is valid against the specified account root.
+    pub fn verify(&self, account_root: Digest) -> bool {
+        self.path.verify(self.account_id.into(), self.account_hash, &account_root)
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the index of the leaf of the specified account.
+fn leaf_index(account_id: AccountId) -> NodeIndex {
+    NodeIndex::new(ACCOUNT_TREE_DEPTH, account_id.into()).expect("account ID is a valid index")
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::AccountTree;
+    use crate::{
+        accounts::{
+            Account, AccountCode, AccountId, AccountStorage, AccountType, AccountVault,
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+        },
+        assembly::{Assembler, ModuleAst},
+        utils::collections::BTreeMap,
+        BlockError, Digest, Felt, ONE, ZERO,
+    };
+
+    fn build_hash(seed: u64) -> Digest {
+        Digest::new([Felt::new(seed), ONE, ONE, ONE])
+    }
+
+    fn build_new_account() -> Account {
+        let id = AccountId::new_dummy([0; 32], AccountType::RegularAccountUpdatableCode);
+
+        let module = ModuleAst::parse("export.foo\n push.1 push.2 mul\nend").unwrap();
+        let code = AccountCode::new(module, &Assembler::default()).unwrap();
+        let storage = AccountStorage::new(Vec::new(), BTreeMap::new(), BTreeMap::new()).unwrap();
+
+        Account::new(id, AccountVault::new(&[]).unwrap(), storage, code, ZERO)
+    }
+
+    #[test]
+    fn test_account_tree() {
+        let account_1 =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let account_2 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let account_3 =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+
+        let mut tree =
+            AccountTree::with_entries([(account_1, build_hash(1)), (account_2, build_hash(2))])
+                .unwrap();
+        assert_eq!(tree.get_account_hash(account_1), Some(build_hash(1)));
+        assert_eq!(tree.get_account_hash(account_3), None);
+
+        // the state of each account can be checked against the root of the tree
+        let root = tree.root();
+        for account_id in [account_1, account_2, account_3] {
+            let proof = tree.prove(account_id);
+            assert!(proof.verify(root));
+            assert_eq!(proof.account_hash(), tree.get_account_hash(account_id).unwrap_or_default());
+        }
+
+        // proofs are not valid against a different state of the tree
+        let proof = tree.prove(account_1);
+        assert_eq!(tree.update_account(account_1, build_hash(3)), Some(build_hash(1)));
+        assert!(!proof.verify(tree.root()));
+        assert!(tree.prove(account_1).verify(tree.root()));
+
+        assert_eq!(
+            AccountTree::with_entries([(account_1, build_hash(1)), (account_1, build_hash(2))])
+                .err(),
+            Some(BlockError::DuplicateAccountId(account_1))
+        );
+    }
+
+    #[test]
+    fn test_account_tree_apply_account_update() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let mut tree = AccountTree::with_entries([(account_id, build_hash(1))]).unwrap();
+
+        // the initial hash of the update must match the current hash of the account
+        tree.apply_account_update(account_id, build_hash(1), build_hash(2), None)
+            .unwrap();
+        assert_eq!(tree.get_account_hash(account_id), Some(build_hash(2)));
+
+        let root = tree.root();
+        assert_eq!(
+            tree.apply_account_update(account_id, build_hash(1), build_hash(3), None),
+            Err(BlockError::InconsistentAccountHash {
+                account_id,
+                expected: build_hash(2),
+                actual: build_hash(1),
+            })
+        );
+        assert_eq!(tree.root(), root);
+
+        // an account which is already in the tree cannot be created again
+        let new_account = build_new_account();
+        assert_eq!(
+            tree.apply_account_update(account_id, build_hash(2), build_hash(3), Some(&new_account)),
+            Err(BlockError::DuplicateAccountId(account_id))
+        );
+    }
+
+    #[test]
+    fn test_account_tree_apply_account_update_new_account() {
+        let new_account = build_new_account();
+        let account_id = new_account.id();
+        let mut tree = AccountTree::new();
+
+        // the initial state of a new account must be provided
+        assert_eq!(
+            tree.apply_account_update(account_id, new_account.hash(), build_hash(1), None),
+            Err(BlockError::InvalidNewAccountState(account_id))
+        );
+
+        // the initial state must belong to the account being created
+        let other_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+        assert_eq!(
+            tree.apply_account_update(
+                other_id,
+                new_account.hash(),
+                build_hash(1),
+                Some(&new_account)
+            ),
+            Err(BlockError::InvalidNewAccountState(other_id))
+        );
+
+        // the initial hash of the update must match the hash of the initial state
+        assert_eq!(
+            tree.apply_account_update(account_id, build_hash(2), build_hash(1), Some(&new_account)),
+            Err(BlockError::InconsistentAccountHash {
+                account_id,
+                expected: new_account.hash(),
+                actual: build_hash(2),
+            })
+        );
+        assert_eq!(tree.get_account_hash(account_id), None);
+
+        tree.apply_account_update(
+            account_id,
+            new_account.hash(),
+            build_hash(1),
+            Some(&new_account),
+        )
+        .unwrap();
+        assert_eq!(tree.get_account_hash(account_id), Some(build_hash(1)));
+    }
+}
@@ -2,5 +2,26 @@ use super::{
     utils::collections::Vec, AdviceInputsBuilder, Digest, Felt, Hasher, ToAdviceInputs, ZERO,
 };
 
+mod account_tree;
+pub use account_tree::{AccountProof, AccountTree, ACCOUNT_TREE_DEPTH};
+
 mod header;
 pub use header::BlockHeader;
+
+mod note_tree;
+pub use note_tree::BlockNoteTree;
+
+mod nullifier_tree;
+pub use nullifier_tree::{NullifierProof, NullifierTree};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The maximum number of batches which can be included in a single block.
+pub const MAX_BATCHES_PER_BLOCK: usize = 1 << 8;
+
+/// The maximum number of notes which can be created in a single batch.
+///
+/// Together with [MAX_BATCHES_PER_BLOCK], this determines the depth of the block note tree:
+/// `log2(MAX_BATCHES_PER_BLOCK) + log2(MAX_NOTES_PER_BATCH) = NOTE_TREE_DEPTH`.
+pub const MAX_NOTES_PER_BATCH: usize = 1 << 12;
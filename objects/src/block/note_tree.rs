@@ -0,0 +1,248 @@
+use super::{BlockHeader, Digest, Vec, MAX_BATCHES_PER_BLOCK, MAX_NOTES_PER_BATCH};
+use crate::{
+    crypto::merkle::{MerklePath, NodeIndex, SimpleSmt},
+    notes::{NoteEnvelope, NoteInclusionProof, NOTE_LEAF_DEPTH, NOTE_TREE_DEPTH},
+    utils::collections::BTreeMap,
+    BlockError, Word,
+};
+
+// BLOCK NOTE TREE
+// ================================================================================================
+
+/// A Merkle tree committing to all notes created in a block.
+///
+/// Each note occupies a node at depth [NOTE_TREE_DEPTH] in the tree, and the index of this node is
+/// computed as `batch_idx * MAX_NOTES_PER_BATCH + note_idx`. The two leaves below this node (at
+/// depth [NOTE_LEAF_DEPTH]) contain the hash of the note and the metadata of the note
+/// respectively. Thus, the node which authenticates a note is `hash(note_hash, note_metadata)`.
+///
+/// The root of this tree is the `note_root` of the block header.
+#[derive(Debug, Clone)]
+pub struct BlockNoteTree(SimpleSmt);
+
+impl BlockNoteTree {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [BlockNoteTree] instantiated with the provided entries.
+    ///
+    /// Each entry is a tuple of the index of the batch in the block, the index of the note in
+    /// the batch, and the envelope of the note.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The batch index of an entry is greater than or equal to [MAX_BATCHES_PER_BLOCK].
+    /// - The note index of an entry is greater than or equal to [MAX_NOTES_PER_BATCH].
+    /// - More than one note is provided for the same batch and note index.
+    pub fn with_entries(
+        entries: impl IntoIterator<Item = (usize, usize, NoteEnvelope)>,
+    ) -> Result<Self, BlockError> {
+        let mut notes = BTreeMap::new();
+        for (batch_idx, note_idx, envelope) in entries {
+            let index = note_index(batch_idx, note_idx)?;
+            if notes.insert(index, envelope).is_some() {
+                return Err(BlockError::DuplicateNoteFound(index));
+            }
+        }
+
+        let leaves = notes.into_iter().flat_map(|(index, envelope)| {
+            [
+                (index * 2, envelope.note_hash().into()),
+                (index * 2 + 1, Word::from(envelope.metadata())),
+            ]
+        });
+        let tree = SimpleSmt::with_leaves(NOTE_LEAF_DEPTH, leaves.collect::<Vec<_>>())
+            .expect("leaf indices are unique and within the bounds of the tree");
+
+        Ok(Self(tree))
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of the tree, i.e., the note root of the block.
+    pub fn root(&self) -> Digest {
+        self.0.root()
+    }
+
+    /// Returns the Merkle path to the node authenticating the note with the specified batch and
+    /// note index.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The batch index or the note index are out of bounds.
+    /// - The tree does not contain a note with the specified batch and note index.
+    pub fn get_note_path(
+        &self,
+        batch_idx: usize,
+        note_idx: usize,
+    ) -> Result<MerklePath, BlockError> {
+        let index = note_index(batch_idx, note_idx)?;
+
+        // the leaf containing the note hash is empty if there is no note at this index
+        let leaf_index =
+            NodeIndex::new(NOTE_LEAF_DEPTH, index * 2).expect("index is within tree bounds");
+        if self.0.get_node(leaf_index).expect("index is within tree bounds") == Digest::default() {
+            return Err(BlockError::NoteNotFound {
+                batch_idx,
+                note_idx,
+            });
+        }
+
+        let node_index =
+            NodeIndex::new(NOTE_TREE_DEPTH, index).expect("index is within tree bounds");
+        Ok(self.0.get_path(node_index).expect("index is within tree bounds"))
+    }
+
+    /// Returns an inclusion proof for the note with the specified batch and note index in the
+    /// block with the specified header.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The batch index or the note index are out of bounds.
+    /// - The tree does not contain a note with the specified batch and note index.
+    /// - The note root of the block header is different from the root of this tree.
+    pub fn get_inclusion_proof(
+        &self,
+        block_header: &BlockHeader,
+        batch_idx: usize,
+        note_idx: usize,
+    ) -> Result<NoteInclusionProof, BlockError> {
+        if block_header.note_root() != self.root() {
+            return Err(BlockError::InconsistentNoteRoot {
+                expected: block_header.note_root(),
+                actual: self.root(),
+            });
+        }
+
+        let note_path = self.get_note_path(batch_idx, note_idx)?;
+        let index = note_index(batch_idx, note_idx)?;
+
+        Ok(NoteInclusionProof::new(
+            block_header.block_num(),
+            block_header.sub_hash(),
+            block_header.note_root(),
+            index,
+            note_path,
+        )
+        .expect("index is within tree bounds"))
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the index of the node authenticating the note with the specified batch and note index.
+fn note_index(batch_idx: usize, note_idx: usize) -> Result<u64, BlockError> {
+    if batch_idx >= MAX_BATCHES_PER_BLOCK {
+        return Err(BlockError::TooManyBatchesInBlock(batch_idx + 1));
+    }
+    if note_idx >= MAX_NOTES_PER_BATCH {
+        return Err(BlockError::TooManyNotesInBatch(note_idx + 1));
+    }
+
+    Ok((batch_idx * MAX_NOTES_PER_BATCH + note_idx) as u64)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::BlockNoteTree;
+    use crate::{
+        accounts::{AccountId, ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN},
+        block::{MAX_BATCHES_PER_BLOCK, MAX_NOTES_PER_BATCH},
+        notes::{NoteEnvelope, NoteMetadata, NoteTag, NoteType},
+        BlockError, BlockHeader, Digest, Felt, ONE, ZERO,
+    };
+
+    fn build_envelope(seed: u64) -> NoteEnvelope {
+        let sender =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let metadata = NoteMetadata::new(sender, NoteType::Public, NoteTag::from(seed as u32), ONE);
+        NoteEnvelope::new(Digest::new([Felt::new(seed), ONE, ONE, ONE]), metadata)
+    }
+
+    #[test]
+    fn test_block_note_tree_inclusion_proofs() {
+        let entries =
+            vec![(0, 0, build_envelope(1)), (0, 1, build_envelope(2)), (3, 5, build_envelope(3))];
+        let tree = BlockNoteTree::with_entries(entries.clone()).unwrap();
+
+        let block_header = BlockHeader::new(
+            Digest::default(),
+            Felt::new(4),
+            Digest::default(),
+            Digest::default(),
+            Digest::default(),
+            tree.root(),
+            Digest::default(),
+            Digest::default(),
+            ZERO,
+            ZERO,
+        );
+
+        for (batch_idx, note_idx, envelope) in entries {
+            let proof = tree.get_inclusion_proof(&block_header, batch_idx, note_idx).unwrap();
+            assert_eq!(
+                proof.origin().node_index.value(),
+                (batch_idx * MAX_NOTES_PER_BATCH + note_idx) as u64
+            );
+            assert!(proof.verify(envelope.note_hash(), envelope.metadata(), &block_header).is_ok());
+        }
+
+        // the note root of the block must match the root of the tree
+        let other_tree = BlockNoteTree::with_entries([(0, 0, build_envelope(1))]).unwrap();
+        assert!(matches!(
+            other_tree.get_inclusion_proof(&block_header, 0, 0),
+            Err(BlockError::InconsistentNoteRoot { .. })
+        ));
+    }
+
+    #[test]
+    fn test_block_note_tree_invalid_entries() {
+        let result =
+            BlockNoteTree::with_entries([(0, 1, build_envelope(1)), (0, 1, build_envelope(2))]);
+        assert!(matches!(result, Err(BlockError::DuplicateNoteFound(1))));
+
+        let result = BlockNoteTree::with_entries([(MAX_BATCHES_PER_BLOCK, 0, build_envelope(1))]);
+        assert!(matches!(result, Err(BlockError::TooManyBatchesInBlock(_))));
+
+        let result = BlockNoteTree::with_entries([(0, MAX_NOTES_PER_BATCH, build_envelope(1))]);
+        assert!(matches!(result, Err(BlockError::TooManyNotesInBatch(_))));
+    }
+
+    #[test]
+    fn test_block_note_tree_note_not_found() {
+        let tree = BlockNoteTree::with_entries([(0, 1, build_envelope(1))]).unwrap();
+        let block_header = BlockHeader::new(
+            Digest::default(),
+            Felt::new(4),
+            Digest::default(),
+            Digest::default(),
+            Digest::default(),
+            tree.root(),
+            Digest::default(),
+            Digest::default(),
+            ZERO,
+            ZERO,
+        );
+
+        assert!(tree.get_note_path(0, 1).is_ok());
+        assert!(matches!(
+            tree.get_note_path(0, 0),
+            Err(BlockError::NoteNotFound {
+                batch_idx: 0,
+                note_idx: 0
+            })
+        ));
+        assert!(matches!(
+            tree.get_inclusion_proof(&block_header, 2, 1),
+            Err(BlockError::NoteNotFound {
+                batch_idx: 2,
+                note_idx: 1
+            })
+        ));
+    }
+}
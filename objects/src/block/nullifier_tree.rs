@@ -0,0 +1,223 @@
+use super::{Digest, Felt, Vec, ZERO};
+use crate::{
+    crypto::merkle::{TieredSmt, TieredSmtProof},
+    notes::Nullifier,
+    utils::collections::BTreeSet,
+    BlockError, Word, ONE,
+};
+
+// NULLIFIER TREE
+// ================================================================================================
+
+/// A sparse Merkle tree containing the nullifiers of all notes consumed on chain.
+///
+/// The tree maps each nullifier to the number of the block in which the corresponding note was
+/// consumed. The value of a leaf is `[block_num, 1, 0, 0]`, where the second element ensures that
+/// the leaf of a note consumed in the genesis block is not empty. The root of the tree is the
+/// `nullifier_root` of the block header.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct NullifierTree(TieredSmt);
+
+impl NullifierTree {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty [NullifierTree].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of the tree, i.e., the nullifier root of the block.
+    pub fn root(&self) -> Digest {
+        self.0.root()
+    }
+
+    /// Returns the number of the block in which the note with the specified nullifier was
+    /// consumed, or None if the note has not been consumed.
+    pub fn get_block_num(&self, nullifier: Nullifier) -> Option<Felt> {
+        block_num_from_value(self.0.get_value(nullifier.inner()))
+    }
+
+    /// Returns true if the note with the specified nullifier has been consumed.
+    pub fn is_spent(&self, nullifier: Nullifier) -> bool {
+        self.get_block_num(nullifier).is_some()
+    }
+
+    /// Returns a proof of membership or non-membership of the specified nullifier in this tree.
+    pub fn prove(&self, nullifier: Nullifier) -> NullifierProof {
+        NullifierProof {
+            nullifier,
+            proof: self.0.prove(nullifier.inner()),
+        }
+    }
+
+    // MODIFIERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Marks the note with the specified nullifier as consumed in the specified block.
+    ///
+    /// # Errors
+    /// Returns an error if the nullifier is already in the tree.
+    pub fn insert(&mut self, nullifier: Nullifier, block_num: Felt) -> Result<(), BlockError> {
+        self.insert_batch([nullifier], block_num)
+    }
+
+    /// Marks the notes with the specified nullifiers as consumed in the specified block.
+    ///
+    /// Either all nullifiers are inserted into the tree, or none of them are.
+    ///
+    /// # Errors
+    /// Returns an error if any of the nullifiers is already in the tree, or if the same nullifier
+    /// is provided more than once.
+    pub fn insert_batch(
+        &mut self,
+        nullifiers: impl IntoIterator<Item = Nullifier>,
+        block_num: Felt,
+    ) -> Result<(), BlockError> {
+        let nullifiers = nullifiers.into_iter().collect::<Vec<_>>();
+
+        let mut seen = BTreeSet::new();
+        for nullifier in nullifiers.iter() {
+            if !seen.insert(<[u8; 32]>::from(*nullifier)) || self.is_spent(*nullifier) {
+                return Err(BlockError::DuplicateNullifier(nullifier.inner()));
+            }
+        }
+
+        for nullifier in nullifiers {
+            self.0.insert(nullifier.inner(), leaf_value(block_num));
+        }
+
+        Ok(())
+    }
+}
+
+// NULLIFIER PROOF
+// ================================================================================================
+
+/// A proof of membership or non-membership of a nullifier in a [NullifierTree].
+#[derive(Debug, Clone)]
+pub struct NullifierProof {
+    nullifier: Nullifier,
+    proof: TieredSmtProof,
+}
+
+impl NullifierProof {
+    /// Returns the nullifier this proof was created for.
+    pub fn nullifier(&self) -> Nullifier {
+        self.nullifier
+    }
+
+    /// Returns the number of the block in which the note was consumed if this is a membership
+    /// proof, or None if this is a non-membership proof.
+    pub fn block_num(&self) -> Option<Felt> {
+        self.proof.get(&self.nullifier.inner()).and_then(block_num_from_value)
+    }
+
+    /// Returns true if this proof is valid against the specified nullifier root.
+    pub fn verify(&self, nullifier_root: Digest) -> bool {
+        self.proof.get(&self.nullifier.inner()).is_some()
+            && self.proof.compute_root() == nullifier_root
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the leaf value recording that a note was consumed in the specified block.
+fn leaf_value(block_num: Felt) -> Word {
+    [block_num, ONE, ZERO, ZERO]
+}
+
+/// Returns the block number stored in the specified leaf value, or None if the leaf is empty.
+fn block_num_from_value(value: Word) -> Option<Felt> {
+    (value != TieredSmt::EMPTY_VALUE).then(|| value[0])
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::NullifierTree;
+    use crate::{notes::Nullifier, BlockError, Digest, Felt, ONE, ZERO};
+
+    fn build_nullifier(seed: u64) -> Nullifier {
+        Digest::new([Felt::new(seed), ONE, ONE, ONE]).into()
+    }
+
+    #[test]
+    fn test_nullifier_tree_insert() {
+        let mut tree = NullifierTree::new();
+        let nullifier_1 = build_nullifier(1);
+        let nullifier_2 = build_nullifier(2);
+        let nullifier_3 = build_nullifier(3);
+
+        tree.insert_batch([nullifier_1, nullifier_2], Felt::new(5)).unwrap();
+        assert_eq!(tree.get_block_num(nullifier_1), Some(Felt::new(5)));
+        assert_eq!(tree.get_block_num(nullifier_2), Some(Felt::new(5)));
+        assert!(!tree.is_spent(nullifier_3));
+
+        // double spends are rejected and leave the tree unchanged
+        let root = tree.root();
+        assert_eq!(
+            tree.insert_batch([nullifier_3, nullifier_1], Felt::new(6)),
+            Err(BlockError::DuplicateNullifier(nullifier_1.inner()))
+        );
+        assert_eq!(
+            tree.insert_batch([nullifier_3, nullifier_3], Felt::new(6)),
+            Err(BlockError::DuplicateNullifier(nullifier_3.inner()))
+        );
+        assert_eq!(tree.root(), root);
+        assert!(!tree.is_spent(nullifier_3));
+
+        tree.insert(nullifier_3, Felt::new(6)).unwrap();
+        assert_eq!(tree.get_block_num(nullifier_3), Some(Felt::new(6)));
+        assert_ne!(tree.root(), root);
+    }
+
+    #[test]
+    fn test_nullifier_tree_genesis_block() {
+        let mut tree = NullifierTree::new();
+        let nullifier = build_nullifier(1);
+
+        // notes consumed in the genesis block are recorded as spent
+        tree.insert(nullifier, ZERO).unwrap();
+        assert!(tree.is_spent(nullifier));
+        assert_eq!(tree.get_block_num(nullifier), Some(ZERO));
+        assert_ne!(tree.root(), NullifierTree::new().root());
+
+        let proof = tree.prove(nullifier);
+        assert!(proof.verify(tree.root()));
+        assert_eq!(proof.block_num(), Some(ZERO));
+
+        // and thus cannot be consumed again
+        assert_eq!(
+            tree.insert(nullifier, ONE),
+            Err(BlockError::DuplicateNullifier(nullifier.inner()))
+        );
+    }
+
+    #[test]
+    fn test_nullifier_tree_proofs() {
+        let mut tree = NullifierTree::new();
+        let spent = build_nullifier(1);
+        let unspent = build_nullifier(2);
+        tree.insert(spent, Felt::new(3)).unwrap();
+
+        let proof = tree.prove(spent);
+        assert!(proof.verify(tree.root()));
+        assert_eq!(proof.block_num(), Some(Felt::new(3)));
+
+        let proof = tree.prove(unspent);
+        assert!(proof.verify(tree.root()));
+        assert_eq!(proof.block_num(), None);
+
+        // proofs are not valid against a different state of the tree
+        tree.insert(unspent, Felt::new(4)).unwrap();
+        assert!(!proof.verify(tree.root()));
+    }
+}
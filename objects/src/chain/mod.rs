@@ -1,4 +1,7 @@
-use super::{crypto::merkle::Mmr, AdviceInputsBuilder, ToAdviceInputs};
+use super::{
+    crypto::merkle::{Mmr, MmrProof},
+    AdviceInputsBuilder, BlockHeader, StarkField, ToAdviceInputs,
+};
 
 // TODO: Consider using a PartialMmr that only contains the Mmr nodes that are relevant to the
 // transaction being processed.
@@ -25,6 +28,22 @@ impl ChainMmr {
     pub fn mmr_mut(&mut self) -> &mut Mmr {
         &mut self.0
     }
+
+    /// Returns true if the provided MMR proof authenticates the specified block header against
+    /// the current state of this chain MMR.
+    ///
+    /// The proof must have been created for the current forest of the MMR, and must open the leaf
+    /// at the position equal to the number of the block.
+    pub fn verify_block(&self, block_header: &BlockHeader, mmr_proof: MmrProof) -> bool {
+        let forest = self.0.forest();
+        let block_num = block_header.block_num().as_int() as usize;
+        if mmr_proof.forest != forest || mmr_proof.position != block_num || block_num >= forest {
+            return false;
+        }
+
+        let peaks = self.0.peaks(forest).expect("current forest is always valid");
+        peaks.verify(block_header.hash(), mmr_proof)
+    }
 }
 
 impl ToAdviceInputs for &ChainMmr {
@@ -38,3 +57,52 @@ impl ToAdviceInputs for &ChainMmr {
         peaks.to_advice_inputs(target);
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::ChainMmr;
+    use crate::{BlockHeader, Digest, Felt, ZERO};
+
+    fn build_block_header(block_num: u64) -> BlockHeader {
+        BlockHeader::new(
+            Digest::default(),
+            Felt::new(block_num),
+            Digest::default(),
+            Digest::default(),
+            Digest::default(),
+            Digest::default(),
+            Digest::default(),
+            Digest::default(),
+            ZERO,
+            ZERO,
+        )
+    }
+
+    #[test]
+    fn test_chain_mmr_verify_block() {
+        let block_headers = (0..5).map(build_block_header).collect::<Vec<_>>();
+
+        let mut chain_mmr = ChainMmr::default();
+        for block_header in block_headers.iter() {
+            chain_mmr.mmr_mut().add(block_header.hash());
+        }
+        let forest = chain_mmr.mmr().forest();
+
+        for (block_num, block_header) in block_headers.iter().enumerate() {
+            let proof = chain_mmr.mmr().open(block_num, forest).unwrap();
+            assert!(chain_mmr.verify_block(block_header, proof));
+        }
+
+        // a proof for a different block is rejected
+        let proof = chain_mmr.mmr().open(1, forest).unwrap();
+        assert!(!chain_mmr.verify_block(&block_headers[2], proof));
+
+        // a proof for a different state of the chain is rejected
+        let proof = chain_mmr.mmr().open(1, forest).unwrap();
+        chain_mmr.mmr_mut().add(build_block_header(5).hash());
+        assert!(!chain_mmr.verify_block(&block_headers[1], proof));
+    }
+}
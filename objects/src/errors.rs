@@ -2,6 +2,7 @@ use super::{
     accounts::AccountId,
     assets::{Asset, FungibleAsset, NonFungibleAsset},
     crypto::merkle::MerkleError,
+    notes::NoteOrigin,
     utils::string::String,
     Digest, Word,
 };
@@ -16,6 +17,7 @@ use vm_processor::DeserializationError;
 pub enum AccountError {
     AccountCodeAssemblerError(AssemblyError),
     AccountCodeNoProcedures,
+    AccountCodeNotAvailable(Digest),
     AccountCodeTooManyProcedures {
         max: usize,
         actual: usize,
@@ -27,7 +29,13 @@ pub enum AccountError {
     ApplyStorageStoreDiffFailed(MerkleError),
     DuplicateAsset(MerkleError),
     DuplicateNonFungibleAsset(NonFungibleAsset),
+    DuplicateStorageArrayItems(MerkleError),
     DuplicateStorageItems(MerkleError),
+    DuplicateStorageMapKeys(MerkleError),
+    FinalAccountHashMismatch {
+        expected: Digest,
+        actual: Digest,
+    },
     FungibleAssetNotFound(FungibleAsset),
     FungibleFaucetIdInvalidFirstBit,
     FungibleFaucetInvalidMetadata(String),
@@ -36,7 +44,13 @@ pub enum AccountError {
         expected: AccountId,
         actual: AccountId,
     },
+    InvalidAuthScheme(String),
+    InvalidStorageItemWordsCount {
+        expected: u8,
+        actual: usize,
+    },
     NonceMustBeMonotonicallyIncreasing(u64, u64),
+    NonFungibleFaucetInvalidMetadata(String),
     NonFungibleAssetNotFound(NonFungibleAsset),
     NotAFungibleFaucetId(AccountId),
     NotANonFungibleAsset(Asset),
@@ -45,16 +59,31 @@ pub enum AccountError {
         actual: u32,
     },
     SetStoreNodeFailed(MerkleError),
+    StorageArrayDepthMismatch {
+        expected: u8,
+        actual: u8,
+    },
+    StorageArrayIndexOutOfBounds {
+        index: u64,
+        depth: u8,
+    },
+    StorageArrayInvalidDepth(u8),
     StorageArrayRequiresMoreThanOneElement,
+    StorageArrayRootMismatch(u8),
     StorageArrayTooLong {
         actual: usize,
         max: usize,
     },
+    StorageItemWordsMismatch(u8),
     StorageSlotArrayTooSmall {
         actual: u8,
         min: u8,
     },
+    StorageMapRootMismatch(u8),
     StorageSlotIsReserved(u8),
+    StorageSlotNotArray(u8),
+    StorageSlotNotMap(u8),
+    StorageSlotNotMultiWordValue(u8),
     StubDataIncorrectLength(usize, usize),
     SubtractFungibleAssetBalanceError(AssetError),
 }
@@ -101,8 +130,11 @@ impl std::error::Error for AccountError {}
 pub enum AccountDeltaError {
     DuplicateStorageItemUpdate(usize),
     DuplicateVaultUpdate(Asset),
+    InconsistentCodeUpdate { expected: Digest, actual: Digest },
     InconsistentNonceUpdate(String),
+    InconsistentStorageUpdate(usize),
     ImmutableStorageSlot(usize),
+    InvalidFungibleAssetAmount(AssetError),
     TooManyAddedAsset { actual: usize, max: usize },
     TooManyClearedStorageItems { actual: usize, max: usize },
     TooManyRemovedAssets { actual: usize, max: usize },
@@ -202,6 +234,41 @@ impl fmt::Display for AssetError {
 #[cfg(feature = "std")]
 impl std::error::Error for AssetError {}
 
+// BLOCK ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum BlockError {
+    DuplicateAccountId(AccountId),
+    DuplicateNoteFound(u64),
+    DuplicateNullifier(Digest),
+    InconsistentAccountHash {
+        account_id: AccountId,
+        expected: Digest,
+        actual: Digest,
+    },
+    InconsistentNoteRoot {
+        expected: Digest,
+        actual: Digest,
+    },
+    InvalidNewAccountState(AccountId),
+    NoteNotFound {
+        batch_idx: usize,
+        note_idx: usize,
+    },
+    TooManyBatchesInBlock(usize),
+    TooManyNotesInBatch(usize),
+}
+
+impl fmt::Display for BlockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BlockError {}
+
 // NOTE ERROR
 // ================================================================================================
 
@@ -211,13 +278,25 @@ pub enum NoteError {
     DuplicateFungibleAsset(AccountId),
     DuplicateNonFungibleAsset(NonFungibleAsset),
     EmptyAssetList,
+    InconsistentEncryptedNoteHash(Digest, Digest),
+    InconsistentEncryptedNoteMetadata,
+    InconsistentInclusionProofBlockHash(Digest, Digest),
+    InconsistentInclusionProofBlockNum(u64, u64),
     InconsistentStubHash(Digest, Digest),
     InconsistentStubNumAssets(u64, u64),
     InconsistentStubVaultHash(Digest, Digest),
     InvalidStubDataLen(usize),
+    InvalidInclusionProofNotePath(Digest),
+    InvalidNoteTagUseCase(u16),
+    InvalidNoteTagValue(u64),
+    InvalidNoteType(u64),
     InvalidOriginIndex(String),
+    InvalidPartialSwap(String),
     InvalidVaultDataLen(usize),
     InvalidVaultAssetData(AssetError),
+    NoteArgsOriginNotFound(NoteOrigin),
+    NoteDecryptionFailed,
+    NoteEncryptionFailed,
     NoteMetadataSenderInvalid(AccountError),
     ScriptCompilationError(AssemblyError),
     TooManyAssets(usize),
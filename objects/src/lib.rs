@@ -23,7 +23,7 @@ pub mod transaction;
 
 mod errors;
 pub use errors::{
-    AccountDeltaError, AccountError, AssetError, ExecutedTransactionError, NoteError,
+    AccountDeltaError, AccountError, AssetError, BlockError, ExecutedTransactionError, NoteError,
     PreparedTransactionError, TransactionResultError, TransactionScriptError,
     TransactionWitnessError,
 };
@@ -46,6 +46,10 @@ pub mod crypto {
     pub use miden_crypto::dsa;
     pub use miden_crypto::merkle;
     pub use miden_crypto::utils;
+
+    pub mod encryption {
+        pub use crypto_box::{aead::rand_core, PublicKey, SecretKey};
+    }
 }
 
 pub mod utils {
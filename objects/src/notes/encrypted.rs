@@ -0,0 +1,289 @@
+use crypto_box::{aead::rand_core::CryptoRngCore, PublicKey, SecretKey};
+use miden_crypto::utils::{ByteReader, ByteWriter, Deserializable, Serializable};
+use vm_processor::DeserializationError;
+
+use super::{Assembler, Digest, Note, NoteEnvelope, NoteError, NoteScript, Vec};
+
+// ENCRYPTED NOTE
+// ================================================================================================
+
+/// A note encrypted to the public key of its recipient.
+///
+/// Encrypted notes are used to share the details of off-chain notes between the sender and the
+/// recipient over an arbitrary transport. The full note (script, inputs, vault, serial number and
+/// metadata) is serialized and encrypted using a sealed box (X25519 key agreement with an
+/// ephemeral key, followed by XSalsa20-Poly1305 encryption), and thus the sender of the note does
+/// not need to hold a key pair of their own.
+///
+/// The hash of the note is kept in the clear so that the recipient can locate the corresponding
+/// [NoteEnvelope] on-chain. On decryption, the note is verified against this envelope.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct EncryptedNote {
+    note_hash: Digest,
+    ciphertext: Vec<u8>,
+}
+
+impl EncryptedNote {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the specified note encrypted to the provided public key of the recipient.
+    ///
+    /// # Errors
+    /// Returns an error if the encryption of the note fails.
+    pub fn new(
+        note: &Note,
+        recipient_key: &PublicKey,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<Self, NoteError> {
+        let ciphertext = recipient_key
+            .seal(rng, &note.to_bytes())
+            .map_err(|_| NoteError::NoteEncryptionFailed)?;
+
+        Ok(Self {
+            note_hash: note.hash(),
+            ciphertext,
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the hash of the encrypted note.
+    pub fn note_hash(&self) -> Digest {
+        self.note_hash
+    }
+
+    /// Returns the ciphertext of the encrypted note.
+    pub fn ciphertext(&self) -> &[u8] {
+        &self.ciphertext
+    }
+
+    // DECRYPTION
+    // --------------------------------------------------------------------------------------------
+
+    /// Decrypts this note using the provided secret key of the recipient and verifies the
+    /// decrypted note against the specified on-chain note envelope.
+    ///
+    /// The script of the decrypted note is compiled using the provided assembler, such that the
+    /// hash of the returned note is computed from the code of its script.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The ciphertext could not be decrypted using the provided key.
+    /// - The decrypted data is not a valid serialized note.
+    /// - The script of the decrypted note could not be compiled.
+    /// - The hash of the decrypted note does not match the hash of the note envelope, or the hash
+    ///   stored in this encrypted note.
+    /// - The metadata of the decrypted note does not match the metadata of the note envelope.
+    pub fn decrypt(
+        &self,
+        secret_key: &SecretKey,
+        envelope: &NoteEnvelope,
+        assembler: &Assembler,
+    ) -> Result<Note, NoteError> {
+        if self.note_hash != envelope.note_hash() {
+            return Err(NoteError::InconsistentEncryptedNoteHash(
+                envelope.note_hash(),
+                self.note_hash,
+            ));
+        }
+
+        let plaintext = secret_key
+            .unseal(&self.ciphertext)
+            .map_err(|_| NoteError::NoteDecryptionFailed)?;
+        let note =
+            Note::read_from_bytes(&plaintext).map_err(NoteError::NoteDeserializationError)?;
+
+        // the hash of the script is not recomputed during deserialization, and thus the note is
+        // rebuilt from the compiled script to make sure its hash commits to the script code
+        let (script, _) = NoteScript::new(note.script().code().clone(), assembler)?;
+        let note = Note::new(
+            script,
+            note.inputs().inputs(),
+            &note.vault().iter().copied().collect::<Vec<_>>(),
+            note.serial_num(),
+            note.metadata().sender(),
+            note.metadata().note_type(),
+            note.metadata().tag(),
+        )?;
+
+        if note.hash() != envelope.note_hash() {
+            return Err(NoteError::InconsistentEncryptedNoteHash(
+                envelope.note_hash(),
+                note.hash(),
+            ));
+        }
+        if note.metadata() != envelope.metadata() {
+            return Err(NoteError::InconsistentEncryptedNoteMetadata);
+        }
+
+        Ok(note)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for EncryptedNote {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.note_hash.write_into(target);
+        target.write_u64(self.ciphertext.len() as u64);
+        target.write_bytes(&self.ciphertext);
+    }
+}
+
+impl Deserializable for EncryptedNote {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let note_hash = Digest::read_from(source)?;
+        let len = source.read_u64()?;
+        let ciphertext = source.read_vec(len as usize)?;
+
+        Ok(Self {
+            note_hash,
+            ciphertext,
+        })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use crypto_box::{aead::OsRng, SecretKey};
+    use miden_crypto::utils::{Deserializable, Serializable};
+
+    use super::EncryptedNote;
+    use crate::{
+        accounts::{
+            AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        },
+        assembly::{Assembler, ProgramAst},
+        assets::FungibleAsset,
+        notes::{Note, NoteEnvelope, NoteExecution, NoteScript, NoteTag, NoteType},
+        Felt, NoteError, ONE,
+    };
+
+    fn build_note() -> Note {
+        let sender =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+        let code = ProgramAst::parse("begin push.1 drop end").unwrap();
+        let (script, _) = NoteScript::new(code, &Assembler::default()).unwrap();
+
+        Note::new(
+            script,
+            &[Felt::new(1), Felt::new(2)],
+            &[asset],
+            [ONE, Felt::new(2), Felt::new(3), Felt::new(4)],
+            sender,
+            NoteType::OffChain,
+            NoteTag::from_account_id(sender, NoteExecution::Local),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_encrypted_note_round_trip() {
+        let note = build_note();
+        let envelope = NoteEnvelope::from(&note);
+        let recipient_key = SecretKey::generate(&mut OsRng);
+
+        let encrypted = EncryptedNote::new(&note, &recipient_key.public_key(), &mut OsRng).unwrap();
+        assert_eq!(encrypted.note_hash(), envelope.note_hash());
+
+        // the encrypted note can be sent over any transport as bytes
+        let bytes = encrypted.to_bytes();
+        let received = EncryptedNote::read_from_bytes(&bytes).unwrap();
+        assert_eq!(received, encrypted);
+
+        let decrypted = received.decrypt(&recipient_key, &envelope, &Assembler::default()).unwrap();
+        assert_eq!(decrypted, note);
+        assert_eq!(decrypted.hash(), envelope.note_hash());
+    }
+
+    #[test]
+    fn test_encrypted_note_invalid_decryption() {
+        let note = build_note();
+        let envelope = NoteEnvelope::from(&note);
+        let assembler = Assembler::default();
+        let recipient_key = SecretKey::generate(&mut OsRng);
+        let encrypted = EncryptedNote::new(&note, &recipient_key.public_key(), &mut OsRng).unwrap();
+
+        // a different key cannot decrypt the note
+        let other_key = SecretKey::generate(&mut OsRng);
+        assert_eq!(
+            encrypted.decrypt(&other_key, &envelope, &assembler),
+            Err(NoteError::NoteDecryptionFailed)
+        );
+
+        // the note must match the provided envelope
+        let other_note = Note::new(
+            note.script().clone(),
+            &[Felt::new(3)],
+            &note.vault().iter().copied().collect::<Vec<_>>(),
+            note.serial_num(),
+            note.metadata().sender(),
+            NoteType::OffChain,
+            note.metadata().tag(),
+        )
+        .unwrap();
+        let other_envelope = NoteEnvelope::from(&other_note);
+        assert!(matches!(
+            encrypted.decrypt(&recipient_key, &other_envelope, &assembler),
+            Err(NoteError::InconsistentEncryptedNoteHash(..))
+        ));
+
+        // tampering with the ciphertext is detected
+        let mut bytes = encrypted.to_bytes();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 1;
+        let tampered = EncryptedNote::read_from_bytes(&bytes).unwrap();
+        assert_eq!(
+            tampered.decrypt(&recipient_key, &envelope, &assembler),
+            Err(NoteError::NoteDecryptionFailed)
+        );
+    }
+
+    #[test]
+    fn test_encrypted_note_tampered_script() {
+        let note = build_note();
+        let envelope = NoteEnvelope::from(&note);
+        let assembler = Assembler::default();
+        let recipient_key = SecretKey::generate(&mut OsRng);
+
+        // build a script with the hash of the original script but with different code
+        let other_code = ProgramAst::parse("begin push.2 drop end").unwrap();
+        let (other_script, _) = NoteScript::new(other_code, &assembler).unwrap();
+        let mut script_bytes = other_script.to_bytes();
+        let hash_bytes = note.script().hash().to_bytes();
+        script_bytes[..hash_bytes.len()].copy_from_slice(&hash_bytes);
+        let tampered_script = NoteScript::read_from_bytes(&script_bytes).unwrap();
+
+        // the hash of the tampered note matches the envelope, as the script hash is not recomputed
+        let tampered_note = Note::new(
+            tampered_script,
+            note.inputs().inputs(),
+            &note.vault().iter().copied().collect::<Vec<_>>(),
+            note.serial_num(),
+            note.metadata().sender(),
+            note.metadata().note_type(),
+            note.metadata().tag(),
+        )
+        .unwrap();
+        assert_eq!(tampered_note.hash(), envelope.note_hash());
+
+        // the script is compiled on decryption, and thus the tampered code is detected
+        let encrypted =
+            EncryptedNote::new(&tampered_note, &recipient_key.public_key(), &mut OsRng).unwrap();
+        assert!(matches!(
+            encrypted.decrypt(&recipient_key, &envelope, &assembler),
+            Err(NoteError::InconsistentEncryptedNoteHash(..))
+        ));
+    }
+}
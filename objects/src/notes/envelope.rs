@@ -1,4 +1,4 @@
-use super::{Digest, Felt, Note, NoteMetadata, Vec, Word};
+use super::{Digest, Felt, Note, NoteMetadata, NoteType, Vec, Word};
 use miden_crypto::utils::{ByteReader, ByteWriter, Deserializable, Serializable};
 use vm_core::StarkField;
 use vm_processor::DeserializationError;
@@ -11,10 +11,13 @@ use vm_processor::DeserializationError;
 /// - note_hash: hash of the note that was created
 /// - note_metadata: metadata of the note that was created. Metadata is padded with ZERO such that
 ///   it is four elements in size (a word). The metadata includes the following elements:
-///     - sender
-///     - tag
 ///     - num assets
-///     - ZERO
+///     - tag
+///     - sender
+///     - note type
+///
+/// The note type specifies whether the full details of the note are expected to be published
+/// alongside the envelope, or whether only the note hash is published.
 #[derive(Debug, Copy, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct NoteEnvelope {
@@ -40,6 +43,11 @@ impl NoteEnvelope {
     pub fn metadata(&self) -> &NoteMetadata {
         &self.note_metadata
     }
+
+    /// Returns the type of the note that was created.
+    pub fn note_type(&self) -> NoteType {
+        self.note_metadata.note_type()
+    }
 }
 
 impl From<NoteEnvelope> for [Felt; 8] {
@@ -6,46 +6,35 @@ use super::{Digest, Felt, Hasher, NoteError, Vec, ZERO};
 /// Holds the inputs which are placed onto the stack before a note's script is executed.
 /// - inputs are stored in reverse stack order such that when they are pushed onto stack they are
 ///   in the correct order
-/// - hash is computed from inputs in the order they are stored (reverse stack order)
+/// - hash is computed as a sequential hash of the inputs in the order they are stored (reverse
+///   stack order)
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct NoteInputs {
-    inputs: [Felt; 16],
+    inputs: Vec<Felt>,
     hash: Digest,
 }
 
 impl NoteInputs {
-    /// Number of note inputs.
-    const NOTE_NUM_INPUTS: usize = 16;
+    /// Maximum number of note inputs.
+    pub const MAX_NUM_INPUTS: usize = u16::MAX as usize;
 
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
     /// Returns NoteInputs created from the provided inputs.
     ///
-    /// The inputs are padded with ZERO such that they are always of length 16.
-    ///
     /// # Errors
-    /// Returns an error if the number of provided inputs is greater than 16.
+    /// Returns an error if the number of provided inputs is greater than [Self::MAX_NUM_INPUTS].
     pub fn new(inputs: &[Felt]) -> Result<Self, NoteError> {
-        if inputs.len() > Self::NOTE_NUM_INPUTS {
+        if inputs.len() > Self::MAX_NUM_INPUTS {
             return Err(NoteError::too_many_inputs(inputs.len()));
         }
 
-        // pad inputs with ZERO to be constant size (16 elements)
-        let padded_inputs: [Felt; Self::NOTE_NUM_INPUTS] = inputs
-            .iter()
-            .cloned()
-            .chain(core::iter::repeat(ZERO))
-            .take(Self::NOTE_NUM_INPUTS)
-            .collect::<Vec<_>>()
-            .try_into()
-            .expect("padded are of the correct length");
-
-        // compute hash from padded inputs.
-        let hash = Hasher::hash_elements(&padded_inputs);
+        // compute hash from the inputs.
+        let hash = Hasher::hash_elements(inputs);
 
         Ok(Self {
-            inputs: padded_inputs,
+            inputs: inputs.to_vec(),
             hash,
         })
     }
@@ -58,10 +47,27 @@ impl NoteInputs {
         &self.inputs
     }
 
-    /// Returns a hash digest of the inputs. Computed as a linear hash of the inputs.
+    /// Returns the number of inputs.
+    pub fn num_inputs(&self) -> usize {
+        self.inputs.len()
+    }
+
+    /// Returns a hash digest of the inputs. Computed as a sequential hash of the inputs.
     pub fn hash(&self) -> Digest {
         self.hash
     }
+
+    /// Returns the inputs padded with ZERO to the next multiple of 8, such that they can be read
+    /// from the advice provider in chunks of 8 elements.
+    pub fn to_padded_inputs(&self) -> Vec<Felt> {
+        let padded_len = (self.inputs.len() + 7) / 8 * 8;
+
+        let mut padded_inputs = Vec::with_capacity(padded_len);
+        padded_inputs.extend_from_slice(&self.inputs);
+        padded_inputs.resize(padded_len, ZERO);
+
+        padded_inputs
+    }
 }
 
 // SERIALIZATION
@@ -69,13 +75,16 @@ impl NoteInputs {
 
 impl Serializable for NoteInputs {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        debug_assert!(self.inputs.len() <= Self::MAX_NUM_INPUTS);
+        target.write_u16(self.inputs.len() as u16);
         self.inputs.write_into(target);
     }
 }
 
 impl Deserializable for NoteInputs {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let inputs = <[Felt; 16]>::read_from(source)?;
+        let count = source.read_u16()?;
+        let inputs = Felt::read_batch_from(source, count.into())?;
         Self::new(&inputs).map_err(|v| DeserializationError::InvalidValue(format!("{v}")))
     }
 }
@@ -89,26 +98,23 @@ fn test_input_ordering() {
 
     // inputs are provided in reverse stack order
     let inputs = Vec::from([Felt::new(1), Felt::new(2), Felt::new(3)]);
-    // we expect the inputs to be padded to length 16 and to remain in reverse stack order.
-    let expected_ordering = Vec::from([
-        Felt::new(1),
-        Felt::new(2),
-        Felt::new(3),
-        ZERO,
-        ZERO,
-        ZERO,
-        ZERO,
-        ZERO,
-        ZERO,
-        ZERO,
-        ZERO,
-        ZERO,
-        ZERO,
-        ZERO,
-        ZERO,
-        ZERO,
-    ]);
+    // we expect the inputs to remain in reverse stack order.
+    let expected_ordering = Vec::from([Felt::new(1), Felt::new(2), Felt::new(3)]);
 
     let note_inputs = NoteInputs::new(&inputs).expect("note created should succeed");
     assert_eq!(&expected_ordering, note_inputs.inputs());
 }
+
+#[test]
+fn test_input_padding() {
+    use super::Vec;
+
+    let inputs = (1..=10).map(Felt::new).collect::<Vec<_>>();
+    let note_inputs = NoteInputs::new(&inputs).expect("note created should succeed");
+    assert_eq!(note_inputs.hash(), Hasher::hash_elements(&inputs));
+
+    // we expect the padded inputs to be padded with ZERO to the next multiple of 8.
+    let mut expected_padding = inputs.clone();
+    expected_padding.extend([ZERO; 6]);
+    assert_eq!(expected_padding, note_inputs.to_padded_inputs());
+}
@@ -1,26 +1,30 @@
 use miden_crypto::utils::{ByteReader, ByteWriter, Deserializable, Serializable};
 use vm_processor::DeserializationError;
 
-use super::{AccountId, Felt, NoteError, Word};
+use super::{AccountId, Felt, NoteError, NoteTag, NoteType, Word};
 
-/// Represents metadata associated with a note. This includes the sender, tag, and number of assets.
+/// Represents metadata associated with a note. This includes the sender, note type, tag, and number
+/// of assets.
 /// - sender is the account which created the note.
-/// - tag is a tag which can be used to identify the target account for the note.
+/// - note_type specifies whether the details of the note are public, off-chain or encrypted.
+/// - tag is a tag which can be used to identify the target account or use case of the note.
 /// - num_assets is the number of assets in the note.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct NoteMetadata {
     sender: AccountId,
-    tag: Felt,
+    note_type: NoteType,
+    tag: NoteTag,
     num_assets: Felt,
 }
 
 impl NoteMetadata {
     /// Returns a new note metadata object created with the specified parameters.
-    pub fn new(sender: AccountId, tag: Felt, num_assets: Felt) -> Self {
+    pub fn new(sender: AccountId, note_type: NoteType, tag: NoteTag, num_assets: Felt) -> Self {
         // TODO: Assert num assets is valid
         Self {
             sender,
+            note_type,
             tag,
             num_assets,
         }
@@ -31,8 +35,13 @@ impl NoteMetadata {
         self.sender
     }
 
+    /// Returns the type of the note.
+    pub fn note_type(&self) -> NoteType {
+        self.note_type
+    }
+
     /// Returns the tag associated with the note.
-    pub fn tag(&self) -> Felt {
+    pub fn tag(&self) -> NoteTag {
         self.tag
     }
 
@@ -52,8 +61,9 @@ impl From<&NoteMetadata> for Word {
     fn from(metadata: &NoteMetadata) -> Self {
         let mut elements = Word::default();
         elements[0] = metadata.num_assets;
-        elements[1] = metadata.tag;
+        elements[1] = metadata.tag.into();
         elements[2] = metadata.sender.into();
+        elements[3] = metadata.note_type.into();
         elements
     }
 }
@@ -65,7 +75,8 @@ impl TryFrom<Word> for NoteMetadata {
         // TODO: Assert num assets is valid
         Ok(Self {
             sender: elements[2].try_into().map_err(NoteError::NoteMetadataSenderInvalid)?,
-            tag: elements[1],
+            note_type: elements[3].try_into()?,
+            tag: elements[1].try_into()?,
             num_assets: elements[0],
         })
     }
@@ -77,6 +88,7 @@ impl TryFrom<Word> for NoteMetadata {
 impl Serializable for NoteMetadata {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         self.sender.write_into(target);
+        self.note_type.write_into(target);
         self.tag.write_into(target);
         self.num_assets.write_into(target);
     }
@@ -85,11 +97,13 @@ impl Serializable for NoteMetadata {
 impl Deserializable for NoteMetadata {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let sender = AccountId::read_from(source)?;
-        let tag = Felt::read_from(source)?;
+        let note_type = NoteType::read_from(source)?;
+        let tag = NoteTag::read_from(source)?;
         let num_assets = Felt::read_from(source)?;
 
         Ok(Self {
             sender,
+            note_type,
             tag,
             num_assets,
         })
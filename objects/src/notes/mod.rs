@@ -3,9 +3,12 @@ use super::{
     assembly::{Assembler, AssemblyContext, CodeBlock, ProgramAst},
     assets::Asset,
     utils::{collections::Vec, string::ToString},
-    Digest, Felt, Hasher, NoteError, Word, WORD_SIZE, ZERO,
+    Digest, Felt, Hasher, NoteError, StarkField, Word, WORD_SIZE, ZERO,
 };
 
+mod encrypted;
+pub use encrypted::EncryptedNote;
+
 mod envelope;
 pub use envelope::NoteEnvelope;
 
@@ -15,6 +18,9 @@ pub use inputs::NoteInputs;
 mod metadata;
 pub use metadata::NoteMetadata;
 
+mod note_type;
+pub use note_type::NoteType;
+
 mod nullifier;
 pub use nullifier::Nullifier;
 
@@ -28,6 +34,9 @@ pub use script::NoteScript;
 mod stub;
 pub use stub::NoteStub;
 
+mod tag;
+pub use tag::{NoteExecution, NoteTag};
+
 mod vault;
 pub use vault::NoteVault;
 use vm_processor::DeserializationError;
@@ -59,8 +68,8 @@ pub const NOTE_LEAF_DEPTH: u8 = NOTE_TREE_DEPTH + 1;
 /// - A serial number which can be used to break linkability between note hash and note nullifier.
 ///
 /// Auxiliary data which is used to verify authenticity and signal additional information:
-/// - A metadata object which contains information about the sender, the tag and the number of
-///   assets in the note.
+/// - A metadata object which contains information about the sender, the type, the tag and the
+///   number of assets in the note.
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Note {
@@ -88,7 +97,8 @@ impl Note {
         assets: &[Asset],
         serial_num: Word,
         sender: AccountId,
-        tag: Felt,
+        note_type: NoteType,
+        tag: NoteTag,
     ) -> Result<Self, NoteError> {
         let vault = NoteVault::new(assets)?;
         let num_assets = vault.num_assets();
@@ -97,7 +107,7 @@ impl Note {
             inputs: NoteInputs::new(inputs)?,
             vault,
             serial_num,
-            metadata: NoteMetadata::new(sender, tag, Felt::new(num_assets as u64)),
+            metadata: NoteMetadata::new(sender, note_type, tag, Felt::new(num_assets as u64)),
         })
     }
 
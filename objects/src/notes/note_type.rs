@@ -0,0 +1,92 @@
+use core::fmt;
+
+use miden_crypto::utils::{ByteReader, ByteWriter, Deserializable, Serializable};
+use vm_processor::DeserializationError;
+
+use super::{Felt, NoteError, StarkField};
+
+// NOTE TYPE
+// ================================================================================================
+
+/// Specifies how the details of a note are made available to its consumers.
+///
+/// The note type is included in the note metadata, and thus nodes and clients can determine
+/// whether they should expect to receive the full details of a note or only its hash.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[repr(u8)]
+pub enum NoteType {
+    /// The full details of the note are published on-chain.
+    Public = 1,
+    /// Only the hash of the note is published on-chain. The details of the note must be shared
+    /// with the recipient off-chain.
+    OffChain = 2,
+    /// The details of the note are published on-chain encrypted to the recipient.
+    Encrypted = 3,
+}
+
+impl NoteType {
+    /// Returns true if the full details of the note are expected to be published on-chain.
+    pub fn is_public(&self) -> bool {
+        matches!(self, Self::Public)
+    }
+}
+
+// CONVERSIONS
+// ================================================================================================
+
+impl From<NoteType> for u8 {
+    fn from(note_type: NoteType) -> Self {
+        note_type as u8
+    }
+}
+
+impl From<NoteType> for Felt {
+    fn from(note_type: NoteType) -> Self {
+        Felt::from(note_type as u8)
+    }
+}
+
+impl TryFrom<u64> for NoteType {
+    type Error = NoteError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::Public),
+            2 => Ok(Self::OffChain),
+            3 => Ok(Self::Encrypted),
+            _ => Err(NoteError::InvalidNoteType(value)),
+        }
+    }
+}
+
+impl TryFrom<Felt> for NoteType {
+    type Error = NoteError;
+
+    fn try_from(value: Felt) -> Result<Self, Self::Error> {
+        value.as_int().try_into()
+    }
+}
+
+impl fmt::Display for NoteType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", *self as u8)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for NoteType {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        (*self as u8).write_into(target);
+    }
+}
+
+impl Deserializable for NoteType {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let value = source.read_u8()?;
+        Self::try_from(value as u64)
+            .map_err(|err| DeserializationError::InvalidValue(format!("{err}")))
+    }
+}
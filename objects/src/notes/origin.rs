@@ -1,8 +1,13 @@
 use miden_crypto::utils::{ByteReader, ByteWriter, Deserializable, Serializable};
 use vm_processor::DeserializationError;
 
-use super::{Digest, Felt, NoteError, ToString, NOTE_TREE_DEPTH};
-use crate::crypto::merkle::{MerklePath, NodeIndex};
+use super::{
+    Digest, Felt, Hasher, NoteError, NoteMetadata, StarkField, ToString, Word, NOTE_TREE_DEPTH,
+};
+use crate::{
+    crypto::merkle::{MerklePath, NodeIndex},
+    BlockHeader,
+};
 
 /// Contains information about the origin of a note.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -75,6 +80,53 @@ impl NoteInclusionProof {
     pub fn note_path(&self) -> &MerklePath {
         &self.note_path
     }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies that the note with the specified hash and metadata was created in the block with
+    /// the specified header.
+    ///
+    /// This performs the same checks as the transaction kernel prologue, except for the
+    /// authentication of the block header against the chain MMR, which can be done via
+    /// [ChainMmr::verify_block()](crate::ChainMmr::verify_block).
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The block number of this proof is different from the number of the specified block.
+    /// - The sub hash and note root of this proof do not hash to the hash of the specified block.
+    /// - The Merkle path of this proof does not authenticate the note against the note root.
+    pub fn verify(
+        &self,
+        note_hash: Digest,
+        metadata: &NoteMetadata,
+        block_header: &BlockHeader,
+    ) -> Result<(), NoteError> {
+        if self.origin.block_num != block_header.block_num() {
+            return Err(NoteError::InconsistentInclusionProofBlockNum(
+                block_header.block_num().as_int(),
+                self.origin.block_num.as_int(),
+            ));
+        }
+
+        let block_hash = Hasher::merge(&[self.sub_hash, self.note_root]);
+        if block_hash != block_header.hash() {
+            return Err(NoteError::InconsistentInclusionProofBlockHash(
+                block_header.hash(),
+                block_hash,
+            ));
+        }
+
+        let auth_hash = Hasher::merge(&[note_hash, Word::from(metadata).into()]);
+        if !self
+            .note_path
+            .verify(self.origin.node_index.value(), auth_hash, &self.note_root)
+        {
+            return Err(NoteError::InvalidInclusionProofNotePath(note_hash));
+        }
+
+        Ok(())
+    }
 }
 
 // SERIALIZATION
@@ -123,3 +175,74 @@ impl Deserializable for NoteInclusionProof {
         })
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::NoteInclusionProof;
+    use crate::{
+        accounts::{AccountId, ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN},
+        crypto::merkle::{NodeIndex, SimpleSmt},
+        notes::{NoteMetadata, NoteTag, NoteType, NOTE_LEAF_DEPTH, NOTE_TREE_DEPTH},
+        BlockHeader, Digest, Felt, NoteError, ONE, ZERO,
+    };
+
+    #[test]
+    fn test_note_inclusion_proof_verify() {
+        let sender =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let metadata = NoteMetadata::new(sender, NoteType::Public, NoteTag::from(1), ONE);
+        let note_hash = Digest::new([ONE, Felt::new(2), Felt::new(3), Felt::new(4)]);
+
+        // build a note tree with the note at index 1
+        let note_index = 1;
+        let entries = vec![(2, note_hash.into()), (3, (&metadata).into())];
+        let note_tree = SimpleSmt::with_leaves(NOTE_LEAF_DEPTH, entries).unwrap();
+        let note_path = note_tree
+            .get_path(NodeIndex::new(NOTE_TREE_DEPTH, note_index).unwrap())
+            .unwrap();
+
+        let block_num = Felt::new(7);
+        let block_header = BlockHeader::new(
+            Digest::default(),
+            block_num,
+            Digest::default(),
+            Digest::default(),
+            Digest::default(),
+            note_tree.root(),
+            Digest::default(),
+            Digest::default(),
+            ZERO,
+            ZERO,
+        );
+
+        let proof = NoteInclusionProof::new(
+            block_num,
+            block_header.sub_hash(),
+            block_header.note_root(),
+            note_index,
+            note_path.clone(),
+        )
+        .unwrap();
+        assert_eq!(proof.verify(note_hash, &metadata, &block_header), Ok(()));
+
+        // a different note or note metadata is rejected
+        assert_eq!(
+            proof.verify(Digest::default(), &metadata, &block_header),
+            Err(NoteError::InvalidInclusionProofNotePath(Digest::default()))
+        );
+        let other_metadata = NoteMetadata::new(sender, NoteType::OffChain, NoteTag::from(1), ONE);
+        assert!(proof.verify(note_hash, &other_metadata, &block_header).is_err());
+
+        // a proof for a different block is rejected
+        let proof =
+            NoteInclusionProof::new(ONE, block_header.sub_hash(), note_tree.root(), 1, note_path)
+                .unwrap();
+        assert_eq!(
+            proof.verify(note_hash, &metadata, &block_header),
+            Err(NoteError::InconsistentInclusionProofBlockNum(7, 1))
+        );
+    }
+}
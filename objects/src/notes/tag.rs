@@ -0,0 +1,282 @@
+use core::fmt;
+
+use miden_crypto::utils::{ByteReader, ByteWriter, Deserializable, Serializable};
+use vm_processor::DeserializationError;
+
+use super::{AccountId, Asset, Felt, NoteError, StarkField};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Bit of the tag which specifies that the note is meant to be executed by the network.
+const NETWORK_EXECUTION_BIT: u32 = 1 << 31;
+
+/// Bit of the tag which specifies that the note is a use case note rather than a note targeting a
+/// specific account.
+const USE_CASE_BIT: u32 = 1 << 30;
+
+/// Mask for the payload of the tag, i.e., the bits of the tag which are not used as flags.
+const PAYLOAD_MASK: u32 = USE_CASE_BIT - 1;
+
+/// Number of bits of the account ID prefix encoded in tags targeting a specific account.
+const ACCOUNT_PREFIX_BITS: u32 = 30;
+
+// NOTE EXECUTION
+// ================================================================================================
+
+/// Specifies who is expected to execute a note.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NoteExecution {
+    /// The note is expected to be consumed by its recipient locally.
+    Local,
+    /// The note is expected to be consumed by the network.
+    Network,
+}
+
+// NOTE TAG
+// ================================================================================================
+
+/// A tag which can be used by clients to find the notes they are interested in.
+///
+/// The tag is a 32-bit value with the following layout:
+/// - bit 31 specifies whether the note is meant to be executed by the network.
+/// - bit 30 specifies whether the note is a use case note (e.g., a swap between a pair of assets),
+///   or a note targeting a specific account.
+/// - bits 0 to 29 contain the payload of the tag. For notes targeting a specific account, this is
+///   the 30 most significant bits of the account ID. For use case notes, bits 16 to 29 contain the
+///   use case ID and bits 0 to 15 contain the use case payload.
+///
+/// Since the payload of account targeted tags is only a prefix of the account ID, a tag may match
+/// several accounts, which helps to preserve the privacy of the recipient.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct NoteTag(u32);
+
+impl NoteTag {
+    /// Maximum value of a use case ID.
+    pub const MAX_USE_CASE_ID: u16 = (1 << 14) - 1;
+
+    /// Use case ID of tags for notes swapping a pair of assets.
+    pub const SWAP_USE_CASE_ID: u16 = 0;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a tag for a note targeting the specified account.
+    pub fn from_account_id(account_id: AccountId, execution: NoteExecution) -> Self {
+        let prefix = (u64::from(account_id) >> (64 - ACCOUNT_PREFIX_BITS)) as u32;
+        Self(execution_bit(execution) | prefix)
+    }
+
+    /// Returns a tag for a use case note with the specified use case ID and payload.
+    ///
+    /// # Errors
+    /// Returns an error if the use case ID is greater than [Self::MAX_USE_CASE_ID].
+    pub fn for_use_case(
+        use_case_id: u16,
+        payload: u16,
+        execution: NoteExecution,
+    ) -> Result<Self, NoteError> {
+        if use_case_id > Self::MAX_USE_CASE_ID {
+            return Err(NoteError::InvalidNoteTagUseCase(use_case_id));
+        }
+
+        Ok(Self(
+            execution_bit(execution) | USE_CASE_BIT | (use_case_id as u32) << 16 | payload as u32,
+        ))
+    }
+
+    /// Returns a tag for a note offering `offered_asset` in exchange for `requested_asset`.
+    ///
+    /// The payload of the tag consists of 8 bits of the ID of the faucet which issued the offered
+    /// asset followed by 8 bits of the ID of the faucet which issued the requested asset.
+    pub fn for_swap(offered_asset: Asset, requested_asset: Asset) -> Self {
+        let payload = faucet_id_bits(offered_asset.faucet_id()) << 8
+            | faucet_id_bits(requested_asset.faucet_id());
+        Self::for_use_case(Self::SWAP_USE_CASE_ID, payload, NoteExecution::Local)
+            .expect("swap use case ID is valid")
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the inner value of the tag.
+    pub fn inner(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns who is expected to execute the note.
+    pub fn execution(&self) -> NoteExecution {
+        if self.0 & NETWORK_EXECUTION_BIT == 0 {
+            NoteExecution::Local
+        } else {
+            NoteExecution::Network
+        }
+    }
+
+    /// Returns true if the note is expected to be executed by the network.
+    pub fn is_network_execution(&self) -> bool {
+        self.execution() == NoteExecution::Network
+    }
+
+    /// Returns true if the tag targets a specific account.
+    pub fn is_account_target(&self) -> bool {
+        self.0 & USE_CASE_BIT == 0
+    }
+
+    /// Returns the use case ID of the tag, or None if the tag targets a specific account.
+    pub fn use_case_id(&self) -> Option<u16> {
+        (!self.is_account_target()).then(|| ((self.0 & PAYLOAD_MASK) >> 16) as u16)
+    }
+
+    /// Returns the use case payload of the tag, or None if the tag targets a specific account.
+    pub fn use_case_payload(&self) -> Option<u16> {
+        (!self.is_account_target()).then_some(self.0 as u16)
+    }
+
+    // MATCHERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if the tag targets an account whose ID starts with the prefix encoded in the
+    /// tag.
+    ///
+    /// Since only a prefix of the account ID is encoded in the tag, the tag may match several
+    /// accounts.
+    pub fn matches_account(&self, account_id: AccountId) -> bool {
+        self.is_account_target()
+            && self.0 & PAYLOAD_MASK == (u64::from(account_id) >> (64 - ACCOUNT_PREFIX_BITS)) as u32
+    }
+
+    /// Returns true if the tag is a swap tag for a note offering `offered_asset` in exchange for
+    /// `requested_asset`.
+    pub fn matches_swap(&self, offered_asset: Asset, requested_asset: Asset) -> bool {
+        self.0 & !NETWORK_EXECUTION_BIT
+            == Self::for_swap(offered_asset, requested_asset).0 & !NETWORK_EXECUTION_BIT
+    }
+}
+
+// CONVERSIONS
+// ================================================================================================
+
+impl From<u32> for NoteTag {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<NoteTag> for u32 {
+    fn from(tag: NoteTag) -> Self {
+        tag.0
+    }
+}
+
+impl From<NoteTag> for Felt {
+    fn from(tag: NoteTag) -> Self {
+        Felt::from(tag.0)
+    }
+}
+
+impl TryFrom<Felt> for NoteTag {
+    type Error = NoteError;
+
+    fn try_from(value: Felt) -> Result<Self, Self::Error> {
+        let value = value.as_int();
+        u32::try_from(value)
+            .map(Self)
+            .map_err(|_| NoteError::InvalidNoteTagValue(value))
+    }
+}
+
+impl fmt::Display for NoteTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for NoteTag {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.0.write_into(target);
+    }
+}
+
+impl Deserializable for NoteTag {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self(source.read_u32()?))
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the bit of the tag which specifies who is expected to execute the note.
+fn execution_bit(execution: NoteExecution) -> u32 {
+    match execution {
+        NoteExecution::Local => 0,
+        NoteExecution::Network => NETWORK_EXECUTION_BIT,
+    }
+}
+
+/// Returns 8 bits of the specified faucet ID which are used in swap tags. The most significant
+/// bits of the ID are skipped as they encode the type of the account.
+fn faucet_id_bits(faucet_id: AccountId) -> u16 {
+    ((u64::from(faucet_id) >> 48) as u8) as u16
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{NoteExecution, NoteTag};
+    use crate::{
+        accounts::{
+            AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        },
+        assets::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+        utils::vec,
+    };
+
+    #[test]
+    fn test_account_target_tag() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+        let tag = NoteTag::from_account_id(account_id, NoteExecution::Local);
+        assert!(tag.is_account_target());
+        assert!(!tag.is_network_execution());
+        assert!(tag.matches_account(account_id));
+        assert!(!tag.matches_account(faucet_id));
+        assert_eq!(tag.use_case_id(), None);
+
+        let tag = NoteTag::from_account_id(account_id, NoteExecution::Network);
+        assert!(tag.is_network_execution());
+        assert!(tag.matches_account(account_id));
+    }
+
+    #[test]
+    fn test_swap_tag() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+        let details = NonFungibleAssetDetails::new(faucet_id, vec![1, 2, 3]).unwrap();
+        let non_fungible_asset: Asset = NonFungibleAsset::new(&details).unwrap().into();
+
+        let tag = NoteTag::for_swap(fungible_asset, non_fungible_asset);
+        assert!(!tag.is_account_target());
+        assert_eq!(tag.use_case_id(), Some(NoteTag::SWAP_USE_CASE_ID));
+        assert!(tag.matches_swap(fungible_asset, non_fungible_asset));
+        assert!(!tag.matches_swap(non_fungible_asset, fungible_asset));
+        assert!(!tag.matches_account(faucet_id));
+
+        assert!(
+            NoteTag::for_use_case(NoteTag::MAX_USE_CASE_ID + 1, 0, NoteExecution::Local).is_err()
+        );
+    }
+}
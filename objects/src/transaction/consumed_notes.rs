@@ -1,30 +1,73 @@
+use crate::utils::vec;
 use miden_crypto::utils::{ByteReader, ByteWriter, Deserializable, Serializable};
 use vm_processor::DeserializationError;
 
 use super::{
-    utils::generate_consumed_notes_commitment, AdviceInputsBuilder, Digest, Felt, Nullifier,
-    RecordedNote, ToAdviceInputs, Vec, Word,
+    utils::generate_consumed_notes_commitment, AdviceInputsBuilder, Digest, Felt, NoteError,
+    NoteOrigin, Nullifier, RecordedNote, ToAdviceInputs, Vec, Word,
 };
 
 // CONSUMED NOTES
 // ================================================================================================
 
-/// An object that holds a list of notes that were consumed by a transaction.
+/// An object that holds a list of notes that were consumed by a transaction, together with the
+/// note args provided for each of them.
+///
+/// Note args are supplied by the transaction creator when consuming a note, and can be read by the
+/// note script. They are not committed to by the note hash, and thus do not affect the commitment
+/// to the consumed notes.
 ///
 /// This objects primary use case is to enable all consumed notes to be populated into the advice
 /// provider at once via the [ToAdviceInputs] trait.
 #[derive(Debug, Clone)]
 pub struct ConsumedNotes {
     notes: Vec<RecordedNote>,
+    note_args: Vec<Word>,
     commitment: Digest,
 }
 
 impl ConsumedNotes {
-    /// Creates a new [ConsumedNotes] object.
+    /// Creates a new [ConsumedNotes] object with empty note args for all notes.
     pub fn new(notes: Vec<RecordedNote>) -> Self {
+        let note_args = vec![Word::default(); notes.len()];
+        Self::from_parts(notes, note_args)
+    }
+
+    /// Creates a new [ConsumedNotes] object with the note args provided for the notes with the
+    /// specified origins. Notes for which no note args are provided have empty note args.
+    ///
+    /// # Errors
+    /// Returns an error if note args are provided for a note which is not one of the specified
+    /// notes.
+    pub fn with_note_args(
+        notes: Vec<RecordedNote>,
+        note_args: &[(NoteOrigin, Word)],
+    ) -> Result<Self, NoteError> {
+        let mut args = vec![Word::default(); notes.len()];
+        for (origin, note_args) in note_args.iter() {
+            let note_idx = notes
+                .iter()
+                .position(|note| note.origin() == origin)
+                .ok_or_else(|| NoteError::NoteArgsOriginNotFound(origin.clone()))?;
+            args[note_idx] = *note_args;
+        }
+
+        Ok(Self::from_parts(notes, args))
+    }
+
+    /// Creates a new [ConsumedNotes] object from the notes and the note args of each note.
+    ///
+    /// # Panics
+    /// Panics if the number of note args is not equal to the number of notes.
+    fn from_parts(notes: Vec<RecordedNote>, note_args: Vec<Word>) -> Self {
         assert!(notes.len() <= u16::MAX.into());
+        assert_eq!(notes.len(), note_args.len());
         let commitment = generate_consumed_notes_commitment(&notes);
-        Self { notes, commitment }
+        Self {
+            notes,
+            note_args,
+            commitment,
+        }
     }
 
     /// Returns the consumed notes.
@@ -32,6 +75,11 @@ impl ConsumedNotes {
         &self.notes
     }
 
+    /// Returns the note args of the consumed notes.
+    pub fn note_args(&self) -> &[Word] {
+        &self.note_args
+    }
+
     /// Returns a commitment to the consumed notes.
     pub fn commitment(&self) -> Digest {
         self.commitment
@@ -42,10 +90,11 @@ impl ToAdviceInputs for ConsumedNotes {
     /// Populates the advice inputs for all consumed notes.
     ///
     /// For each note the authentication path is populated into the Merkle store, the note inputs
-    /// and vault assets are populated in the advice map.  A combined note data vector is also
-    /// constructed that holds core data for all notes. This combined vector is added to the advice
-    /// map against the consumed notes commitment. For each note the following data items are added
-    /// to the vector:
+    /// and vault assets are populated in the advice map. The note inputs are stored as the number
+    /// of inputs followed by the inputs padded with ZERO to the next multiple of 8. A combined note
+    /// data vector is also constructed that holds core data for all notes. This combined vector is
+    /// added to the advice map against the consumed notes commitment. For each note the following
+    /// data items are added to the vector:
     ///     out[0..4]    = serial num
     ///     out[4..8]    = script root
     ///     out[8..12]   = input root
@@ -56,16 +105,17 @@ impl ToAdviceInputs for ConsumedNotes {
     ///     ...
     ///     out[20 + num_assets * 4..] = Word::default() (this is conditional padding only applied
     ///                                                   if the number of assets is odd)
-    ///     out[-10]      = origin.block_number
-    ///     out[-9..-5]   = origin.SUB_HASH
-    ///     out[-5..-1]   = origin.NOTE_ROOT
-    ///     out[-1]       = origin.node_index
+    ///     out[-14]      = origin.block_number
+    ///     out[-13..-9]  = origin.SUB_HASH
+    ///     out[-9..-5]   = origin.NOTE_ROOT
+    ///     out[-5]       = origin.node_index
+    ///     out[-4..]     = note_args
     fn to_advice_inputs<T: AdviceInputsBuilder>(&self, target: &mut T) {
         let mut note_data: Vec<Felt> = Vec::new();
 
         note_data.push(Felt::from(self.notes.len() as u64));
 
-        for recorded_note in &self.notes {
+        for (recorded_note, note_args) in self.notes.iter().zip(self.note_args.iter()) {
             let note = recorded_note.note();
             let proof = recorded_note.proof();
 
@@ -88,8 +138,11 @@ impl ToAdviceInputs for ConsumedNotes {
                     .inner_nodes(proof.origin().node_index.value(), note.authentication_hash())
                     .unwrap(),
             );
+            note_data.extend(note_args);
 
-            target.insert_into_map(note.inputs().hash().into(), note.inputs().inputs().to_vec());
+            let mut inputs_data = vec![Felt::from(note.inputs().num_inputs() as u64)];
+            inputs_data.extend(note.inputs().to_padded_inputs());
+            target.insert_into_map(note.inputs().hash().into(), inputs_data);
         }
 
         target.insert_into_map(*self.commitment, note_data);
@@ -127,6 +180,7 @@ impl Serializable for ConsumedNotes {
         assert!(self.notes.len() <= u16::MAX.into());
         target.write_u16(self.notes.len() as u16);
         self.notes.write_into(target);
+        self.note_args.write_into(target);
     }
 }
 
@@ -134,7 +188,8 @@ impl Deserializable for ConsumedNotes {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let count = source.read_u16()?;
         let notes = RecordedNote::read_batch_from(source, count.into())?;
+        let note_args = Word::read_batch_from(source, count.into())?;
 
-        Ok(Self::new(notes))
+        Ok(Self::from_parts(notes, note_args))
     }
 }
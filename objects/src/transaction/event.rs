@@ -1,3 +1,5 @@
+use super::{Felt, Vec};
+use core::ops::Range;
 use vm_processor::ExecutionError;
 
 /// Represents an event which is emitted by a transaction via the invocation of the
@@ -7,6 +9,26 @@ use vm_processor::ExecutionError;
 pub enum Event {
     AddAssetToAccountVault = 131072,
     RemoveAssetFromAccountVault = 131073,
+    SetAccountStorageItem = 131074,
+    TraceStack = 131075,
+    TraceMemory = 131076,
+    TraceValue = 131077,
+    TraceProcedureCall = 131078,
+    SetAccountStorageMapItem = 131079,
+    SetAccountStorageArrayItem = 131080,
+}
+
+impl Event {
+    /// Range of event IDs reserved for the events emitted by the transaction kernel.
+    ///
+    /// Events with IDs outside of this range are user-defined events which can be emitted by
+    /// account code, note scripts, and transaction scripts.
+    pub const KERNEL_EVENT_ID_RANGE: Range<u32> = 131072..196608;
+
+    /// Returns true if the specified event ID is in the range reserved for the kernel events.
+    pub fn is_kernel_event(event_id: u32) -> bool {
+        Self::KERNEL_EVENT_ID_RANGE.contains(&event_id)
+    }
 }
 
 impl TryFrom<u32> for Event {
@@ -16,9 +38,47 @@ impl TryFrom<u32> for Event {
         match value {
             131072 => Ok(Event::AddAssetToAccountVault),
             131073 => Ok(Event::RemoveAssetFromAccountVault),
+            131074 => Ok(Event::SetAccountStorageItem),
+            131075 => Ok(Event::TraceStack),
+            131076 => Ok(Event::TraceMemory),
+            131077 => Ok(Event::TraceValue),
+            131078 => Ok(Event::TraceProcedureCall),
+            131079 => Ok(Event::SetAccountStorageMapItem),
+            131080 => Ok(Event::SetAccountStorageArrayItem),
             _ => Err(ExecutionError::EventError(format!(
                 "Failed to parse Event - event with id {value} is not supported",
             ))),
         }
     }
 }
+
+// TRANSACTION LOG
+// ================================================================================================
+
+/// A structured log recorded by the transaction host when a user-defined event is emitted by a
+/// transaction.
+///
+/// A log consists of the ID of the emitted event and the data extracted from the process state by
+/// the handler of the event at the time the event was emitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionLog {
+    event_id: u32,
+    data: Vec<Felt>,
+}
+
+impl TransactionLog {
+    /// Returns a new [TransactionLog] instantiated from the provided event ID and data.
+    pub fn new(event_id: u32, data: Vec<Felt>) -> Self {
+        Self { event_id, data }
+    }
+
+    /// Returns the ID of the event this log was recorded for.
+    pub fn event_id(&self) -> u32 {
+        self.event_id
+    }
+
+    /// Returns the data of this log.
+    pub fn data(&self) -> &[Felt] {
+        &self.data
+    }
+}
@@ -1,8 +1,8 @@
 use super::{
     accounts::{Account, AccountId},
-    notes::{Note, NoteEnvelope, Nullifier, RecordedNote},
+    notes::{Note, NoteEnvelope, NoteOrigin, Nullifier, RecordedNote},
     utils::collections::Vec,
-    AdviceInputs, AdviceInputsBuilder, BlockHeader, ChainMmr, Digest, Felt, Hasher,
+    AdviceInputs, AdviceInputsBuilder, BlockHeader, ChainMmr, Digest, Felt, Hasher, NoteError,
     PreparedTransactionError, StarkField, ToAdviceInputs, TransactionWitnessError, Word, WORD_SIZE,
     ZERO,
 };
@@ -25,7 +25,7 @@ mod utils;
 pub use account_stub::FinalAccountStub;
 pub use consumed_notes::ConsumedNotes;
 pub use created_notes::CreatedNotes;
-pub use event::Event;
+pub use event::{Event, TransactionLog};
 pub use executed_tx::ExecutedTransaction;
 pub use prepared_tx::PreparedTransaction;
 pub use proven_tx::ProvenTransaction;
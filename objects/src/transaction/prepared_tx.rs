@@ -2,7 +2,7 @@ use crate::{
     accounts::validate_account_seed,
     transaction::{
         utils, Account, AdviceInputs, BlockHeader, ChainMmr, ConsumedNotes, Digest,
-        PreparedTransactionError, Program, RecordedNote, StackInputs, TransactionScript, Vec, Word,
+        PreparedTransactionError, Program, StackInputs, TransactionScript, Word,
     },
 };
 
@@ -35,7 +35,7 @@ impl PreparedTransaction {
         account_seed: Option<Word>,
         block_header: BlockHeader,
         block_chain: ChainMmr,
-        consumed_notes: Vec<RecordedNote>,
+        consumed_notes: ConsumedNotes,
         tx_script: Option<TransactionScript>,
         tx_program: Program,
         auxiliary_data: AdviceInputs,
@@ -46,7 +46,7 @@ impl PreparedTransaction {
             account_seed,
             block_header,
             block_chain,
-            consumed_notes: ConsumedNotes::new(consumed_notes),
+            consumed_notes,
             tx_script,
             tx_program,
             auxiliary_data,
@@ -1,6 +1,9 @@
 use crate::{
     accounts::{Account, AccountDelta, AccountId},
-    transaction::{ConsumedNotes, CreatedNotes, FinalAccountStub, TransactionWitness},
+    transaction::{
+        ConsumedNotes, CreatedNotes, FinalAccountStub, TransactionLog, TransactionWitness,
+    },
+    utils::collections::Vec,
     Digest, TransactionResultError,
 };
 use vm_processor::{AdviceInputs, Program};
@@ -18,6 +21,7 @@ use vm_processor::{AdviceInputs, Program};
 /// - program: the program that was executed.
 /// - tx_script_root: the script root of the transaction.
 /// - advice_witness: an advice witness that contains the minimum required data to execute a tx.
+/// - logs: the logs recorded by the handlers of the user-defined events emitted by the transaction.
 #[derive(Debug, Clone)]
 pub struct TransactionResult {
     account_id: AccountId,
@@ -30,6 +34,7 @@ pub struct TransactionResult {
     program: Program,
     tx_script_root: Option<Digest>,
     advice_witness: AdviceInputs,
+    logs: Vec<TransactionLog>,
 }
 
 impl TransactionResult {
@@ -47,6 +52,7 @@ impl TransactionResult {
         program: Program,
         tx_script_root: Option<Digest>,
         advice_witness: AdviceInputs,
+        logs: Vec<TransactionLog>,
     ) -> Result<Self, TransactionResultError> {
         Ok(Self {
             account_id: initial_account.id(),
@@ -59,6 +65,7 @@ impl TransactionResult {
             program,
             tx_script_root,
             advice_witness,
+            logs,
         })
     }
 
@@ -115,6 +122,12 @@ impl TransactionResult {
         &self.advice_witness
     }
 
+    /// Returns the logs recorded during the execution of the transaction, in the order in which
+    /// the corresponding events were emitted.
+    pub fn logs(&self) -> &[TransactionLog] {
+        &self.logs
+    }
+
     // CONSUMERS
     // --------------------------------------------------------------------------------------------
     pub fn into_witness(self) -> TransactionWitness {
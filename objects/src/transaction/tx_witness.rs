@@ -103,7 +103,7 @@ impl TransactionWitness {
             let num_assets = (num_assets + 1) & !1;
 
             // increment note pointer
-            note_ptr += (num_assets as usize * WORD_SIZE) + 30;
+            note_ptr += (num_assets as usize * WORD_SIZE) + 34;
         }
 
         debug_assert_eq!(
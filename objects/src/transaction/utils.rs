@@ -19,8 +19,8 @@ use vm_core::utils::IntoBytes;
 ///               CN2_A1, CN2_A2, ...
 ///               ...]
 /// Advice Map: {CHAIN_ROOT:             [num_leaves, PEAK_0, ..., PEAK_N],
-///              CN1_IH:                 [CN1_I3, CN1_I2, CN1_I1, CN1_I0],
-///              CN2_IH:                 [CN2_I3, CN2_I2, CN2_I1, CN2_I0],
+///              CN1_IH:                 [cn1_ni, CN1_I0, CN1_I1, ..., ZERO_PADDING],
+///              CN2_IH:                 [cn2_ni, CN2_I0, CN2_I1, ..., ZERO_PADDING],
 ///              [acct_id, 0, 0, 0]?:    [ACT_ID_SEED3, ACT_ID_SEED2, ACT_ID_SEED1, ACT_ID_SEED0],
 ///              ...}
 /// - acct_id is the account id of the account that the transaction is being executed against.
@@ -38,8 +38,9 @@ use vm_core::utils::IntoBytes;
 /// - CN1_A2 is the second asset of consumed note 1.
 /// - CN1_IH is the inputs hash of consumed note 1.
 /// - CN2_SN is the serial number of consumed note 2.
-/// - CN1_I3..0 are the script inputs of consumed note 1.
-/// - CN2_I3..0 are the script inputs of consumed note 2.
+/// - cn1_ni is the number of script inputs of consumed note 1.
+/// - CN1_I0.. are the script inputs of consumed note 1, padded with ZERO to a multiple of 8.
+/// - CN2_I0.. are the script inputs of consumed note 2, padded with ZERO to a multiple of 8.
 /// - CHAIN_ROOT is the root of the block chain MMR from the last known block.
 /// - num_leaves is the number of leaves in the block chain MMR from the last known block.
 /// - PEAK_0 is the first peak in the block chain MMR from the last known block.